@@ -11,12 +11,13 @@ Quick Start:
 """
 
 from collections.abc import Callable
-from typing import Any, Optional, cast
+from dataclasses import fields, replace
+from typing import Any, Literal, Optional, cast
 
-from toonverter.core.spec import ToonEncodeOptions
+from toonverter.core.spec import Delimiter, ToonDecodeOptions, ToonEncodeOptions
 
 from .__version__ import __author__, __license__, __version__
-from .analysis import FormatComparator, TiktokenCounter, compare, count_tokens
+from .analysis import FormatComparator, TiktokenCounter, compare, compare_sizes, count_tokens
 from .analysis.deduplication import SemanticDeduplicator
 from .core import (
     ComparisonReport,
@@ -27,21 +28,31 @@ from .core import (
     EncodeOptions,
     EncodingError,
     FormatNotSupportedError,
+    JsonInputError,
+    LineTooLongError,
     TokenAnalysis,
     ToonConverterError,
     ValidationError,
     registry,
 )
+from .core.capabilities import CONTRACT_VERSION, get_capabilities, supports
 from .core.registry import get_registry
-from .decoders import ToonDecoder
+from .decoders import ToonDecoder, decode_fragment, tokenize_toon
 from .differ import DiffResult
-from .encoders import ToonEncoder
+from .editing import ToonDocument, patch_toon
+from .encoders import (
+    FALLBACK_DECLINED,
+    ToonEncoder,
+    encode_minified,
+    encode_pretty,
+    set_fallback_encoder,
+)
 from .encoders.toon_encoder import _convert_options  # Added import
 from .formats import register_default_formats
 from .integrations.redis_integration import RedisToonWrapper
 from .plugins import load_plugins
 from .schema import SchemaField, SchemaInferrer, SchemaValidator
-from .utils import read_file, write_file
+from .utils import read_file, read_stream, verify_toon_fingerprint, write_file, write_stream
 
 
 # Initialize package
@@ -55,15 +66,24 @@ def convert(
     target: str,
     from_format: str,
     to_format: str,
+    append: bool = False,
     **options: Any,
 ) -> ConversionResult:
     """Convert data from one format to another.
 
+    With ``append`` the output is added on a new line at the end of an
+    existing target instead of replacing it, e.g. to collect converted
+    records in one growing log file. The file then holds one TOON document
+    per conversion, which is only useful for list or tabular roots: each
+    one is a self-contained ``[N]...`` block of records, and readers must
+    split the file at those headers before decoding.
+
     Args:
         source: Path to source file
         target: Path to target file
         from_format: Source format (e.g., 'json', 'yaml')
         to_format: Target format (e.g., 'toon')
+        append: Append to the target if it exists (default: False)
         **options: Additional conversion options
 
     Returns:
@@ -77,46 +97,158 @@ def convert(
         >>> convert('data.json', 'data.toon', 'json', 'toon')
     """
     try:
-        # Read source file
         source_data_str = read_file(source)
+        target_data_str, result = _convert_content(source_data_str, from_format, to_format)
+        write_file(target, target_data_str, append=append)
+        return result
+    except Exception as e:
+        return ConversionResult(
+            success=False, source_format=from_format, target_format=to_format, error=str(e)
+        )
 
-        # Decode from source format
-        source_adapter = registry.get(from_format)
-        data = source_adapter.decode(source_data_str)
 
-        # Encode to target format
-        target_adapter = registry.get(to_format)
-        target_data_str = target_adapter.encode(data)
+def convert_stream(
+    reader: Any,
+    writer: Any,
+    from_format: str,
+    to_format: str,
+) -> ConversionResult:
+    """Convert data read from a file-like object and write it to another.
 
-        # Write target file
-        write_file(target, target_data_str)
+    Works with pipes, sockets and in-memory buffers, text or binary, without
+    temporary files. Failures (including a reader that errors midway) are
+    reported in the result, exactly like :func:`convert`.
 
-        # Count tokens for comparison
-        counter = TiktokenCounter()
-        source_tokens = counter.count_tokens(source_data_str)
-        target_tokens = counter.count_tokens(target_data_str)
+    Args:
+        reader: Object with a ``read(size)`` method providing the source data
+        writer: Object with a ``write`` method receiving the converted data
+        from_format: Source format (e.g., 'json', 'yaml')
+        to_format: Target format (e.g., 'toon')
 
-        return ConversionResult(
-            success=True,
-            source_format=from_format,
-            target_format=to_format,
-            source_tokens=source_tokens,
-            target_tokens=target_tokens,
-            data=data,
-        )
+    Returns:
+        ConversionResult with conversion details
+
+    Examples:
+        >>> import io
+        >>> out = io.StringIO()
+        >>> convert_stream(io.StringIO('{"a": [1, 2]}'), out, 'json', 'toon').success
+        True
+        >>> out.getvalue()
+        'a[2]: 1,2'
+    """
+    try:
+        source_data_str = read_stream(reader)
+        target_data_str, result = _convert_content(source_data_str, from_format, to_format)
+        write_stream(writer, target_data_str)
+        return result
     except Exception as e:
         return ConversionResult(
             success=False, source_format=from_format, target_format=to_format, error=str(e)
         )
 
 
-def encode(data: Any, to_format: str = "toon", **options: Any) -> str:
+def _convert_content(
+    source_data_str: str, from_format: str, to_format: str
+) -> tuple[str, ConversionResult]:
+    """Convert source text between formats and count tokens on both sides."""
+    data = registry.get(from_format).decode(source_data_str)
+    target_data_str = registry.get(to_format).encode(data)
+
+    # Count tokens for comparison
+    counter = TiktokenCounter()
+    source_tokens = counter.count_tokens(source_data_str)
+    target_tokens = counter.count_tokens(target_data_str)
+
+    result = ConversionResult(
+        success=True,
+        source_format=from_format,
+        target_format=to_format,
+        source_tokens=source_tokens,
+        target_tokens=target_tokens,
+        data=data,
+    )
+    return target_data_str, result
+
+
+def toon_to_yaml(text: str, **overrides: Any) -> str:
+    """Decode a TOON document and write it as YAML.
+
+    Args:
+        text: TOON formatted string
+        **overrides: Encoding options for the YAML output
+
+    Returns:
+        YAML formatted string
+
+    Examples:
+        >>> toon_to_yaml("a: 1\\nb[2]: x,y")
+        'a: 1\\nb:\\n- x\\n- y\\n'
+    """
+    return encode(decode(text), to_format="yaml", **overrides)
+
+
+def toon_to_toml(text: str, **overrides: Any) -> str:
+    """Decode a TOON document and write it as TOML.
+
+    TOML has no null, so pass ``toml_null="omit"`` or ``"empty_string"`` for
+    documents containing null values.
+
+    Args:
+        text: TOON formatted string
+        **overrides: Encoding options for the TOML output
+
+    Returns:
+        TOML formatted string
+
+    Raises:
+        EncodingError: If the document cannot be written as TOML
+    """
+    return encode(decode(text), to_format="toml", **overrides)
+
+
+def reindent_toon(text: str, from_indent: int | None, to_indent: int, **overrides: Any) -> str:
+    """Rewrite a TOON document with a different indentation width.
+
+    The document is decoded and encoded again, so the output is also
+    normalized (canonical numbers, minimal quoting, comma delimiter unless
+    overridden); comments are not kept. Pass ``fingerprint=True`` to write a
+    fresh fingerprint trailer for the new layout.
+
+    Args:
+        text: TOON formatted string
+        from_indent: Spaces per indent level of the input, or None to infer it
+        to_indent: Spaces per indent level of the output
+        **overrides: Other ToonEncodeOptions fields for the output
+
+    Returns:
+        TOON formatted string indented with ``to_indent`` spaces
+
+    Raises:
+        DecodingError: If the input is not valid TOON
+        ValidationError: If the input is not indented in steps of ``from_indent``
+
+    Examples:
+        >>> reindent_toon("a:\\n    b: 1", 4, 2)
+        'a:\\n  b: 1'
+    """
+    data = decode(text, options=ToonDecodeOptions(indent_size=from_indent))
+    return encode(data, options=ToonEncodeOptions(indent_size=to_indent, **overrides))
+
+
+def encode(
+    data: Any,
+    to_format: str = "toon",
+    options: EncodeOptions | ToonEncodeOptions | None = None,
+    **overrides: Any,
+) -> str:
     """Encode data to specified format.
 
     Args:
         data: Data to encode
         to_format: Target format (default: 'toon')
-        **options: Encoding options
+        options: Options object (EncodeOptions, or ToonEncodeOptions for TOON)
+        **overrides: Individual encoding options; these take precedence over
+            the same fields in ``options`` and must be fields of its class
 
     Returns:
         Encoded string
@@ -124,35 +256,46 @@ def encode(data: Any, to_format: str = "toon", **options: Any) -> str:
     Raises:
         FormatNotSupportedError: If format not supported
         EncodingError: If encoding fails
+        TypeError: If an override is not a field of the options class, or
+            ToonEncodeOptions is given for another format
 
     Examples:
         >>> encode({"name": "Alice"}, to_format='toon')
         '{name:Alice}'
+        >>> encode({"a": [1, 2]}, options=EncodeOptions(delimiter="|"), indent=4)
+        'a[2|]: 1|2'
     """
     adapter = registry.get(to_format)
 
     # Construct options object based on the target format
     encode_opts: EncodeOptions | ToonEncodeOptions | None = None
-    if options:
-        if to_format == "toon":
-            # First, create a temporary EncodeOptions to correctly parse generic kwargs
-            temp_generic_options = EncodeOptions(**options)
-            # Then, convert to ToonEncodeOptions using the dedicated converter
-            encode_opts = _convert_options(temp_generic_options)
+    if options is not None or overrides:
+        base = options if options is not None else EncodeOptions()
+        merged = _merge_options(base, overrides, adapter.format_name)
+        if adapter.format_name == "toon":
+            # Convert generic options to ToonEncodeOptions using the dedicated converter
+            encode_opts = _convert_options(merged)
         else:
             # For other formats, use the generic EncodeOptions directly
-            encode_opts = EncodeOptions(**options)
+            encode_opts = merged
 
     return adapter.encode(data, cast("Any", encode_opts))
 
 
-def decode(data_str: str, from_format: str = "toon", **options: Any) -> Any:
+def decode(
+    data_str: str,
+    from_format: str = "toon",
+    options: DecodeOptions | ToonDecodeOptions | None = None,
+    **overrides: Any,
+) -> Any:
     """Decode data from specified format.
 
     Args:
         data_str: Data string to decode
         from_format: Source format (default: 'toon')
-        **options: Decoding options
+        options: Options object (DecodeOptions, or ToonDecodeOptions for TOON)
+        **overrides: Individual decoding options; these take precedence over
+            the same fields in ``options`` and must be fields of its class
 
     Returns:
         Decoded Python data
@@ -160,14 +303,147 @@ def decode(data_str: str, from_format: str = "toon", **options: Any) -> Any:
     Raises:
         FormatNotSupportedError: If format not supported
         DecodingError: If decoding fails
+        TypeError: If an override is not a field of the options class, or
+            ToonDecodeOptions is given for another format
 
     Examples:
         >>> decode('{name:Alice}', from_format='toon')
         {'name': 'Alice'}
     """
     adapter = registry.get(from_format)
-    decode_opts = DecodeOptions(**options) if options else None
-    return adapter.decode(data_str, decode_opts)
+    decode_opts: DecodeOptions | ToonDecodeOptions | None = None
+    if options is not None or overrides:
+        base = options if options is not None else DecodeOptions()
+        decode_opts = _merge_options(base, overrides, adapter.format_name)
+    return adapter.decode(data_str, cast("Any", decode_opts))
+
+
+def _merge_options(base: Any, overrides: dict[str, Any], format_name: str) -> Any:
+    """Apply keyword overrides to an options object for ``format_name``.
+
+    Overrides are checked against the fields of ``base``'s own class. The TOON
+    spec classes only apply to TOON; a ToonEncodeOptions delimiter may also be
+    given as a string.
+    """
+    toon_only = isinstance(base, (ToonEncodeOptions, ToonDecodeOptions))
+    if toon_only and format_name != "toon":
+        generic = "EncodeOptions" if isinstance(base, ToonEncodeOptions) else "DecodeOptions"
+        msg = f"{type(base).__name__} only applies to TOON; use {generic} for '{format_name}'"
+        raise TypeError(msg)
+    if not overrides:
+        return base
+    unknown = sorted(set(overrides) - {field.name for field in fields(base)})
+    if unknown:
+        msg = f"{type(base).__name__} has no option(s): {', '.join(unknown)}"
+        raise TypeError(msg)
+    delimiter = overrides.get("delimiter")
+    if isinstance(base, ToonEncodeOptions) and isinstance(delimiter, str):
+        overrides = {**overrides, "delimiter": Delimiter.from_string(delimiter)}
+    return replace(base, **overrides)
+
+
+def decode_value(text: str, **overrides: Any) -> Any:
+    """Decode one TOON value that need not be a whole document.
+
+    Meant for REPLs and playgrounds: a bare ``[3]: 1,2,3``, ``{a: 1}`` or
+    scalar decodes on its own, and text after the value is ignored instead
+    of being an error. Use :func:`toonverter.decoders.decode_fragment` to
+    get that remaining text.
+
+    Args:
+        text: Text starting with a TOON value
+        **overrides: ToonDecodeOptions fields
+
+    Returns:
+        Decoded Python value
+
+    Raises:
+        DecodingError: If the input is empty or the value is malformed
+
+    Examples:
+        >>> decode_value("[3]: 1,2,3")
+        [1, 2, 3]
+        >>> decode_value("{a: 1} # note")
+        {'a': 1}
+    """
+    options = ToonDecodeOptions(**overrides) if overrides else None
+    value, _ = decode_fragment(text, options)
+    return value
+
+
+def decode_into(
+    text: str, target: dict[str, Any] | list[Any], **overrides: Any
+) -> dict[str, Any] | list[Any]:
+    """Decode a TOON document into an existing dict or list in place.
+
+    For reloading configuration without breaking references held elsewhere:
+    nested dicts and lists already in ``target`` are updated rather than
+    replaced. Dict keys missing from the document are kept; lists take the
+    document's length. Inside a list, containers are reused but cleared
+    first, so each element ends up exactly as the document has it. A value
+    whose type differs from the existing one at the same place replaces it.
+
+    Args:
+        text: TOON document whose root is an object or array
+        target: Dict or list to update
+        **overrides: ToonDecodeOptions fields
+
+    Returns:
+        ``target``, updated
+
+    Raises:
+        DecodingError: If decoding fails
+        ValidationError: If the document root is not the same kind of
+            container as ``target``
+
+    Examples:
+        >>> config = {"db": {"host": "a", "port": 1}, "debug": False}
+        >>> db = config["db"]
+        >>> _ = decode_into("db:\\n  host: b\\ndebug: true", config)
+        >>> db
+        {'host': 'b', 'port': 1}
+    """
+    value = decode(text, **overrides)
+    if not _same_container(target, value):
+        msg = f"Cannot decode a {type(value).__name__} root into a {type(target).__name__}"
+        raise ValidationError(msg)
+    _merge_into(target, value)
+    return target
+
+
+def _merge_into(target: Any, value: Any, exact: bool = False) -> None:
+    """Update a dict or list from a value of the same type, recursing in place.
+
+    With ``exact`` (everything inside a list) a dict is cleared and refilled,
+    so it ends up with exactly the document's keys while nested containers
+    keep their identity.
+    """
+    if isinstance(target, dict):
+        existing = dict(target)
+        if exact:
+            target.clear()
+        for key, item in value.items():
+            if key in existing and _same_container(existing[key], item):
+                _merge_into(existing[key], item, exact)
+                target[key] = existing[key]
+            else:
+                target[key] = item
+        return
+    del target[len(value) :]
+    for index, item in enumerate(value):
+        if index == len(target):
+            target.append(item)
+        elif _same_container(target[index], item):
+            _merge_into(target[index], item, exact=True)
+        else:
+            target[index] = item
+
+
+def _same_container(existing: Any, item: Any) -> bool:
+    """Whether both values are dicts or both are lists."""
+    return (isinstance(existing, dict) and isinstance(item, dict)) or (
+        isinstance(existing, list) and isinstance(item, list)
+    )
 
 
 def analyze(
@@ -211,20 +487,30 @@ def load(path: str, format: str) -> Any:
     return decode(content, from_format=format)
 
 
-def save(data: Any, path: str, format: str, **options: Any) -> None:
+def save(
+    data: Any,
+    path: str,
+    format: str,
+    max_output_bytes: int | None = None,
+    **options: Any,
+) -> None:
     """Save data to file.
 
     Args:
         data: Data to save
         path: File path
         format: File format
+        max_output_bytes: Refuse to write output larger than this many bytes
         **options: Encoding options
 
+    Raises:
+        FileOperationError: If writing fails or the output exceeds max_output_bytes
+
     Examples:
         >>> save({"key": "value"}, 'data.toon', format='toon')
     """
     content = encode(data, to_format=format, **options)
-    write_file(path, content)
+    write_file(path, content, max_bytes=max_output_bytes)
 
 
 def list_formats() -> list[str]:
@@ -388,6 +674,8 @@ class Analyzer:
 
 
 __all__ = [
+    "CONTRACT_VERSION",
+    "FALLBACK_DECLINED",
     "Analyzer",
     "ComparisonReport",
     "ConversionError",
@@ -402,11 +690,14 @@ __all__ = [
     "EncodingError",
     "FormatComparator",
     "FormatNotSupportedError",
+    "JsonInputError",
+    "LineTooLongError",
     "TiktokenCounter",
     "TokenAnalysis",
     # Exceptions
     "ToonConverterError",
     "ToonDecoder",
+    "ToonDocument",
     "ToonEncoder",
     "ValidationError",
     "__author__",
@@ -419,19 +710,35 @@ __all__ = [
     "__version__",
     "analyze",
     "compare",
+    "compare_sizes",
     # Level 1 Facade API
     "convert",
+    "convert_stream",
     "count_tokens",
     "decode",
+    "decode_into",
+    "decode_value",
+    "project_toon",
     "encode",
+    "encode_minified",
+    "encode_pretty",
+    "get_capabilities",
     "get_registry",
     "is_supported",
     "list_formats",
     "load",
     "load_plugins",
+    "patch_toon",
+    "reindent_toon",
     # Utilities
     "registry",
     "save",
+    "set_fallback_encoder",
+    "supports",
+    "tokenize_toon",
+    "toon_to_toml",
+    "toon_to_yaml",
+    "verify_toon_fingerprint",
     # Schema Tools
     "infer_schema",
     "validate_schema",
@@ -479,19 +786,47 @@ def validate_schema(data: Any, schema: "SchemaField", strict: bool = False) -> l
     return validator.validate(data, schema, strict=strict)
 
 
-def diff(obj1: Any, obj2: Any) -> "DiffResult":
+def project_toon(
+    data_str: str,
+    pointers: list[str],
+    missing: Literal["skip", "error"] = "skip",
+    as_toon: bool = False,
+) -> Any:
+    """Decode a TOON document and keep only the values at the given paths.
+
+    Args:
+        data_str: TOON formatted string
+        pointers: JSON Pointers of the values to keep, e.g. "/users/0/name"
+        missing: "skip" ignores paths with no value, "error" raises LookupError
+        as_toon: Return the projection re-encoded as TOON instead of Python data
+
+    Returns:
+        Projected data, or its TOON encoding if as_toon is True
+
+    Examples:
+        >>> project_toon("user:\\n  name: Ada\\n  email: a@x\\nn: 1", ["/user/name"])
+        {'user': {'name': 'Ada'}}
+    """
+    from toonverter.utils import project
+
+    projected = project(decode(data_str), pointers, missing=missing)
+    return encode(projected) if as_toon else projected
+
+
+def diff(obj1: Any, obj2: Any, loose_numbers: bool = False) -> "DiffResult":
     """Compute difference between two objects.
 
     Args:
         obj1: Original object
         obj2: New object
+        loose_numbers: Treat ints and floats of equal value as equal
 
     Returns:
         DiffResult object
     """
     from toonverter.differ import DiffResult, ToonDiffer
 
-    differ = ToonDiffer()
+    differ = ToonDiffer(loose_numbers=loose_numbers)
     return differ.diff(obj1, obj2)
 
 
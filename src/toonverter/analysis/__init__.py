@@ -1,7 +1,7 @@
 """Analysis module for token counting and format comparison."""
 
 from .analyzer import TiktokenCounter, analyze_text, count_tokens
-from .comparator import FormatComparator, compare
+from .comparator import FormatComparator, compare, compare_sizes
 from .reporter import ReportFormatter, format_report
 
 
@@ -11,6 +11,7 @@ __all__ = [
     "TiktokenCounter",
     "analyze_text",
     "compare",
+    "compare_sizes",
     "count_tokens",
     "format_report",
 ]
@@ -1,10 +1,13 @@
 """Multi-format token comparison."""
 
+import json
 from typing import Any
 
-from toonverter.core.exceptions import FormatNotSupportedError
+from toonverter.core.exceptions import DecodingError, FormatNotSupportedError
 from toonverter.core.registry import registry
+from toonverter.core.spec import Delimiter, ToonEncodeOptions
 from toonverter.core.types import ComparisonReport, EncodeOptions, TokenAnalysis
+from toonverter.encoders import encode
 
 from .analyzer import TiktokenCounter
 
@@ -140,3 +143,45 @@ def compare(
     """
     comparator = FormatComparator(model)
     return comparator.compare_formats(data, formats, encode_options)
+
+
+def compare_sizes(
+    json_text: str, indent_size: int = 2, delimiter: str = ","
+) -> dict[str, int | float]:
+    """Measure the byte size of a JSON document against its TOON encoding.
+
+    Sizes are UTF-8 byte counts; the JSON side is ``json_text`` as given, so
+    pretty-printed input counts its whitespace.
+
+    Args:
+        json_text: JSON document
+        indent_size: Spaces per indent level of the TOON output
+        delimiter: TOON array delimiter ("," "\\t" or "|")
+
+    Returns:
+        Dictionary with ``json_bytes``, ``toon_bytes``, ``ratio`` (TOON size
+        divided by JSON size) and ``savings_percent``
+
+    Raises:
+        DecodingError: If ``json_text`` is not valid JSON
+
+    Examples:
+        >>> compare_sizes('{"tags": ["a", "b"]}')
+        {'json_bytes': 20, 'toon_bytes': 12, 'ratio': 0.6, 'savings_percent': 40.0}
+    """
+    try:
+        data = json.loads(json_text)
+    except json.JSONDecodeError as e:
+        msg = f"Invalid JSON: {e}"
+        raise DecodingError(msg) from e
+
+    options = ToonEncodeOptions(indent_size=indent_size, delimiter=Delimiter.from_string(delimiter))
+    json_bytes = len(json_text.encode("utf-8"))
+    toon_bytes = len(encode(data, options).encode("utf-8"))
+    ratio = toon_bytes / json_bytes
+    return {
+        "json_bytes": json_bytes,
+        "toon_bytes": toon_bytes,
+        "ratio": ratio,
+        "savings_percent": (1 - ratio) * 100,
+    }
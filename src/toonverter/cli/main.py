@@ -2,6 +2,7 @@
 
 import sys
 from pathlib import Path
+from typing import Any
 
 import click
 
@@ -68,18 +69,42 @@ def encode(input_file: str, output: str | None, compact: bool) -> None:
 @click.argument("input_file", type=click.Path(exists=True))
 @click.option("--output", "-o", type=click.Path(), help="Output file")
 @click.option("--format", "-f", default="json", help="Output format")
-def decode(input_file: str, output: str | None, format: str) -> None:
+@click.option("--compact", is_flag=True, help="Use compact output without whitespace")
+@click.option("--indent", type=click.IntRange(min=0), help="Pretty-print with this indent")
+@click.option(
+    "--max-output-bytes",
+    type=click.IntRange(min=0),
+    help="Fail instead of writing output larger than this many bytes",
+)
+def decode(
+    input_file: str,
+    output: str | None,
+    format: str,
+    compact: bool,
+    indent: int | None,
+    max_output_bytes: int | None,
+) -> None:
     """Decode TOON format to other formats."""
     import toonverter as toon
 
+    options: dict[str, Any] = {}
+    if compact:
+        options["compact"] = True
+    elif indent is not None:
+        options["indent"] = indent
+
     try:
         data = toon.load(input_file, format="toon")
 
         if output:
-            toon.save(data, output, format=format)
+            toon.save(data, output, format=format, max_output_bytes=max_output_bytes, **options)
             click.echo(f"✓ Decoded to {output}")
         else:
-            encoded = toon.encode(data, to_format=format)
+            encoded = toon.encode(data, to_format=format, **options)
+            size = len(encoded.encode("utf-8"))
+            if max_output_bytes is not None and size > max_output_bytes:
+                msg = f"Output is {size} bytes, exceeding limit of {max_output_bytes}"
+                raise ValueError(msg)
             click.echo(encoded)
     except Exception as e:
         click.echo(f"✗ Error: {e}", err=True)
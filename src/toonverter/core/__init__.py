@@ -10,6 +10,8 @@ from .exceptions import (
     EncodingError,
     FileOperationError,
     FormatNotSupportedError,
+    JsonInputError,
+    LineTooLongError,
     PluginError,
     TokenCountError,
     ToonConverterError,
@@ -44,6 +46,8 @@ __all__ = [
     "FormatName",
     "FormatNotSupportedError",
     "FormatRegistry",
+    "JsonInputError",
+    "LineTooLongError",
     "Plugin",
     "PluginError",
     "TokenAnalysis",
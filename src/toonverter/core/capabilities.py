@@ -0,0 +1,119 @@
+"""Contract version and feature capabilities.
+
+Wrappers and integrations should feature-detect with :func:`supports` instead
+of pinning an exact package or contract version, so additive features do not
+force lockstep releases. The contract version only changes major when an
+existing behavior changes incompatibly; a new feature bumps the minor version.
+
+Contract history:
+
+- 1.1.0: capability reporting with :func:`get_capabilities` and :func:`supports`
+- 2.0.0: strict decoding rejects lines that are not a whole number of indent
+  levels; ``key:`` with nothing after it decodes as {}; comment-only
+  documents decode as empty; a single trailing delimiter never closes an
+  empty cell and a second one is an error
+"""
+
+from typing import Any
+
+from toonverter.__version__ import __version__
+from toonverter.core.spec import Delimiter, ToonDecodeOptions, ToonEncodeOptions
+from toonverter.decoders.stream_decoder import StreamDecoder
+from toonverter.encoders.stream_encoder import ToonStreamEncoder
+
+
+CONTRACT_VERSION = "2.0.0"
+
+# Feature name -> (object, attribute) that implements it. Every entry must
+# resolve, so a feature can only be advertised once its backing code exists.
+FEATURES: dict[str, tuple[Any, str]] = {
+    "strict_lengths": (ToonDecodeOptions, "strict"),
+    "type_inference": (ToonDecodeOptions, "type_inference"),
+    "intern_strings": (ToonDecodeOptions, "intern_strings"),
+    "max_tabular_fields": (ToonDecodeOptions, "max_tabular_fields"),
+    "max_indent_depth": (ToonDecodeOptions, "max_indent_depth"),
+    "max_dict_entries": (ToonDecodeOptions, "max_dict_entries"),
+    "max_list_elements": (ToonDecodeOptions, "max_list_elements"),
+    "max_line_length": (ToonDecodeOptions, "max_line_length"),
+    "missing_cells": (ToonDecodeOptions, "missing_cells"),
+    "max_errors": (ToonDecodeOptions, "max_errors"),
+    "expand_paths": (ToonDecodeOptions, "expand_paths"),
+    "coerce_string_booleans": (ToonDecodeOptions, "coerce_string_booleans"),
+    "string_booleans_case_sensitive": (ToonDecodeOptions, "string_booleans_case_sensitive"),
+    "nonfinite_tokens": (ToonDecodeOptions, "nonfinite_tokens"),
+    "coerce_types": (ToonDecodeOptions, "coerce_types"),
+    "row_errors": (ToonDecodeOptions, "row_errors"),
+    "as_pairs": (ToonDecodeOptions, "as_pairs"),
+    "duplicate_keys": (ToonDecodeOptions, "duplicate_keys"),
+    "missing_values": (ToonDecodeOptions, "missing_values"),
+    "empty_list_items": (ToonDecodeOptions, "empty_list_items"),
+    "decode_column_encoding": (ToonDecodeOptions, "column_encoding"),
+    "decode_indent_size": (ToonDecodeOptions, "indent_size"),
+    "key_folding": (ToonEncodeOptions, "key_folding"),
+    "token_budget": (ToonEncodeOptions, "token_budget"),
+    "nonfinite_error": (ToonEncodeOptions, "nonfinite"),
+    "integer_keyed_dict_as_list": (ToonEncodeOptions, "integer_keyed_dict_as_list"),
+    "blank_lines": (ToonEncodeOptions, "blank_lines"),
+    "float_precision": (ToonEncodeOptions, "float_precision"),
+    "preserve_negative_zero": (ToonEncodeOptions, "preserve_negative_zero"),
+    "int_as_float": (ToonEncodeOptions, "int_as_float"),
+    "align_columns": (ToonEncodeOptions, "align_columns"),
+    "transform": (ToonEncodeOptions, "transform"),
+    "redact_paths": (ToonEncodeOptions, "redact_paths"),
+    "redact_with": (ToonEncodeOptions, "redact_with"),
+    "empty_strings": (ToonEncodeOptions, "empty_strings"),
+    "on_unsupported": (ToonEncodeOptions, "on_unsupported"),
+    "reserved_literals": (ToonEncodeOptions, "reserved_literals"),
+    "column_encoding": (ToonEncodeOptions, "column_encoding"),
+    "normalize_numbers": (ToonEncodeOptions, "normalize_numbers"),
+    "enum_mode": (ToonEncodeOptions, "enum_mode"),
+    "comments": (ToonEncodeOptions, "comments"),
+    "line_ending": (ToonEncodeOptions, "line_ending"),
+    "fingerprint": (ToonEncodeOptions, "fingerprint"),
+    "trailing_newline": (ToonEncodeOptions, "trailing_newline"),
+    "streaming_rows": (StreamDecoder, "decode_stream"),
+    "streaming_encode": (ToonStreamEncoder, "iterencode"),
+    "streaming_rows_encode": (ToonStreamEncoder, "iterencode_rows"),
+    "delimiter_tab": (Delimiter, "TAB"),
+    "delimiter_pipe": (Delimiter, "PIPE"),
+}
+
+
+def get_capabilities() -> dict[str, Any]:
+    """Describe the contract version and supported features.
+
+    Returns:
+        Dictionary with contract version, package version, feature names and
+        supported delimiters
+
+    Examples:
+        >>> caps = get_capabilities()
+        >>> caps["contract"]
+        '2.0.0'
+        >>> "streaming_rows" in caps["features"]
+        True
+    """
+    return {
+        "contract": CONTRACT_VERSION,
+        "version": __version__,
+        "features": sorted(FEATURES),
+        "delimiters": [d.value for d in Delimiter],
+    }
+
+
+def supports(feature: str) -> bool:
+    """Check whether a feature is available.
+
+    Args:
+        feature: Feature name as listed by get_capabilities()
+
+    Returns:
+        True if the feature is supported
+
+    Examples:
+        >>> supports("intern_strings")
+        True
+        >>> supports("time_travel")
+        False
+    """
+    return feature in FEATURES
@@ -17,8 +17,25 @@ class DecodingError(ToonConverterError):
     """Raised when decoding from TOON format fails."""
 
 
+class JsonInputError(DecodingError):
+    """Raised when TOON decoding fails on input that is valid JSON."""
+
+
 class ValidationError(ToonConverterError):
-    """Raised when input validation fails."""
+    """Raised when input validation fails.
+
+    Attributes:
+        errors: Individual error messages when several problems were collected
+            (a single-item list otherwise)
+    """
+
+    def __init__(self, message: str = "", errors: list[str] | None = None) -> None:
+        super().__init__(message)
+        self.errors = errors if errors is not None else [message]
+
+
+class LineTooLongError(ValidationError):
+    """Raised when an input line is longer than the configured maximum."""
 
 
 class FormatNotSupportedError(ToonConverterError):
@@ -5,6 +5,7 @@ TOON specification at https://github.com/toon-format/spec
 """
 
 import re
+from collections.abc import Callable, Mapping, Sequence
 from dataclasses import dataclass
 from enum import Enum
 from typing import Any, Literal
@@ -92,6 +93,30 @@ QUOTE_REQUIRED_CHARS = {
     "\t",  # Tab
 }
 
+# Types ToonDecodeOptions.coerce_types can convert string values to
+COERCIBLE_TYPES = ("int", "float", "bool", "null")
+
+# Column types of a decode_typed_columns schema
+COLUMN_TYPES = ("int", "float", "str", "bool", "any")
+
+# Column encodings of tabular arrays and their header annotations: {ts:+delta,status:*rle}
+COLUMN_ENCODINGS = {"delta": "+delta", "rle": "*rle"}
+
+# Key of the marker dict that replaces a malformed tabular row with row_errors="mark"
+PARSE_ERROR_KEY = "__parse_error__"
+
+# Comment marker; only starts a comment at line start or after whitespace
+COMMENT_CHAR = "#"
+
+# Byte order mark some Windows tools write at the start of UTF-8 files
+BOM = "\ufeff"
+
+# Line separators selectable with ToonEncodeOptions.line_ending
+LINE_ENDINGS = {"lf": "\n", "crlf": "\r\n"}
+
+# Start of the trailer comment written with ToonEncodeOptions.fingerprint
+FINGERPRINT_PREFIX = "# toonverter:"
+
 # Valid escape sequences (only these 5)
 ESCAPE_SEQUENCES = {
     "\\": "\\",  # Backslash
@@ -126,6 +151,73 @@ class ToonEncodeOptions:
         strict: Enable strict validation of output (default: True)
         token_budget: Maximum token count for output (active optimization)
         optimization_policy: Rules for intelligent degradation
+        nonfinite: Handling of NaN/Infinity - "null" or "error" (default: "null")
+        integer_keyed_dict_as_list: Encode dicts whose keys are exactly "0".."N-1"
+            as arrays (default: False)
+        blank_lines: Separate root-level fields and list items with a blank line
+            for readability (default: False)
+        float_precision: Round floats to this many significant digits
+            (default: None, shortest exact representation)
+        preserve_negative_zero: Write -0.0 as ``-0.0`` so the sign survives
+            a round trip, instead of ``0`` (default: False)
+        int_as_float: Write every number as a float, integers included
+            (``1`` becomes ``1.0``), so a column of mixed ints and floats
+            decodes to floats only (default: False)
+        align_columns: Pad tabular cells with spaces so columns line up;
+            the padding is trimmed on decode (default: False)
+        transform: Called as ``transform(pointer, value)`` for every value,
+            parents before children; the returned value is encoded instead,
+            without visiting its children (default: None)
+        redact_paths: JSON Pointers of values to replace with ``redact_with``;
+            a ``*`` segment matches any key or index, e.g. ``/rows/*/ssn``
+        redact_with: Replacement for redacted values (default: "***")
+        empty_strings: Handling of empty strings - "quote" keeps them as ``""``,
+            "null" writes them as null (default: "quote")
+        on_unsupported: Handling of values with no TOON form, such as sets or
+            dates - "error" raises with the value's pointer path, "skip"
+            drops the entry, "null" writes null and "repr" writes
+            ``repr(value)`` as a string (default: "error")
+        reserved_literals: Extra words quoted like ``true``/``false``/``null``
+            when they appear as string values (case-insensitive), e.g. the
+            ``yes``/``no`` aliases a consumer's decoder treats as booleans
+            (default: ())
+        column_encoding: Compact encodings for tabular columns by field name,
+            an opt-in format extension announced in the header as
+            ``{ts:+delta,status:*rle}``. "delta" writes each integer as the
+            difference from the previous non-null value of the column (the
+            first one as is); "rle" leaves a cell empty when it repeats the
+            previous row's value (written out where an empty cell would not
+            survive, e.g. at the edges of tab-delimited rows), with nulls
+            written as ``null``. Decoding
+            requires ``ToonDecodeOptions(column_encoding=True)``
+            (default: None)
+        normalize_numbers: Write other numeric types (``Decimal``,
+            ``Fraction``, numpy scalars) in the canonical form of ints and
+            floats: integral values as plain digits (``Decimal("100.00")``
+            and ``Decimal("1E+3")`` become ``100`` and ``1000``, or ``100.0``
+            with ``int_as_float``) and the rest as the shortest round-trip
+            float (default: False, such values are unsupported)
+        enum_mode: Write ``enum.Enum`` members, as values and as dict keys,
+            as their ``.value`` ("value") or ``.name`` ("name"). A value that
+            itself has no TOON form, such as a tuple, is handled by
+            ``on_unsupported`` (default: None, plain
+            Enum members are unsupported while ``IntEnum`` and other
+            int-based members encode as numbers)
+        comments: Comment lines to write above the document (key ``""``)
+            and above top-level keys (keys ``"/name"``), as collected by
+            ``ToonDecoder.decode_with_comments``. The root block is followed
+            by a blank line so it does not attach to the first key
+            (default: None)
+        line_ending: Line separator of the output - "lf" (``\\n``) or "crlf"
+            (``\\r\\n``) for Windows-targeted files; the decoder accepts
+            both (default: "lf")
+        fingerprint: End the output with a comment line recording the
+            library version, delimiter, indent and a SHA-256 of everything
+            above it, ``# toonverter: v=1.1.2 delimiter=, indent=2 sha256=...``,
+            checked by ``verify_toon_fingerprint`` (default: False)
+        trailing_newline: End non-empty output with a line ending, after the
+            fingerprint if any, so files written from a string and from
+            ``ToonStreamEncoder`` end in the same bytes (default: False)
     """
 
     indent_size: int = DEFAULT_INDENT_SIZE
@@ -134,15 +226,72 @@ class ToonEncodeOptions:
     strict: bool = True
     token_budget: int | None = None
     optimization_policy: OptimizationPolicy | None = None
+    nonfinite: Literal["null", "error"] = "null"
+    integer_keyed_dict_as_list: bool = False
+    blank_lines: bool = False
+    float_precision: int | None = None
+    preserve_negative_zero: bool = False
+    int_as_float: bool = False
+    align_columns: bool = False
+    transform: Callable[[str, Any], Any] | None = None
+    redact_paths: Sequence[str] = ()
+    redact_with: Any = "***"
+    empty_strings: Literal["quote", "null"] = "quote"
+    on_unsupported: Literal["error", "skip", "null", "repr"] = "error"
+    reserved_literals: Sequence[str] = ()
+    column_encoding: Mapping[str, str] | None = None
+    normalize_numbers: bool = False
+    enum_mode: Literal["value", "name"] | None = None
+    comments: Mapping[str, Sequence[str]] | None = None
+    line_ending: Literal["lf", "crlf"] = "lf"
+    fingerprint: bool = False
+    trailing_newline: bool = False
 
     def __post_init__(self) -> None:
         """Validate options."""
+        if self.line_ending not in LINE_ENDINGS:
+            msg = "line_ending must be 'lf' or 'crlf'"
+            raise ValueError(msg)
+        if self.comments:
+            nested = [p for p in self.comments if p and (p[0] != "/" or "/" in p[1:])]
+            if nested:
+                msg = f"comments keys must be '' or a top-level pointer like '/name', got {nested}"
+                raise ValueError(msg)
+            if any("\n" in line for lines in self.comments.values() for line in lines):
+                msg = "comment lines must not contain newlines"
+                raise ValueError(msg)
+        if self.column_encoding:
+            unknown = set(self.column_encoding.values()) - set(COLUMN_ENCODINGS)
+            if unknown:
+                msg = f"column_encoding values must be 'delta' or 'rle', got {sorted(unknown)}"
+                raise ValueError(msg)
         if self.indent_size < 0:
             msg = "indent_size must be at least 0 (0 for compact mode)"
             raise ValueError(msg)
         if self.key_folding not in ("safe", "none"):
             msg = "key_folding must be 'safe' or 'none'"
             raise ValueError(msg)
+        if self.nonfinite not in ("null", "error"):
+            msg = "nonfinite must be 'null' or 'error'"
+            raise ValueError(msg)
+        if self.empty_strings not in ("quote", "null"):
+            msg = "empty_strings must be 'quote' or 'null'"
+            raise ValueError(msg)
+        if self.on_unsupported not in ("error", "skip", "null", "repr"):
+            msg = "on_unsupported must be 'error', 'skip', 'null' or 'repr'"
+            raise ValueError(msg)
+        if self.enum_mode not in (None, "value", "name"):
+            msg = "enum_mode must be 'value', 'name' or None"
+            raise ValueError(msg)
+        if self.blank_lines and self.indent_size == 0:
+            msg = "blank_lines requires indent_size > 0"
+            raise ValueError(msg)
+        if self.comments and self.indent_size == 0:
+            msg = "comments requires indent_size > 0"
+            raise ValueError(msg)
+        if self.float_precision is not None and self.float_precision < 1:
+            msg = "float_precision must be at least 1"
+            raise ValueError(msg)
 
 
 @dataclass
@@ -152,10 +301,144 @@ class ToonDecodeOptions:
     Attributes:
         strict: Enable strict validation of lengths and fields (default: True)
         type_inference: Automatically infer types from strings (default: True)
+        intern_strings: Reuse one string object for equal decoded string values,
+            reducing memory for repetitive categorical columns (default: False)
+        max_tabular_fields: Reject tabular headers declaring more fields than this
+            (default: None, unlimited)
+        max_indent_depth: Reject input indented deeper than this many levels,
+            checked while tokenizing (default: None, unlimited)
+        max_dict_entries: Reject any single object with more than this many
+            keys, checked as fields are parsed; a tabular header is checked
+            for the keys its rows will have (default: None, unlimited)
+        max_list_elements: Reject any single array declaring or holding more
+            than this many elements, tabular rows included; the declared
+            ``[N]`` is checked before the body is read (default: None, unlimited)
+        missing_cells: How non-strict mode fills tabular rows shorter than the
+            header - "omit" leaves the keys out, "null" sets them to None
+            (default: "omit"). Empty cells such as the middle of ``1,,3`` are
+            explicit nulls either way.
+        max_errors: Number of strict-mode tabular row errors to collect before
+            failing; all collected errors are reported together (default: 1)
+        expand_paths: "safe" expands unquoted dotted keys such as ``a.b.c: 1``
+            into nested objects, the inverse of key folding (default: "off")
+        coerce_string_booleans: Decode string values "true" and "false",
+            including quoted ones, as booleans (default: False)
+        string_booleans_case_sensitive: Only coerce the lowercase spellings;
+            otherwise "TRUE" and "False" are coerced too (default: False)
+        indent_size: Spaces per indent level of the input, or None to infer
+            it from the first indented line; a ``#!toon`` directive takes
+            precedence (default: 2)
+        nonfinite_tokens: Handling of unquoted ``nan``, ``inf`` and
+            ``Infinity`` (any case, optionally signed). They are never numbers,
+            since the encoder writes non-finite floats as null. "string" keeps
+            them as strings, "error" rejects them (default: "string")
+        coerce_types: Convert string values, quoted or not, that exactly match
+            the lexical form of these types: "int" (``42``, ``-7``), "float"
+            (``1.5``, ``1e5``), "bool" (``true``/``false``) and "null". Numbers
+            with leading zeros such as ``007`` are never coerced, so zero-padded
+            codes survive (default: (), no coercion)
+        max_line_length: Reject input lines longer than this many characters
+            with a LineTooLongError (default: None, unlimited)
+        row_errors: Handling of a malformed tabular row - "raise" fails the
+            decode, "mark" replaces the row with ``{"__parse_error__": message}``
+            and continues with the next row. Malformed rows include bad cells,
+            width mismatches in strict mode and lines that cannot be tokenized,
+            such as an unterminated quote (default: "raise")
+        as_pairs: Return every object as a list of ``(key, value)`` tuples
+            in document order instead of a dict (default: False)
+        duplicate_keys: Handling of a key repeated within one object - "last"
+            keeps the last value at the first occurrence's position, "collect"
+            keeps every occurrence in order and requires ``as_pairs``
+            (default: "last")
+        missing_values: Value of a key followed by a colon and nothing else -
+            ``key:`` with no deeper lines, or ``{a:, b: 1}`` - "object" decodes
+            it as {} (the encoder's form for an empty object), "null" as None,
+            "error" rejects it with the key and line (default: "object")
+        empty_list_items: Value of a ``-`` list marker with nothing after it
+            and no deeper lines - "object" decodes it as {} (the encoder's
+            form for an empty object item), "null" as None, for dialects that
+            write sparse lists that way (default: "object")
+        column_encoding: Restore tabular columns whose header field carries a
+            ``:+delta`` or ``:*rle`` annotation (see
+            ``ToonEncodeOptions.column_encoding``); without it such headers
+            are rejected (default: False)
     """
 
     strict: bool = True
     type_inference: bool = True
+    intern_strings: bool = False
+    max_tabular_fields: int | None = None
+    max_indent_depth: int | None = None
+    max_dict_entries: int | None = None
+    max_list_elements: int | None = None
+    missing_cells: Literal["omit", "null"] = "omit"
+    max_errors: int = 1
+    expand_paths: Literal["off", "safe"] = "off"
+    coerce_string_booleans: bool = False
+    string_booleans_case_sensitive: bool = False
+    indent_size: int | None = DEFAULT_INDENT_SIZE
+    nonfinite_tokens: Literal["string", "error"] = "string"
+    coerce_types: tuple[str, ...] = ()
+    max_line_length: int | None = None
+    row_errors: Literal["raise", "mark"] = "raise"
+    as_pairs: bool = False
+    duplicate_keys: Literal["last", "collect"] = "last"
+    missing_values: Literal["object", "null", "error"] = "object"
+    empty_list_items: Literal["object", "null"] = "object"
+    column_encoding: bool = False
+
+    def __post_init__(self) -> None:
+        """Validate options."""
+        unknown = set(self.coerce_types) - set(COERCIBLE_TYPES)
+        if unknown:
+            allowed = ", ".join(COERCIBLE_TYPES)
+            msg = f"coerce_types must only contain {allowed}, got {sorted(unknown)}"
+            raise ValueError(msg)
+        if self.nonfinite_tokens not in ("string", "error"):
+            msg = "nonfinite_tokens must be 'string' or 'error'"
+            raise ValueError(msg)
+        if self.row_errors not in ("raise", "mark"):
+            msg = "row_errors must be 'raise' or 'mark'"
+            raise ValueError(msg)
+        if self.duplicate_keys not in ("last", "collect"):
+            msg = "duplicate_keys must be 'last' or 'collect'"
+            raise ValueError(msg)
+        if self.missing_values not in ("object", "null", "error"):
+            msg = "missing_values must be 'object', 'null' or 'error'"
+            raise ValueError(msg)
+        if self.empty_list_items not in ("object", "null"):
+            msg = "empty_list_items must be 'object' or 'null'"
+            raise ValueError(msg)
+        if self.duplicate_keys == "collect" and not self.as_pairs:
+            msg = "duplicate_keys='collect' requires as_pairs=True"
+            raise ValueError(msg)
+        if self.indent_size is not None and self.indent_size < 1:
+            msg = "indent_size must be at least 1, or None to infer it"
+            raise ValueError(msg)
+        if self.max_tabular_fields is not None and self.max_tabular_fields < 1:
+            msg = "max_tabular_fields must be at least 1"
+            raise ValueError(msg)
+        if self.max_indent_depth is not None and self.max_indent_depth < 0:
+            msg = "max_indent_depth must be at least 0"
+            raise ValueError(msg)
+        if self.max_dict_entries is not None and self.max_dict_entries < 0:
+            msg = "max_dict_entries must be at least 0"
+            raise ValueError(msg)
+        if self.max_list_elements is not None and self.max_list_elements < 0:
+            msg = "max_list_elements must be at least 0"
+            raise ValueError(msg)
+        if self.max_line_length is not None and self.max_line_length < 1:
+            msg = "max_line_length must be at least 1"
+            raise ValueError(msg)
+        if self.missing_cells not in ("omit", "null"):
+            msg = "missing_cells must be 'omit' or 'null'"
+            raise ValueError(msg)
+        if self.max_errors < 1:
+            msg = "max_errors must be at least 1"
+            raise ValueError(msg)
+        if self.expand_paths not in ("off", "safe"):
+            msg = "expand_paths must be 'off' or 'safe'"
+            raise ValueError(msg)
 
 
 @dataclass
@@ -1,6 +1,7 @@
 """Type definitions and data classes for TOON Converter."""
 
-from dataclasses import dataclass, field
+from collections.abc import Mapping, Sequence
+from dataclasses import dataclass, field, fields
 from typing import Any, Literal
 
 
@@ -31,6 +32,34 @@ class EncodeOptions:
         max_line_length: Maximum line length before wrapping
         token_budget: Maximum token count for output (active optimization)
         optimization_policy: Rules for intelligent degradation
+        nonfinite: Handling of NaN/Infinity - "null" or "error"
+        integer_keyed_dict_as_list: Encode {"0": a, "1": b, ...} dicts as arrays
+        blank_lines: Put a blank line between root-level fields and list items
+        float_precision: Round floats to this many significant digits
+        preserve_negative_zero: Write -0.0 as "-0.0" instead of "0"
+        int_as_float: Write integers as floats (1 as "1.0") so numbers decode uniformly
+        align_columns: Pad tabular cells so columns line up
+        collapse_single_key_chains: Write single-key object chains as dotted
+            paths (``a.b.c: 1``); decode with expand_paths="safe" to restore them
+        redact_paths: JSON Pointers of values to mask ("*" matches any key or index)
+        redact_with: Replacement for redacted values
+        empty_strings: Write empty strings as "" ("quote") or as null ("null")
+        on_unsupported: "error", "skip", "null" or "repr" for values such as sets
+        reserved_literals: Extra words to quote like true/false/null (e.g. "yes", "no")
+        column_encoding: "delta" or "rle" per tabular field name, written as a
+            header annotation; decode with column_encoding=True
+        normalize_numbers: Write Decimal, Fraction and numpy numbers in the
+            canonical int/float form (Decimal("100.00") as 100)
+        enum_mode: Write Enum members as their "value" or "name"
+        comments: Comment lines above the document ("") and top-level keys
+            ("/name"), as returned by ToonDecoder.decode_with_comments
+        line_ending: "lf" or "crlf" line separators in the output
+        fingerprint: End the output with a comment recording version,
+            delimiter, indent and a SHA-256 of the content
+        trailing_newline: End non-empty output with a line ending
+        toml_null: How TOML output handles None, which TOML cannot represent:
+            "omit" drops the key or array item, "empty_string" writes "",
+            "error" raises
     """
 
     indent: int = 2
@@ -42,6 +71,39 @@ class EncodeOptions:
     max_line_length: int | None = None
     token_budget: int | None = None
     optimization_policy: OptimizationPolicy | None = None
+    nonfinite: Literal["null", "error"] = "null"
+    integer_keyed_dict_as_list: bool = False
+    blank_lines: bool = False
+    float_precision: int | None = None
+    preserve_negative_zero: bool = False
+    int_as_float: bool = False
+    align_columns: bool = False
+    collapse_single_key_chains: bool = False
+    redact_paths: Sequence[str] = ()
+    redact_with: Any = "***"
+    empty_strings: Literal["quote", "null"] = "quote"
+    on_unsupported: Literal["error", "skip", "null", "repr"] = "error"
+    reserved_literals: Sequence[str] = ()
+    column_encoding: Mapping[str, str] | None = None
+    normalize_numbers: bool = False
+    enum_mode: Literal["value", "name"] | None = None
+    comments: Mapping[str, Sequence[str]] | None = None
+    line_ending: Literal["lf", "crlf"] = "lf"
+    fingerprint: bool = False
+    trailing_newline: bool = False
+    toml_null: Literal["omit", "empty_string", "error"] = "error"
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the options as a dictionary of keyword arguments.
+
+        Returns:
+            Mapping of option name to value, accepted by EncodeOptions(**d)
+
+        Examples:
+            >>> EncodeOptions(indent=4).to_dict()["indent"]
+            4
+        """
+        return {f.name: getattr(self, f.name) for f in fields(self)}
 
     @classmethod
     def create_compact(cls) -> "EncodeOptions":
@@ -79,11 +141,62 @@ class DecodeOptions:
         strict: Raise errors on malformed input
         type_inference: Automatically infer data types
         delimiter: Expected field delimiter
+        intern_strings: Share one object between equal decoded string values
+        max_tabular_fields: Maximum number of fields in a tabular header
+        max_indent_depth: Maximum indentation depth (in levels) of the input
+        max_dict_entries: Maximum number of keys in any one object
+        max_list_elements: Maximum number of elements in any one array
+        missing_cells: "omit" or "null" for cells missing from short tabular rows
+        max_errors: Number of tabular row errors to collect before failing
+        expand_paths: "safe" expands dotted keys like a.b.c into nested objects
+        coerce_string_booleans: Decode "true"/"false" string values as booleans
+        string_booleans_case_sensitive: Only coerce the lowercase spellings
+        indent_size: Spaces per indent level of the input (None infers it)
+        nonfinite_tokens: "string" keeps unquoted nan/inf as strings, "error" rejects them
+        coerce_types: Convert string values matching "int", "float", "bool" or
+            "null" literals to those types (leading-zero numbers stay strings)
+        max_line_length: Maximum length of an input line in characters
+        row_errors: "raise" fails on a malformed tabular row, "mark" replaces it
+            with {"__parse_error__": message} and keeps decoding
+        as_pairs: Return objects as lists of (key, value) tuples
+        duplicate_keys: "last" keeps the last value of a repeated key, "collect"
+            keeps all of them (requires as_pairs)
+        missing_values: "object", "null" or "error" for a key with nothing after its colon
+        empty_list_items: "object" or "null" for a list marker with nothing after it
+        column_encoding: Restore delta and rle encoded tabular columns
     """
 
     strict: bool = True
     type_inference: bool = True
     delimiter: Literal[",", "\t", "|", ";"] = ","
+    intern_strings: bool = False
+    max_tabular_fields: int | None = None
+    max_indent_depth: int | None = None
+    max_dict_entries: int | None = None
+    max_list_elements: int | None = None
+    missing_cells: Literal["omit", "null"] = "omit"
+    max_errors: int = 1
+    expand_paths: Literal["off", "safe"] = "off"
+    coerce_string_booleans: bool = False
+    string_booleans_case_sensitive: bool = False
+    indent_size: int | None = 2
+    nonfinite_tokens: Literal["string", "error"] = "string"
+    coerce_types: tuple[str, ...] = ()
+    max_line_length: int | None = None
+    row_errors: Literal["raise", "mark"] = "raise"
+    as_pairs: bool = False
+    duplicate_keys: Literal["last", "collect"] = "last"
+    missing_values: Literal["object", "null", "error"] = "object"
+    empty_list_items: Literal["object", "null"] = "object"
+    column_encoding: bool = False
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the options as a dictionary of keyword arguments.
+
+        Returns:
+            Mapping of option name to value, accepted by DecodeOptions(**d)
+        """
+        return {f.name: getattr(self, f.name) for f in fields(self)}
 
 
 @dataclass
@@ -1,8 +1,36 @@
 """Decoders module for TOON Converter - Official TOON v2.0 Specification."""
 
+from .lexer import tokenize_toon
 from .stream_decoder import StreamDecoder
 from .stream_lexer import StreamLexer
-from .toon_decoder import ToonDecoder, decode
+from .toon_decoder import (
+    ChunkedDecoder,
+    Span,
+    ToonDecoder,
+    decode,
+    decode_columns,
+    decode_fileobj,
+    decode_fragment,
+    decode_rows,
+    decode_spans,
+    decode_typed_columns,
+    validate_toon,
+)
 
 
-__all__ = ["ToonDecoder", "StreamDecoder", "StreamLexer", "decode"]
+__all__ = [
+    "ToonDecoder",
+    "ChunkedDecoder",
+    "Span",
+    "StreamDecoder",
+    "StreamLexer",
+    "decode",
+    "decode_columns",
+    "decode_fileobj",
+    "decode_fragment",
+    "decode_rows",
+    "decode_spans",
+    "decode_typed_columns",
+    "tokenize_toon",
+    "validate_toon",
+]
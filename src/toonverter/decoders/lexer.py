@@ -4,10 +4,93 @@ Tokenizes TOON input into structured tokens for parsing.
 Handles indentation tracking, line-by-line scanning, and token classification.
 """
 
+import re
+import warnings
+from collections.abc import Iterable
 from dataclasses import dataclass
 from enum import Enum
+from functools import lru_cache
+from typing import Any
 
+from toonverter.core.exceptions import LineTooLongError, ValidationError
+from toonverter.core.spec import BOM, COMMENT_CHAR, DEFAULT_INDENT_SIZE
 from toonverter.encoders.indentation import detect_indentation
+from toonverter.encoders.string_encoder import decode_hex_escape
+
+
+# Leading "#!toon key=value" directive and the delimiter spellings it accepts
+_DIRECTIVE_PREFIX = "#!toon"
+_DIRECTIVE_DELIMITERS = {",": ",", "comma": ",", "|": "|", "pipe": "|", "\t": "\t", "tab": "\t"}
+
+# Array header bracket with optional delimiter marker: [N], [N|] or [N<TAB>]
+_HEADER_BRACKET_PATTERN = re.compile(r"\[(\d+)([|\t]?)\]")
+_EXPONENT_PATTERN = re.compile(r"-?\d+(?:\.\d+)?[eE][+-]?\d+")
+# Quote or escape that ends a run of plain quoted-string characters
+_QUOTED_STOP_PATTERN = re.compile(r'["\\]')
+
+# Characters of source shown on each side of an error position
+EXCERPT_RADIUS = 30
+
+
+@lru_cache(maxsize=8)
+def _identifier_stop_pattern(delimiter: str) -> re.Pattern[str]:
+    """Pattern matching the delimiter or special character that ends an unquoted run."""
+    return re.compile(f"[{re.escape(delimiter)}:\\[\\]{{}} \t]")
+
+
+def excerpt(line: str, column: int, radius: int = EXCERPT_RADIUS) -> str:
+    """Cut the part of a line around a column for use in an error message.
+
+    Keeps messages about very long lines (minified data pasted as one value)
+    short; each cut side is marked with ``...``.
+
+    Args:
+        line: Full source line
+        column: Zero-based column of the error
+        radius: Characters to keep on each side of the column
+
+    Returns:
+        The excerpt, the whole line if it is short enough
+
+    Examples:
+        >>> excerpt("short line", 3)
+        'short line'
+        >>> excerpt("x" * 100 + "!" + "y" * 100, 100, radius=3)
+        '...xxx!yy...'
+    """
+    start = max(0, column - radius)
+    end = min(len(line), column + radius)
+    prefix = "..." if start > 0 else ""
+    suffix = "..." if end < len(line) else ""
+    return f"{prefix}{line[start:end]}{suffix}"
+
+
+def parse_number(text: str) -> int | float | None:
+    """Parse an unquoted number literal.
+
+    Integers stay exact at any size; literals with a fraction or an exponent
+    become floats.
+
+    Args:
+        text: Unquoted token text
+
+    Returns:
+        The number, or None if the text is not a number literal
+
+    Examples:
+        >>> parse_number("9223372036854775808")
+        9223372036854775808
+        >>> parse_number("1e20")
+        1e+20
+        >>> parse_number("e5") is None
+        True
+    """
+    try:
+        if "." in text or _EXPONENT_PATTERN.fullmatch(text):
+            return float(text)
+        return int(text)
+    except ValueError:
+        return None
 
 
 class TokenType(Enum):
@@ -39,6 +122,8 @@ class TokenType(Enum):
     # Special
     IDENTIFIER = "identifier"  # Unquoted key or value
     QUOTED_STRING = "quoted_string"  # "value"
+    COMMENT = "comment"  # # to end of line
+    ERROR = "error"  # Untokenizable tabular row, only with recover_rows
 
 
 @dataclass
@@ -55,26 +140,161 @@ class Token:
         return f"Token({self.type.name}, {self.value!r}, L{self.line}:C{self.column})"
 
 
+def is_blank_line(line: str) -> bool:
+    """Check whether a line has no content (whitespace only or a full-line comment).
+
+    Args:
+        line: Raw line
+
+    Returns:
+        True if the line should be skipped
+
+    Examples:
+        >>> is_blank_line("   # note")
+        True
+        >>> is_blank_line("a: 1 # note")
+        False
+    """
+    stripped = line.strip()
+    return not stripped or stripped.startswith("#")
+
+
+def infer_indent_size(lines: Iterable[str]) -> int | None:
+    """Infer the indent unit from the first indented line.
+
+    Blank and comment-only lines are ignored, so the first indented line is
+    one level below an unindented line and its indentation is one unit.
+
+    Args:
+        lines: Lines of the document
+
+    Returns:
+        Number of spaces per indent level, or None if no line is indented
+
+    Examples:
+        >>> infer_indent_size(["a:", "", "    b: 1", "        c: 2"])
+        4
+        >>> infer_indent_size(["a: 1", "b: 2"]) is None
+        True
+    """
+    for line in lines:
+        if not is_blank_line(line):
+            indent = detect_indentation(line)
+            if indent:
+                return indent
+    return None
+
+
+def parse_directive(line: str) -> dict[str, int | str]:
+    """Parse a ``#!toon key=value ...`` directive from the first line of a document.
+
+    Supported settings are ``indent`` (spaces per level) and ``delimiter``
+    (``,``/``comma``, ``|``/``pipe`` or ``tab``). Unknown settings are ignored so
+    newer producers stay readable.
+
+    Args:
+        line: First line of the document
+
+    Returns:
+        Lexer settings ({"indent_size": ..., "delimiter": ...}); empty if the
+        line is not a directive
+
+    Raises:
+        ValueError: If a setting has an invalid value
+
+    Examples:
+        >>> parse_directive("#!toon indent=4 delimiter=|")
+        {'indent_size': 4, 'delimiter': '|'}
+        >>> parse_directive("a: 1")
+        {}
+    """
+    stripped = line.strip()
+    if not stripped.startswith(_DIRECTIVE_PREFIX):
+        return {}
+
+    settings: dict[str, int | str] = {}
+    for item in stripped[len(_DIRECTIVE_PREFIX) :].split():
+        name, _, value = item.partition("=")
+        if name == "indent":
+            if not value.isdigit() or int(value) < 1:
+                msg = f"Invalid indent in #!toon directive: {value!r}"
+                raise ValueError(msg)
+            settings["indent_size"] = int(value)
+        elif name == "delimiter":
+            if value not in _DIRECTIVE_DELIMITERS:
+                msg = f"Invalid delimiter in #!toon directive: {value!r}"
+                raise ValueError(msg)
+            settings["delimiter"] = _DIRECTIVE_DELIMITERS[value]
+    return settings
+
+
 class ToonLexer:
     """Lexer for tokenizing TOON format strings.
 
     Converts TOON text into a stream of tokens that can be parsed.
     """
 
-    def __init__(self, text: str, indent_size: int = 2) -> None:
+    def __init__(
+        self,
+        text: str,
+        indent_size: int | None = 2,
+        delimiter: str = ",",
+        max_indent_depth: int | None = None,
+        strict_indent: bool = False,
+        max_line_length: int | None = None,
+        recover_rows: bool = False,
+        fragment: bool = False,
+    ) -> None:
         """Initialize lexer.
 
+        A leading ``#!toon`` directive overrides ``indent_size`` and ``delimiter``.
+        A leading byte order mark (U+FEFF) is ignored.
+
+        Runs of plain characters are scanned with regex and ``str.find``
+        instead of per-character loops.
+
         Args:
             text: TOON formatted text
-            indent_size: Number of spaces per indent level
+            indent_size: Number of spaces per indent level, or None to infer it
+                from the first indented line (see :func:`infer_indent_size`)
+            delimiter: Document delimiter used outside declared array headers
+            max_indent_depth: Reject lines indented deeper than this many levels
+            strict_indent: Reject lines whose indentation does not follow the
+                indent unit (see :meth:`check_indent_unit`)
+            max_line_length: Reject lines longer than this many characters
+            recover_rows: Emit a single ERROR token, whose value is the error
+                message, for a tabular row that cannot be tokenized instead
+                of raising
+            fragment: Skip the indent unit check on the first non-blank line,
+                where a fragment embedded in other text may start at any column
         """
+        text = text.removeprefix(BOM)
         self.text = text
-        self.indent_size = indent_size
         self.lines = text.split("\n")
+        directive = parse_directive(self.lines[0])
+        if indent_size is None:
+            indent_size = infer_indent_size(self.lines) or DEFAULT_INDENT_SIZE
+        self.indent_size = int(directive.get("indent_size", indent_size))
+        self.delimiter = str(directive.get("delimiter", delimiter))
+        self.max_indent_depth = max_indent_depth
+        self.strict_indent = strict_indent
+        self.max_line_length = max_line_length
+        self.recover_rows = recover_rows
+        self.fragment = fragment
+        # Indentation stripped from the line being scanned, for error columns
+        self.line_offset = 0
         self.current_line = 0
         self.current_column = 0
         self.current_indent = 0
         self.indent_stack: list[int] = [0]
+        # Delimiter declared by the most recent array header on the current line
+        self.header_delimiter = ","
+        # (header indent level, delimiter) for rows under an open tabular header
+        self.tabular_scope: tuple[int, str] | None = None
+        # (key indent level, delimiter) for lines continuing a wrapped inline array
+        self.inline_scope: tuple[int, str] | None = None
+        # Tokens emitted so far; the tokens before the failing line if tokenize() raised
+        self.tokens: list[Token] = []
 
     def tokenize(self) -> list[Token]:
         """Tokenize entire input.
@@ -83,50 +303,72 @@ class ToonLexer:
             List of tokens
         """
         tokens: list[Token] = []
+        self.tokens = tokens
 
         for line_num, line in enumerate(self.lines):
             self.current_line = line_num
             self.current_column = 0
+            self.check_line_length(line, line_num)
 
-            # Skip empty lines
-            if not line.strip():
+            # Skip empty and comment-only lines
+            if is_blank_line(line):
                 continue
 
             # Handle indentation
             indent = detect_indentation(line)
             indent_level = indent // self.indent_size
+            self.check_indent_depth(indent_level, line_num)
+            if tokens or not self.fragment:
+                self.check_indent_unit(indent, line_num)
 
-            # Emit indent/dedent tokens
+            # Emit one indent/dedent token per level crossed; each token's
+            # indent_level is the level reached after that single step
             if indent_level > self.current_indent:
                 # Indent
-                for _ in range(indent_level - self.current_indent):
+                for level in range(self.current_indent + 1, indent_level + 1):
                     tokens.append(
                         Token(
                             type=TokenType.INDENT,
                             value=None,
                             line=line_num,
                             column=0,
-                            indent_level=indent_level,
+                            indent_level=level,
                         )
                     )
                 self.current_indent = indent_level
 
             elif indent_level < self.current_indent:
                 # Dedent
-                for _ in range(self.current_indent - indent_level):
+                for level in range(self.current_indent - 1, indent_level - 1, -1):
                     tokens.append(
                         Token(
                             type=TokenType.DEDENT,
                             value=None,
                             line=line_num,
                             column=0,
-                            indent_level=indent_level,
+                            indent_level=level,
                         )
                     )
                 self.current_indent = indent_level
 
             # Tokenize line content
-            line_tokens = self._tokenize_line(line.strip(), line_num, indent_level)
+            delimiter = self.delimiter_for_line(indent_level)
+            self.line_offset = indent
+            try:
+                line_tokens = self._tokenize_line(line.strip(), line_num, indent_level, delimiter)
+            except ValueError as e:
+                if not (self.recover_rows and self.tabular_scope is not None):
+                    raise
+                line_tokens = [
+                    Token(
+                        type=TokenType.ERROR,
+                        value=str(e),
+                        line=line_num,
+                        column=0,
+                        indent_level=indent_level,
+                    )
+                ]
+            self.update_tabular_scope(line_tokens, indent_level)
             tokens.extend(line_tokens)
 
             # Add newline token
@@ -142,16 +384,16 @@ class ToonLexer:
 
         # Add final dedents if needed
         while self.current_indent > 0:
+            self.current_indent -= 1
             tokens.append(
                 Token(
                     type=TokenType.DEDENT,
                     value=None,
                     line=len(self.lines),
                     column=0,
-                    indent_level=0,
+                    indent_level=self.current_indent,
                 )
             )
-            self.current_indent -= 1
 
         # Add EOF token
         tokens.append(
@@ -166,13 +408,144 @@ class ToonLexer:
 
         return tokens
 
-    def _tokenize_line(self, line: str, line_num: int, indent_level: int) -> list[Token]:
+    def check_line_length(self, line: str, line_num: int) -> None:
+        """Enforce max_line_length for a line before any of it is tokenized.
+
+        Args:
+            line: Line content
+            line_num: Zero-based line number
+
+        Raises:
+            LineTooLongError: If the line is longer than max_line_length
+        """
+        if self.max_line_length is not None and len(line) > self.max_line_length:
+            msg = (
+                f"Line {line_num + 1} is {len(line)} characters long, "
+                f"exceeding max_line_length {self.max_line_length}"
+            )
+            raise LineTooLongError(msg)
+
+    def error_position(self, line: str, column: int, line_num: int) -> str:
+        """Describe a position in the line being scanned for an error message.
+
+        Args:
+            line: Stripped line content passed to the scanner
+            column: Zero-based column within ``line``
+            line_num: Zero-based line number
+
+        Returns:
+            Text such as ``at line 3, column 17 near '...'`` with 1-based numbers
+        """
+        return (
+            f"at line {line_num + 1}, column {self.line_offset + column + 1} "
+            f"near {excerpt(line, column)!r}"
+        )
+
+    def check_indent_depth(self, indent_level: int, line_num: int) -> None:
+        """Enforce max_indent_depth for a line before any of it is tokenized.
+
+        Args:
+            indent_level: Indent level of the line
+            line_num: Zero-based line number
+
+        Raises:
+            ValidationError: If the line is indented deeper than max_indent_depth
+        """
+        if self.max_indent_depth is not None and indent_level > self.max_indent_depth:
+            msg = (
+                f"Maximum indentation depth {self.max_indent_depth} exceeded "
+                f"at line {line_num + 1}"
+            )
+            raise ValidationError(msg)
+
+    def check_indent_unit(self, indent: int, line_num: int) -> None:
+        """Reject a line whose leading spaces are not a whole number of levels.
+
+        Without this check such lines are silently rounded down to the level
+        below, which turns a pasted section using a different indent unit
+        into phantom nesting far from the cause.
+
+        Args:
+            indent: Leading spaces of the line
+            line_num: Zero-based line number
+
+        Raises:
+            ValidationError: If strict_indent is set and indent is not a
+                multiple of indent_size; otherwise the same message is a warning
+        """
+        if indent % self.indent_size:
+            msg = (
+                f"Inconsistent indentation at line {line_num + 1}: expected a multiple "
+                f"of {self.indent_size} spaces, found {indent}"
+            )
+            if self.strict_indent:
+                raise ValidationError(msg)
+            warnings.warn(msg, stacklevel=2)
+
+    def delimiter_for_line(self, indent_level: int) -> str:
+        """Get the delimiter active at the start of a line.
+
+        Rows nested under a tabular header, and lines continuing an inline
+        array, use the delimiter declared in that header; every other line
+        starts out with the document delimiter.
+
+        Args:
+            indent_level: Indent level of the line
+
+        Returns:
+            Active delimiter character
+        """
+        if self.tabular_scope is not None:
+            header_level, delimiter = self.tabular_scope
+            if indent_level > header_level:
+                return delimiter
+            self.tabular_scope = None
+        if self.inline_scope is not None:
+            key_level, delimiter = self.inline_scope
+            if indent_level > key_level:
+                return delimiter
+            self.inline_scope = None
+        return self.delimiter
+
+    def update_tabular_scope(self, line_tokens: list[Token], indent_level: int) -> None:
+        """Open a delimiter scope if the line ends with a tabular header or inline array.
+
+        Deeper lines after an inline array may continue it, so they are
+        scanned with its delimiter. Their level is compared with the array's
+        key, which on a ``- key[N]: ...`` line sits one level below the hyphen.
+
+        Args:
+            line_tokens: Tokens of the line just scanned
+            indent_level: Indent level of that line
+        """
+        line_tokens = [t for t in line_tokens if t.type != TokenType.COMMENT]
+        if (
+            len(line_tokens) >= 2
+            and line_tokens[-1].type == TokenType.COLON
+            and line_tokens[-2].type == TokenType.BRACE_END
+        ):
+            self.tabular_scope = (indent_level, self.header_delimiter)
+            return
+        for i in range(len(line_tokens) - 2, 0, -1):
+            if line_tokens[i].type == TokenType.COLON:
+                if line_tokens[i - 1].type == TokenType.ARRAY_END:
+                    keyed = (
+                        line_tokens[0].type == TokenType.DASH
+                        and line_tokens[1].type != TokenType.ARRAY_START
+                    )
+                    self.inline_scope = (indent_level + keyed, self.header_delimiter)
+                return
+
+    def _tokenize_line(
+        self, line: str, line_num: int, indent_level: int, delimiter: str = ","
+    ) -> list[Token]:
         """Tokenize a single line.
 
         Args:
             line: Line content (stripped)
             line_num: Line number
             indent_level: Current indent level
+            delimiter: Delimiter active at the start of the line
 
         Returns:
             List of tokens for this line
@@ -183,31 +556,45 @@ class ToonLexer:
         while i < len(line):
             char = line[i]
 
+            # Active delimiter (comma, pipe or tab)
+            if char == delimiter:
+                tokens.append(
+                    Token(
+                        type=TokenType.COMMA,
+                        value=char,
+                        line=line_num,
+                        column=i,
+                        indent_level=indent_level,
+                    )
+                )
+                i += 1
+                continue
+
             # Skip whitespace
             if char in (" ", "\t"):
                 i += 1
                 continue
 
-            # Colon
-            if char == ":":
+            # Comment: "#" at line start or after whitespace runs to end of line;
+            # inside an unquoted value ("a#b") it is a literal character
+            if char == "#" and (i == 0 or line[i - 1] in (" ", "\t")):
                 tokens.append(
                     Token(
-                        type=TokenType.COLON,
-                        value=":",
+                        type=TokenType.COMMENT,
+                        value=line[i + 1 :].strip(),
                         line=line_num,
                         column=i,
                         indent_level=indent_level,
                     )
                 )
-                i += 1
-                continue
+                break
 
-            # Comma
-            if char == ",":
+            # Colon
+            if char == ":":
                 tokens.append(
                     Token(
-                        type=TokenType.COMMA,
-                        value=",",
+                        type=TokenType.COLON,
+                        value=":",
                         line=line_num,
                         column=i,
                         indent_level=indent_level,
@@ -218,8 +605,8 @@ class ToonLexer:
 
             # Dash (list marker)
             if char == "-" and (i == 0 or line[i - 1] in (" ", "\t")):
-                # Check if it's a list marker (followed by space)
-                if i + 1 < len(line) and line[i + 1] == " ":
+                # Check if it's a list marker (followed by space, or alone for an empty object)
+                if i + 1 == len(line) or line[i + 1] == " ":
                     tokens.append(
                         Token(
                             type=TokenType.DASH,
@@ -232,6 +619,15 @@ class ToonLexer:
                     i += 2  # Skip dash and space
                     continue
 
+            # Array header bracket: the declared delimiter applies to the rest of the line
+            header = _HEADER_BRACKET_PATTERN.match(line, i) if char == "[" else None
+            if header:
+                delimiter = header.group(2) or self.delimiter
+                self.header_delimiter = delimiter
+                tokens.extend(self._header_bracket_tokens(header, line_num, indent_level))
+                i = header.end()
+                continue
+
             # Array/brace markers
             if char == "[":
                 tokens.append(
@@ -293,12 +689,63 @@ class ToonLexer:
                 continue
 
             # Identifier or unquoted value
-            token, new_i = self._scan_identifier(line, i, line_num, indent_level)
+            token, new_i = self._scan_identifier(line, i, line_num, indent_level, delimiter)
             tokens.append(token)
             i = new_i
 
         return tokens
 
+    def _header_bracket_tokens(
+        self, header: re.Match[str], line_num: int, indent_level: int
+    ) -> list[Token]:
+        """Build tokens for an array header bracket such as [3] or [3|].
+
+        Args:
+            header: Match of the header bracket pattern
+            line_num: Line number
+            indent_level: Indent level
+
+        Returns:
+            ARRAY_START, NUMBER, optional delimiter marker and ARRAY_END tokens
+        """
+        start = header.start()
+        tokens = [
+            Token(
+                type=TokenType.ARRAY_START,
+                value="[",
+                line=line_num,
+                column=start,
+                indent_level=indent_level,
+            ),
+            Token(
+                type=TokenType.NUMBER,
+                value=int(header.group(1)),
+                line=line_num,
+                column=start + 1,
+                indent_level=indent_level,
+            ),
+        ]
+        if header.group(2):
+            tokens.append(
+                Token(
+                    type=TokenType.IDENTIFIER,
+                    value=header.group(2),
+                    line=line_num,
+                    column=header.end() - 2,
+                    indent_level=indent_level,
+                )
+            )
+        tokens.append(
+            Token(
+                type=TokenType.ARRAY_END,
+                value="]",
+                line=line_num,
+                column=header.end() - 1,
+                indent_level=indent_level,
+            )
+        )
+        return tokens
+
     def _scan_quoted_string(
         self, line: str, start: int, line_num: int, indent_level: int
     ) -> tuple[Token, int]:
@@ -314,6 +761,19 @@ class ToonLexer:
             Tuple of (token, next_position)
         """
         i = start + 1  # Skip opening quote
+        end = line.find('"', i)
+        if end != -1 and line.find("\\", i, end) == -1:
+            return (
+                Token(
+                    type=TokenType.QUOTED_STRING,
+                    value=line[i:end],
+                    line=line_num,
+                    column=start,
+                    indent_level=indent_level,
+                ),
+                end + 1,
+            )
+
         chars: list[str] = []
 
         while i < len(line):
@@ -333,12 +793,20 @@ class ToonLexer:
                         chars.append("\r")
                     elif next_char == "t":
                         chars.append("\t")
+                    elif next_char == "x":
+                        try:
+                            chars.append(decode_hex_escape(line, i + 2))
+                        except ValueError as e:
+                            msg = f"{e} {self.error_position(line, i, line_num)}"
+                            raise ValueError(msg) from e
+                        i += 2
                     else:
-                        msg = f"Invalid escape sequence: \\{next_char}"
+                        position = self.error_position(line, i, line_num)
+                        msg = f"Invalid escape sequence \\{next_char} {position}"
                         raise ValueError(msg)
                     i += 2
                 else:
-                    msg = "Unterminated escape sequence"
+                    msg = f"Unterminated escape sequence {self.error_position(line, i, line_num)}"
                     raise ValueError(msg)
 
             elif char == '"':
@@ -355,14 +823,16 @@ class ToonLexer:
                     i + 1,
                 )
             else:
-                chars.append(char)
-                i += 1
+                stop = _QUOTED_STOP_PATTERN.search(line, i)
+                end = len(line) if stop is None else stop.start()
+                chars.append(line[i:end])
+                i = end
 
-        msg = f"Unterminated quoted string at line {line_num}"
+        msg = f"Unterminated quoted string {self.error_position(line, start, line_num)}"
         raise ValueError(msg)
 
     def _scan_identifier(
-        self, line: str, start: int, line_num: int, indent_level: int
+        self, line: str, start: int, line_num: int, indent_level: int, delimiter: str = ","
     ) -> tuple[Token, int]:
         """Scan an identifier or unquoted value.
 
@@ -371,27 +841,25 @@ class ToonLexer:
             start: Start position
             line_num: Line number
             indent_level: Indent level
+            delimiter: Active delimiter
 
         Returns:
             Tuple of (token, next_position)
         """
-        i = start
-        chars: list[str] = []
-
         # Scan until delimiter or special character
-        while i < len(line):
-            char = line[i]
-            if char in (":", ",", "[", "]", "{", "}", " ", "\t"):
-                break
-            chars.append(char)
-            i += 1
-
-        value_str = "".join(chars)
-
-        # Determine token type
-        if value_str == "true":
+        stop = _identifier_stop_pattern(delimiter).search(line, start)
+        i = len(line) if stop is None else stop.start()
+        value_str = line[start:i]
+
+        # Determine token type; keys such as "1: x", "1e5[2]: ..." or "null: x"
+        # keep their text
+        is_key = i < len(line) and line[i] in (":", "[")
+        if is_key:
+            token_type = TokenType.IDENTIFIER
+            value: str | int | float | bool | None = value_str
+        elif value_str == "true":
             token_type = TokenType.BOOLEAN
-            value: str | int | float | bool | None = True
+            value = True
         elif value_str == "false":
             token_type = TokenType.BOOLEAN
             value = False
@@ -399,18 +867,14 @@ class ToonLexer:
             token_type = TokenType.NULL
             value = None
         else:
-            # Try to parse as number
-            try:
-                if "." in value_str:
-                    value = float(value_str)
-                    token_type = TokenType.NUMBER
-                else:
-                    value = int(value_str)
-                    token_type = TokenType.NUMBER
-            except ValueError:
+            number = parse_number(value_str)
+            if number is None:
                 # It's an identifier/string
                 token_type = TokenType.IDENTIFIER
                 value = value_str
+            else:
+                value = number
+                token_type = TokenType.NUMBER
 
         return (
             Token(
@@ -422,3 +886,88 @@ class ToonLexer:
             ),
             i,
         )
+
+
+_LAYOUT_TYPES = (TokenType.INDENT, TokenType.DEDENT, TokenType.NEWLINE, TokenType.EOF)
+
+
+def tokenize_toon(text: str, indent_size: int | None = 2) -> list[dict[str, Any]]:
+    """Tokenize a TOON document for editor tooling such as syntax highlighting.
+
+    Every token is returned, including layout (``indent``, ``dedent``,
+    ``newline``, ``eof``) and ``comment`` tokens; unlike the parser's token
+    stream this includes full-line comments. The ``type`` of each token is
+    the value of its :class:`TokenType`. Lines and columns are 0-based
+    positions in ``text``. Errors do not raise: the tokens of the lines
+    before the failing one are returned, followed by one ``error`` token
+    whose value is the message.
+
+    Args:
+        text: TOON formatted text
+        indent_size: Spaces per indent level, or None to infer it
+
+    Returns:
+        List of dicts with ``type``, ``value``, ``line``, ``column`` and
+        ``indent_level`` keys
+
+    Examples:
+        >>> [t["type"] for t in tokenize_toon("a: 1")]
+        ['identifier', 'colon', 'number', 'newline', 'eof']
+    """
+    try:
+        lexer = ToonLexer(text, indent_size=indent_size)
+    except ValueError as e:
+        # Invalid #!toon directive on the first line
+        return [_token_dict(Token(TokenType.ERROR, str(e), 0, 0, 0), 0)]
+    try:
+        with warnings.catch_warnings():
+            # Lenient indentation warnings are for decoding, not highlighting
+            warnings.simplefilter("ignore")
+            tokens = lexer.tokenize()
+        error = None
+    except (ValueError, ValidationError) as e:
+        tokens = lexer.tokens
+        error = Token(
+            type=TokenType.ERROR,
+            value=str(e),
+            line=lexer.current_line,
+            column=0,
+            indent_level=lexer.current_indent,
+        )
+
+    last_line = len(lexer.lines) if error is None else error.line
+    comments = [
+        Token(
+            type=TokenType.COMMENT,
+            value=line.strip()[1:].strip(),
+            line=line_num,
+            column=0,
+            # Comment lines are skipped by the lexer, so tabs in their indentation are fine
+            indent_level=(len(line) - len(line.lstrip(" "))) // lexer.indent_size,
+        )
+        for line_num, line in enumerate(lexer.lines[:last_line])
+        if line.strip().startswith(COMMENT_CHAR)
+    ]
+
+    result = []
+    for token in sorted([*tokens, *comments], key=lambda t: t.line):
+        column = token.column
+        # Content columns are relative to the line with its indentation stripped
+        if token.type not in _LAYOUT_TYPES:
+            line = lexer.lines[token.line]
+            column += len(line) - len(line.lstrip())
+        result.append(_token_dict(token, column))
+    if error is not None:
+        result.append(_token_dict(error, error.column))
+    return result
+
+
+def _token_dict(token: Token, column: int) -> dict[str, Any]:
+    """Plain-dict form of a token for :func:`tokenize_toon`."""
+    return {
+        "type": token.type.value,
+        "value": token.value,
+        "line": token.line,
+        "column": column,
+        "indent_level": token.indent_level,
+    }
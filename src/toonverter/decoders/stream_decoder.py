@@ -11,7 +11,11 @@ from toonverter.core.exceptions import DecodingError
 from toonverter.core.spec import RootForm, ToonDecodeOptions
 from toonverter.decoders.lexer import Token, TokenType
 from toonverter.decoders.stream_lexer import StreamLexer
-from toonverter.decoders.toon_decoder import ToonDecoder
+from toonverter.decoders.toon_decoder import ToonDecoder, _to_pairs
+
+
+# Errors the lexer and parser raise on malformed input, reported as DecodingError
+_PARSE_ERRORS = (ValueError, IndexError, KeyError, OverflowError)
 
 
 class PeekableIterator:
@@ -54,10 +58,28 @@ class StreamDecoder:
     def decode_stream(self, stream: Iterator[str]) -> Iterator[Any]:
         """Decode a stream of TOON lines.
 
-        Assumes the root is an Array. Yields decoded items one by one.
+        Assumes the root is an Array. Yields decoded items one by one, in the
+        form :meth:`ToonDecoder.decode` gives them: with ``as_pairs`` every
+        object is a list of ``(key, value)`` tuples, keeping repeated keys
+        under ``duplicate_keys="collect"``.
+
+        Raises:
+            DecodingError: If the stream is not valid TOON. Errors raised by
+                ``stream`` itself propagate unchanged.
         """
-        lexer = StreamLexer(stream)
-        tokens = PeekableIterator(lexer.tokenize())
+        for item in self._decode_stream(stream):
+            yield _to_pairs(item) if self.options.as_pairs else item
+
+    def _decode_stream(self, stream: Iterator[str]) -> Iterator[Any]:
+        """Decode the stream, wrapping lexer and parser errors as DecodingError."""
+        lexer = StreamLexer(
+            stream,
+            indent_size=self.options.indent_size,
+            max_indent_depth=self.options.max_indent_depth,
+            strict_indent=self.options.strict,
+            max_line_length=self.options.max_line_length,
+        )
+        tokens = PeekableIterator(t for t in self._tokens(lexer) if t.type != TokenType.COMMENT)
 
         # Skip initial structural tokens
         while True:
@@ -83,14 +105,37 @@ class StreamDecoder:
             self.chunk_decoder.tokens = all_tokens
             self.chunk_decoder.pos = 0
 
-            root_form = self.chunk_decoder._detect_root_form()
-            if root_form == RootForm.ARRAY:
-                # Should have been caught, but safe fallback
-                yield self.chunk_decoder._parse_root_array()
-            elif root_form == RootForm.PRIMITIVE:
-                yield self.chunk_decoder._parse_root_primitive()
-            else:
-                yield self.chunk_decoder._parse_root_object()
+            try:
+                root_form = self.chunk_decoder._detect_root_form()
+                if root_form == RootForm.ARRAY:
+                    # Should have been caught, but safe fallback
+                    root = self.chunk_decoder._parse_root_array()
+                elif root_form == RootForm.PRIMITIVE:
+                    root = self.chunk_decoder._parse_root_primitive()
+                else:
+                    root = self.chunk_decoder._parse_root_object()
+            except RecursionError as e:
+                msg = "Document is nested too deeply to decode"
+                raise DecodingError(msg) from e
+            except _PARSE_ERRORS as e:
+                msg = f"Failed to decode TOON stream: {e}"
+                raise DecodingError(msg) from e
+            yield root
+
+    def _tokens(self, lexer: StreamLexer) -> Iterator[Token]:
+        """Tokenize the lexer's source line by line.
+
+        Only the lexer's own errors are wrapped; the caller's iterator is read
+        outside the ``try``.
+        """
+        for line in lexer.source:
+            try:
+                line_tokens = lexer.tokenize_line(line)
+            except _PARSE_ERRORS as e:
+                msg = f"Failed to decode TOON stream: {e}"
+                raise DecodingError(msg) from e
+            yield from line_tokens
+        yield from lexer.finish()
 
     def _parse_stream_array(self, tokens: PeekableIterator) -> Iterator[Any]:
         """Parse root array tokens and yield items."""
@@ -106,8 +151,10 @@ class StreamDecoder:
                 return  # Empty array
             msg = "Expected array length"
             raise DecodingError(msg)
-
-        length = int(length_token.value)  # type: ignore
+        length = length_token.value
+        if not isinstance(length, int) or isinstance(length, bool):
+            msg = "Expected array length"
+            raise DecodingError(msg)
 
         # 3. Skip to Colon (handling fields/delimiters)
         while True:
@@ -132,21 +179,21 @@ class StreamDecoder:
 
             # List Item Marker
             if t_peeked.type == TokenType.DASH:
-                _ = next(tokens)  # Consume '-'
+                dash = next(tokens)  # Consume '-'
 
                 # Capture value tokens
-                val_tokens = self._collect_value_tokens(tokens)
+                val_tokens = self._collect_value_tokens(tokens, inline=False)
                 if val_tokens:
-                    item = self._decode_chunk(val_tokens)
+                    item = self._decode_chunk(val_tokens, dash.indent_level)
                     yield item
                     items_yielded += 1
 
             # Inline Item (no dash)
             else:
                 # Primitive or inline object in inline array
-                val_tokens = self._collect_value_tokens(tokens)
+                val_tokens = self._collect_value_tokens(tokens, inline=True)
                 if val_tokens:
-                    item = self._decode_chunk(val_tokens)
+                    item = self._decode_chunk(val_tokens, val_tokens[0].indent_level)
                     yield item
                     items_yielded += 1
 
@@ -155,8 +202,13 @@ class StreamDecoder:
                 if nxt and nxt.type == TokenType.COMMA:
                     next(tokens)
 
-    def _collect_value_tokens(self, tokens: PeekableIterator) -> list[Token]:
-        """Collect tokens for a single value."""
+    def _collect_value_tokens(self, tokens: PeekableIterator, inline: bool) -> list[Token]:
+        """Collect tokens for a single value.
+
+        Args:
+            tokens: Token stream positioned at the value
+            inline: Whether the value is an inline array item ended by a delimiter
+        """
         collected: list[Token] = []
         balance = 0  # Brackets
 
@@ -177,8 +229,8 @@ class StreamDecoder:
 
             # Stop conditions
             if balance == 0:
-                if t.type == TokenType.DASH:
-                    break  # Next item
+                if t.type == TokenType.DASH and t.indent_level <= start_indent:
+                    break  # Next item (deeper hyphens belong to nested lists)
 
                 # DEDENT check: strict inequality for robustness?
                 # If we drop BELOW the start indent, we are out.
@@ -199,7 +251,7 @@ class StreamDecoder:
                 # Correct logic: We stop when we dedent to < start_indent.
 
                 # Inline array item separator
-                if t.type == TokenType.COMMA:
+                if inline and t.type == TokenType.COMMA:
                     break
 
                 # Newline handling for primitives
@@ -224,12 +276,17 @@ class StreamDecoder:
 
         return collected
 
-    def _decode_chunk(self, tokens: list[Token]) -> Any:
-        """Decode a list of tokens using standard decoder."""
+    def _decode_chunk(self, tokens: list[Token], level: int) -> Any:
+        """Decode the tokens of one list item using the standard decoder.
+
+        Args:
+            tokens: Item tokens following the list marker
+            level: Indent level of the item's hyphen line
+        """
         self.chunk_decoder.tokens = tokens
         self.chunk_decoder.pos = 0
 
-        # Find the first content token to determine base depth
+        # Skip chunks without content
         start = 0
         while start < len(tokens) and tokens[start].type in (TokenType.NEWLINE, TokenType.INDENT):
             start += 1
@@ -238,15 +295,12 @@ class StreamDecoder:
             # Just whitespace/newlines? Return None?
             return None
 
-        # Detect form again?
-        # We can just use _parse_value(depth=0) because ToonDecoder logic
-        # calculates relative depth or uses absolute checks.
-
-        # Actually, we should try to parse the *root* of this chunk.
-        # If it looks like an object (starts with key:), parse object.
-        # If array, parse array.
-        # If primitive, parse primitive.
-
-        # Simplest hack: wrap logic
-        # We rely on _parse_value which handles dispatch.
-        return self.chunk_decoder._parse_value(depth=0)
+        # The dash has already been consumed, so the chunk is a list item body
+        try:
+            return self.chunk_decoder._parse_list_item(level)
+        except RecursionError as e:
+            msg = "List item is nested too deeply to decode"
+            raise DecodingError(msg) from e
+        except _PARSE_ERRORS as e:
+            msg = f"Failed to decode TOON stream: {e}"
+            raise DecodingError(msg) from e
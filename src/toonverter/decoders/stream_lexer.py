@@ -6,7 +6,14 @@ Tokenizes TOON input line-by-line using a generator approach for memory efficien
 from collections.abc import Iterator
 from typing import TextIO
 
-from toonverter.decoders.lexer import Token, TokenType, ToonLexer
+from toonverter.core.spec import DEFAULT_INDENT_SIZE
+from toonverter.decoders.lexer import (
+    Token,
+    TokenType,
+    ToonLexer,
+    is_blank_line,
+    parse_directive,
+)
 from toonverter.encoders.indentation import detect_indentation
 
 
@@ -16,15 +23,29 @@ class StreamLexer:
     Converts TOON input stream into a generator of tokens.
     """
 
-    def __init__(self, source: Iterator[str] | TextIO, indent_size: int = 2) -> None:
+    def __init__(
+        self,
+        source: Iterator[str] | TextIO,
+        indent_size: int | None = 2,
+        max_indent_depth: int | None = None,
+        strict_indent: bool = False,
+        max_line_length: int | None = None,
+    ) -> None:
         """Initialize streaming lexer.
 
         Args:
             source: Iterator yielding lines of text (e.g. file object)
-            indent_size: Number of spaces per indent level
+            indent_size: Number of spaces per indent level, or None to take it
+                from the first indented line
+            max_indent_depth: Reject lines indented deeper than this many levels
+            strict_indent: Reject lines indented inconsistently with the indent unit
+            max_line_length: Reject lines longer than this many characters
         """
         self.source = source
-        self.indent_size = indent_size
+        # Until the first indented line is seen, unindented lines read the same
+        # under any unit
+        self._infer_indent = indent_size is None
+        self.indent_size = DEFAULT_INDENT_SIZE if indent_size is None else indent_size
         self.current_line = 0
         self.current_indent = 0
         # We assume standard ToonLexer logic for line tokenization
@@ -33,7 +54,13 @@ class StreamLexer:
         # Let's inherit or compose?
         # Inheritance is tricky because __init__ differs.
         # Composition: we delegate line parsing.
-        self._line_lexer = ToonLexer("")  # Dummy, we will use its _tokenize_line method if possible
+        # Dummy, we will use its _tokenize_line method if possible
+        self._line_lexer = ToonLexer(
+            "",
+            max_indent_depth=max_indent_depth,
+            strict_indent=strict_indent,
+            max_line_length=max_line_length,
+        )
         # Actually, _tokenize_line is dependent on self for raising errors?
         # It raises ValueError directly.
         # But _scan_quoted_string uses `line_num` passed as arg.
@@ -41,83 +68,137 @@ class StreamLexer:
     def tokenize(self) -> Iterator[Token]:
         """Yield tokens one by one."""
         for line in self.source:
-            # Handle potential trailing newlines from file reading
-            line_content = line.rstrip("\n")
-
-            # Skip empty lines (whitespace only)
-            if not line_content.strip():
-                self.current_line += 1
-                continue
-
-            # Handle indentation
-            indent = detect_indentation(line_content)
-            indent_level = indent // self.indent_size
-
-            # Emit indent/dedent tokens
-            if indent_level > self.current_indent:
-                for _ in range(indent_level - self.current_indent):
-                    yield Token(
+            yield from self.tokenize_line(line)
+        yield from self.finish()
+
+    def tokenize_line(self, line: str) -> list[Token]:
+        """Tokenize the next line of the source.
+
+        Args:
+            line: Line of text, with or without its trailing newline
+
+        Returns:
+            The line's INDENT/DEDENT, content and NEWLINE tokens
+        """
+        tokens: list[Token] = []
+        # Handle potential trailing newlines from file reading
+        line_content = line.rstrip("\n")
+        self._line_lexer.check_line_length(line_content, self.current_line)
+
+        # A leading #!toon directive overrides indent size and delimiter
+        if self.current_line == 0:
+            directive = parse_directive(line_content)
+            if "indent_size" in directive:
+                self._infer_indent = False
+            self.indent_size = int(directive.get("indent_size", self.indent_size))
+            self._line_lexer.indent_size = self.indent_size
+            self._line_lexer.delimiter = str(directive.get("delimiter", ","))
+
+        # Skip empty lines (whitespace only) and comment-only lines
+        if is_blank_line(line_content):
+            self.current_line += 1
+            return tokens
+
+        # Handle indentation
+        indent = detect_indentation(line_content)
+        if self._infer_indent and indent:
+            self._infer_indent = False
+            self.indent_size = indent
+            self._line_lexer.indent_size = indent
+        indent_level = indent // self.indent_size
+        self._line_lexer.check_indent_depth(indent_level, self.current_line)
+        self._line_lexer.check_indent_unit(indent, self.current_line)
+
+        # Emit indent/dedent tokens
+        # One token per level crossed, carrying the level after that step
+        if indent_level > self.current_indent:
+            for level in range(self.current_indent + 1, indent_level + 1):
+                tokens.append(
+                    Token(
                         type=TokenType.INDENT,
                         value=None,
                         line=self.current_line,
                         column=0,
-                        indent_level=indent_level,
+                        indent_level=level,
                     )
-                self.current_indent = indent_level
+                )
+            self.current_indent = indent_level
 
-            elif indent_level < self.current_indent:
-                for _ in range(self.current_indent - indent_level):
-                    yield Token(
+        elif indent_level < self.current_indent:
+            for level in range(self.current_indent - 1, indent_level - 1, -1):
+                tokens.append(
+                    Token(
                         type=TokenType.DEDENT,
                         value=None,
                         line=self.current_line,
                         column=0,
-                        indent_level=indent_level,
+                        indent_level=level,
                     )
-                self.current_indent = indent_level
-
-            # Tokenize line content
-            # We reuse ToonLexer's private methods if possible, or duplicate logic.
-            # Duplicating is safer to avoid state issues, and the logic is simple enough.
-            # But adhering to "DRY", let's try to use ToonLexer.
-
-            # ToonLexer._tokenize_line is stateless regarding the Lexer instance
-            # (it uses args for line_num etc).
-            # So we can reuse it.
-            stripped = line_content.strip()
-            if stripped == "-":
-                stripped = "- "
-
-            line_tokens = self._line_lexer._tokenize_line(stripped, self.current_line, indent_level)
-            yield from line_tokens
+                )
+            self.current_indent = indent_level
+
+        # Tokenize line content
+        # We reuse ToonLexer's private methods if possible, or duplicate logic.
+        # Duplicating is safer to avoid state issues, and the logic is simple enough.
+        # But adhering to "DRY", let's try to use ToonLexer.
+
+        # ToonLexer._tokenize_line is stateless regarding the Lexer instance
+        # (it uses args for line_num etc).
+        # So we can reuse it.
+        stripped = line_content.strip()
+        if stripped == "-":
+            stripped = "- "
+
+        delimiter = self._line_lexer.delimiter_for_line(indent_level)
+        self._line_lexer.line_offset = indent
+        line_tokens = self._line_lexer._tokenize_line(
+            stripped, self.current_line, indent_level, delimiter
+        )
+        self._line_lexer.update_tabular_scope(line_tokens, indent_level)
+        tokens.extend(line_tokens)
 
-            # Add newline token
-            yield Token(
+        # Add newline token
+        tokens.append(
+            Token(
                 type=TokenType.NEWLINE,
                 value=None,
                 line=self.current_line,
                 column=len(line_content),
                 indent_level=indent_level,
             )
+        )
 
-            self.current_line += 1
+        self.current_line += 1
+        return tokens
+
+    def finish(self) -> list[Token]:
+        """Close the open indentation levels once the source is exhausted.
 
+        Returns:
+            The closing DEDENT tokens and the EOF token
+        """
+        tokens: list[Token] = []
         # Add final dedents
         while self.current_indent > 0:
-            yield Token(
-                type=TokenType.DEDENT,
+            self.current_indent -= 1
+            tokens.append(
+                Token(
+                    type=TokenType.DEDENT,
+                    value=None,
+                    line=self.current_line,
+                    column=0,
+                    indent_level=self.current_indent,
+                )
+            )
+
+        # Add EOF token
+        tokens.append(
+            Token(
+                type=TokenType.EOF,
                 value=None,
                 line=self.current_line,
                 column=0,
                 indent_level=0,
             )
-            self.current_indent -= 1
-
-        # Add EOF token
-        yield Token(
-            type=TokenType.EOF,
-            value=None,
-            line=self.current_line,
-            column=0,
-            indent_level=0,
         )
+        return tokens
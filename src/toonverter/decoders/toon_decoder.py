@@ -4,12 +4,141 @@ Decodes TOON format strings to Python data structures following
 the official TOON v2.0 specification.
 """
 
-from typing import Any
+import json
+import re
+from collections.abc import Callable, Iterable, Iterator, Mapping
+from dataclasses import dataclass
+from typing import Any, Literal
+
+from toonverter.core.exceptions import (
+    DecodingError,
+    FormatNotSupportedError,
+    JsonInputError,
+    ValidationError,
+)
+from toonverter.core.spec import (
+    COLUMN_ENCODINGS,
+    COLUMN_TYPES,
+    COMMENT_CHAR,
+    KEY_FOLD_SEPARATOR,
+    KEY_SEGMENT_PATTERN,
+    PARSE_ERROR_KEY,
+    ArrayForm,
+    Delimiter,
+    RootForm,
+    ToonDecodeOptions,
+    ToonValue,
+)
+
+from toonverter.utils.io import Utf8ChunkDecoder
+from toonverter.utils.pointer import PathSegment, format_pointer
+
+from .lexer import Token, TokenType, ToonLexer, parse_directive, parse_number
+
+
+# Lexical forms recognized by coerce_types; leading zeros ("007") never match
+_INT_FORM = re.compile(r"-?(?:0|[1-9]\d*)")
+_FLOAT_FORM = re.compile(r"-?(?:0|[1-9]\d*)(?:\.\d+(?:[eE][+-]?\d+)?|[eE][+-]?\d+)")
+_NOT_COERCED = object()
+# Marks a cell that does not match its decode_typed_columns schema type
+_MISMATCH = object()
+# Unquoted nan/inf spellings that float() would accept
+_NONFINITE_PATTERN = re.compile(r"[+-]?(?:nan|inf(?:inity)?)", re.IGNORECASE)
+_NUMBER_TEXT = re.compile(r"[-+0-9.eE]+")
+_LAYOUT_TOKENS = (TokenType.NEWLINE, TokenType.INDENT, TokenType.DEDENT)
+_LINE_END_TOKENS = (*_LAYOUT_TOKENS, TokenType.EOF)
+# Header annotation (``+delta``) -> column encoding name
+_COLUMN_ANNOTATIONS = {marker: kind for kind, marker in COLUMN_ENCODINGS.items()}
+_VALUE_TOKENS = (
+    TokenType.STRING,
+    TokenType.QUOTED_STRING,
+    TokenType.IDENTIFIER,
+    TokenType.NUMBER,
+    TokenType.BOOLEAN,
+    TokenType.NULL,
+)
+
+
+@dataclass(frozen=True)
+class Span:
+    """Source range of a decoded value.
+
+    Lines and columns are 0-based, as in the Language Server Protocol, and
+    the end position is exclusive. An object field's span starts at its key.
+    """
+
+    start_line: int
+    start_col: int
+    end_line: int
+    end_col: int
+
+
+class _MultiDict(dict[str, Any]):
+    """Object node that also records every assignment, repeated keys included.
+
+    Used with ``duplicate_keys="collect"``; the dict view keeps the last
+    value so lookups during parsing behave as usual.
+    """
+
+    def __init__(self) -> None:
+        super().__init__()
+        self.pairs: list[tuple[str, Any]] = []
+
+    def __setitem__(self, key: str, value: Any) -> None:
+        super().__setitem__(key, value)
+        self.pairs.append((key, value))
+
 
-from toonverter.core.exceptions import DecodingError, ValidationError
-from toonverter.core.spec import ArrayForm, Delimiter, RootForm, ToonDecodeOptions, ToonValue
+class _ColumnDecoder:
+    """Restores the delta and rle cells of one tabular array, row by row."""
 
-from .lexer import Token, TokenType, ToonLexer
+    def __init__(self, fields: list[str], encodings: dict[int, str]) -> None:
+        self.fields = fields
+        self.encodings = encodings
+        self.previous: dict[int, Any] = {}
+        self.row = 0
+
+    def restore(
+        self, cells: list[Token | None], convert: Callable[[Token], Any]
+    ) -> dict[int, Any]:
+        """Decode the encoded columns of one row.
+
+        Args:
+            cells: Value tokens of the row, None for each empty cell
+            convert: Converts a cell token to its value
+
+        Returns:
+            Restored value per column index
+
+        Raises:
+            ValueError: If a delta cell is not an integer, or the first rle
+                cell of a column is empty
+        """
+        self.row += 1
+        restored: dict[int, Any] = {}
+        for index, kind in self.encodings.items():
+            if index >= len(cells):
+                continue
+            cell = cells[index]
+            name = self.fields[index]
+            if kind == "rle":
+                if cell is not None:
+                    restored[index] = self.previous[index] = convert(cell)
+                elif index in self.previous:
+                    restored[index] = self.previous[index]
+                else:
+                    msg = f"Column {name!r} row {self.row} is empty with no value to repeat"
+                    raise ValueError(msg)
+                continue
+            value = None if cell is None else convert(cell)
+            if value is not None:
+                if not isinstance(value, int) or isinstance(value, bool):
+                    msg = f"Delta column {name!r} row {self.row} holds {value!r}, not an integer"
+                    raise ValueError(msg)
+                value += self.previous.get(index, 0)
+                self.previous[index] = value
+            restored[index] = value
+        return restored
 
 
 class ToonDecoder:
@@ -22,6 +151,10 @@ class ToonDecoder:
     - Three array forms (inline, tabular, list)
     - Strict validation mode
     - Proper escape sequence handling
+    - ``key: ""`` → empty string, ``key: null`` → None and a bare ``key:``
+      with no nested lines → {} (the encoder's form for an empty object),
+      unless ``options.missing_values`` says otherwise; likewise a bare ``-``
+      list item → {} unless ``options.empty_list_items`` is "null"
     """
 
     def __init__(self, options: ToonDecodeOptions | None = None) -> None:
@@ -33,6 +166,13 @@ class ToonDecoder:
         self.options = options or ToonDecodeOptions()
         self.tokens: list[Token] = []
         self.pos = 0
+        self._interned: dict[str, str] = {}
+        self._indent_size = 2
+        self.errors: list[str] = []
+        # Span recording state, only active inside decode_with_spans
+        self._spans: dict[str, Span] | None = None
+        self._path: list[PathSegment] = []
+        self._source_lines: list[str] = []
 
     def decode(self, data_str: str) -> ToonValue:
         """Decode TOON string to Python data structure.
@@ -45,6 +185,7 @@ class ToonDecoder:
 
         Raises:
             DecodingError: If decoding fails
+            JsonInputError: If decoding fails and the input is valid JSON
 
         Examples:
             >>> decoder = ToonDecoder()
@@ -56,29 +197,668 @@ class ToonDecoder:
             [1, 2, 3]
         """
         try:
-            # Handle empty documents → {}
+            return self._decode(data_str)
+        except (DecodingError, ValidationError) as e:
+            if _looks_like_json(data_str):
+                msg = f"{e} (input appears to be JSON; use json.loads or from_format='json')"
+                raise JsonInputError(msg) from e
+            raise
+
+    def decode_with_spans(self, data_str: str) -> tuple[ToonValue, dict[str, Span]]:
+        """Decode a TOON string and record where each value came from.
+
+        Meant for editor tooling such as go-to-definition or hover. Every
+        value with source text gets a span, keyed by its JSON Pointer; empty
+        tabular and inline cells, which have no text, get none.
+
+        Args:
+            data_str: TOON formatted string
+
+        Returns:
+            Tuple of (decoded value, spans by JSON Pointer in document order,
+            parents before their children)
+
+        Raises:
+            DecodingError: If decoding fails
+
+        Examples:
+            >>> value, spans = ToonDecoder().decode_with_spans("a:\\n  b[2]: 1,22")
+            >>> spans["/a/b"]
+            Span(start_line=1, start_col=2, end_line=1, end_col=12)
+            >>> spans["/a/b/1"]
+            Span(start_line=1, start_col=10, end_line=1, end_col=12)
+        """
+        self._spans = {}
+        try:
+            value = self.decode(data_str)
+            spans = self._spans
+        finally:
+            self._spans = None
+            self._source_lines = []
+        ordered = sorted(
+            spans.items(),
+            key=lambda item: (
+                item[1].start_line,
+                item[1].start_col,
+                -item[1].end_line,
+                -item[1].end_col,
+                item[0].count("/"),
+            ),
+        )
+        return value, dict(ordered)
+
+    def decode_with_comments(self, data_str: str) -> tuple[ToonValue, dict[str, list[str]]]:
+        """Decode a TOON string and collect the comments above the root and its keys.
+
+        Covers the usual config-file comments without tracking every comment
+        position: the comment block at the top of the document belongs to
+        the root (``""``) when a blank line separates it from the first
+        entry, and the block directly above a top-level key belongs to that
+        key (``"/name"``). Indented, trailing and other comments are
+        dropped. Pass the result as ``ToonEncodeOptions(comments=...)`` to
+        write the blocks back.
+
+        Args:
+            data_str: TOON formatted string
+
+        Returns:
+            Tuple of (decoded value, comment lines by JSON Pointer, without
+            the ``#`` and the space after it)
+
+        Raises:
+            DecodingError: If decoding fails
+
+        Examples:
+            >>> text = "# App config\\n\\n# Network\\nport: 80"
+            >>> ToonDecoder().decode_with_comments(text)
+            ({'port': 80}, {'': ['App config'], '/port': ['Network']})
+        """
+        value, spans = self.decode_with_spans(data_str)
+        lines = data_str.split("\n")
+        top = 1 if parse_directive(lines[0]) else 0
+        comments: dict[str, list[str]] = {}
+
+        end = top
+        while end < len(lines) and lines[end].startswith(COMMENT_CHAR):
+            end += 1
+        detached = end == len(lines) or not lines[end].strip()
+        if end > top and (detached or not isinstance(value, dict)):
+            comments[""] = [_comment_text(line) for line in lines[top:end]]
+
+        if isinstance(value, dict):
+            for key in value:
+                span = spans.get(format_pointer([key]))
+                if span is None:
+                    continue
+                start = span.start_line
+                while start > top and lines[start - 1].startswith(COMMENT_CHAR):
+                    start -= 1
+                if start < span.start_line:
+                    block = lines[start : span.start_line]
+                    comments[format_pointer([key])] = [_comment_text(line) for line in block]
+        return value, comments
+
+    def _decode(self, data_str: str) -> ToonValue:
+        """Decode a TOON string without the JSON input hint."""
+        try:
+            # Only input with no content at all is the empty object; an empty
+            # root list is "[0]:" and an empty root string is '""'
             if not data_str or not data_str.strip():
-                return {}
+                return [] if self.options.as_pairs else {}
+
+            self._tokenize(data_str)
+            if self.options.strict:
+                self._validate_indentation()
 
-            # Tokenize input
-            lexer = ToonLexer(data_str, indent_size=2)
-            self.tokens = lexer.tokenize()
-            self.pos = 0
+            # Comment-only documents, e.g. a fingerprinted empty object, are empty too
+            if self._peek_content().type == TokenType.EOF:
+                return [] if self.options.as_pairs else {}
 
             # Parse root based on first token
             root_form = self._detect_root_form()
+            first = self._peek_content()
+            # A JSON object would also parse as a root inline object
+            if first.type == TokenType.BRACE_START and _looks_like_json(data_str):
+                msg = f"Root inline object on line {first.line + 1} is a JSON document"
+                raise DecodingError(msg)
 
             if root_form == RootForm.ARRAY:
-                return self._parse_root_array()
-            if root_form == RootForm.PRIMITIVE:
-                return self._parse_root_primitive()
-            # RootForm.OBJECT
-            return self._parse_root_object()
+                result: Any = self._parse_root_array()
+                self._raise_collected()
+            elif root_form == RootForm.PRIMITIVE:
+                result = self._parse_root_primitive()
+            else:
+                result = self._parse_root_object()
+                self._raise_collected()
+            if self._spans is not None and first.type != TokenType.EOF:
+                self._record_span(first, self._last_token())
+            return _to_pairs(result) if self.options.as_pairs else result
 
-        except (ValueError, IndexError, KeyError) as e:
+        except (ValueError, IndexError, KeyError, RecursionError) as e:
             msg = f"Failed to decode TOON data: {e}"
             raise DecodingError(msg) from e
 
+    def decode_fragment(self, data_str: str) -> tuple[ToonValue, int]:
+        """Decode exactly one value from the start of a string.
+
+        Unlike :meth:`decode`, trailing content is not an error: decoding stops
+        after the first complete value and the number of characters consumed
+        is returned. Supported values are primitives, inline objects
+        (``{a: 1}``), arrays (``[2]: 1,2``, tabular and list forms) and
+        blocks of ``key: value`` lines, which end at the first line that is
+        not a field at the block's own indent level.
+
+        Args:
+            data_str: Text starting with a TOON value
+
+        Returns:
+            Tuple of (value, characters consumed); leading blank lines,
+            comments and whitespace before the remainder count as consumed,
+            except the indentation of a remainder that starts its own line
+
+        Raises:
+            DecodingError: If the input is empty or the value is malformed
+
+        Examples:
+            >>> ToonDecoder().decode_fragment("[2]: 1,2 rest")
+            ([1, 2], 9)
+        """
+        try:
+            self._tokenize(data_str, fragment=True)
+            token = self._peek_content()
+            if token.type == TokenType.EOF:
+                msg = "Cannot decode a fragment from empty input"
+                raise DecodingError(msg)
+
+            if token.type == TokenType.BRACE_START:
+                value: ToonValue = self._parse_inline_object()
+            elif token.type == TokenType.ARRAY_START:
+                header = self._parse_array_header()
+                if header["form"] == ArrayForm.INLINE:
+                    value = self._parse_inline_array_fragment(header)
+                else:
+                    value = self._parse_array_body(header, token.indent_level)
+            elif self._at_field_start():
+                value = self._parse_fragment_fields(token.indent_level)
+            else:
+                self.pos += 1
+                value = self._token_to_value(token)
+
+            rest = self._peek_content()
+            if self.options.as_pairs:
+                value = _to_pairs(value)
+        except (ValidationError, ValueError, IndexError, KeyError, RecursionError) as e:
+            msg = f"Failed to decode TOON fragment: {e}"
+            raise DecodingError(msg) from e
+
+        if rest.type == TokenType.EOF:
+            return value, len(data_str)
+        offset = _token_offset(data_str, rest)
+        if rest.column == 0:
+            # The remainder starts its own line: keep that line's indentation
+            offset = data_str.rfind("\n", 0, offset) + 1
+        return value, offset
+
+    def decode_columns(
+        self,
+        data_str: str,
+        key: str | None = None,
+        columns: list[str] | None = None,
+    ) -> dict[str, list[Any]]:
+        """Decode a tabular array column by column.
+
+        Builds ``{field: [values...]}`` directly from the rows instead of a
+        list of row dicts, which is what DataFrame-style consumers want.
+        Cells of columns not listed in ``columns`` are skipped without being
+        converted. Cells missing from short rows (non-strict mode) are None.
+
+        Args:
+            data_str: TOON document whose root, or root field ``key``, is a
+                tabular array
+            key: Root field holding the table (None for a root array)
+            columns: Columns to return, in this order (None for all)
+
+        Returns:
+            Mapping of column name to list of values
+
+        Raises:
+            FormatNotSupportedError: If the target is not a tabular array
+            ValidationError: If a requested column is not in the header
+
+        Examples:
+            >>> ToonDecoder().decode_columns("[2]{id,name}:\\n  1,Ada\\n  2,Bob")
+            {'id': [1, 2], 'name': ['Ada', 'Bob']}
+        """
+        try:
+            self._tokenize(data_str)
+            header, level = self._seek_tabular(key, "Column decoding")
+            fields: list[str] = header["fields"]
+            selected = fields if columns is None else columns
+            unknown = [name for name in selected if name not in fields]
+            if unknown:
+                msg = f"Unknown columns {unknown}; header declares {fields}"
+                raise ValidationError(msg)
+
+            indices = [(name, fields.index(name)) for name in selected]
+            result: dict[str, list[Any]] = {name: [] for name in selected}
+            columns = self._column_decoder(header)
+            for cells, _ in self._iter_tabular_rows(header, level):
+                restored = {} if columns is None else columns.restore(cells, self._token_to_value)
+                for name, index in indices:
+                    if index in restored:
+                        result[name].append(restored[index])
+                        continue
+                    cell = cells[index] if index < len(cells) else None
+                    result[name].append(None if cell is None else self._token_to_value(cell))
+            self._raise_collected()
+            return result
+        except (ValueError, IndexError, KeyError, RecursionError) as e:
+            msg = f"Failed to decode TOON columns: {e}"
+            raise DecodingError(msg) from e
+
+    def decode_typed_columns(
+        self,
+        data_str: str,
+        schema: Mapping[str, str],
+        key: str | None = None,
+        mismatch: Literal["raise", "null"] = "raise",
+    ) -> dict[str, list[Any]]:
+        """Decode a tabular array into columns of declared types.
+
+        A faster :meth:`decode_columns` for callers that know the table's
+        schema: each cell is checked against its column's type instead of
+        going through the general value conversion. "int", "float", "str"
+        and "bool" accept only cells of that type (ints are widened in
+        "float" columns, and quoted digits are not ints); "any" converts
+        like :meth:`decode_columns`. Nulls and empty cells are None in every
+        column.
+
+        Args:
+            data_str: TOON document whose root, or root field ``key``, is a
+                tabular array
+            schema: Column name to type ("int", "float", "str", "bool" or
+                "any"), in the order the columns are returned
+            key: Root field holding the table (None for a root array)
+            mismatch: "raise" fails on a cell of the wrong type, "null"
+                stores None instead
+
+        Returns:
+            Mapping of column name to list of values
+
+        Raises:
+            FormatNotSupportedError: If the target is not a tabular array
+            ValidationError: If a schema column is not in the header, or a
+                cell does not match its type and mismatch is "raise"
+
+        Examples:
+            >>> ToonDecoder().decode_typed_columns(
+            ...     "[2]{id,score}:\\n  1,2.5\\n  2,3", {"id": "int", "score": "float"}
+            ... )
+            {'id': [1, 2], 'score': [2.5, 3.0]}
+        """
+        unknown_types = sorted(set(schema.values()) - set(COLUMN_TYPES))
+        if unknown_types:
+            msg = f"Unknown column types {unknown_types}; expected one of {list(COLUMN_TYPES)}"
+            raise ValueError(msg)
+        if mismatch not in ("raise", "null"):
+            msg = "mismatch must be 'raise' or 'null'"
+            raise ValueError(msg)
+
+        try:
+            self._tokenize(data_str)
+            header, level = self._seek_tabular(key, "Column decoding")
+            fields: list[str] = header["fields"]
+            unknown = [name for name in schema if name not in fields]
+            if unknown:
+                msg = f"Unknown columns {unknown}; header declares {fields}"
+                raise ValidationError(msg)
+
+            plan = [(name, fields.index(name), kind) for name, kind in schema.items()]
+            result: dict[str, list[Any]] = {name: [] for name in schema}
+            columns = self._column_decoder(header)
+            for row, (cells, _) in enumerate(self._iter_tabular_rows(header, level), 1):
+                restored = {} if columns is None else columns.restore(cells, self._token_to_value)
+                for name, index, kind in plan:
+                    cell = cells[index] if index < len(cells) else None
+                    if index in restored:
+                        value = restored[index]
+                    elif cell is None:
+                        value = None
+                    elif kind == "any":
+                        value = self._token_to_value(cell)
+                    else:
+                        value = cell.value
+                    typed = _typed_cell(kind, value)
+                    if typed is _MISMATCH:
+                        if mismatch == "raise":
+                            line = "" if cell is None else f" (line {cell.line + 1})"
+                            msg = (
+                                f"Row {row}{line}, column {name!r}: "
+                                f"expected {kind}, got {value!r}"
+                            )
+                            raise ValidationError(msg)
+                        typed = None
+                    result[name].append(typed)
+            self._raise_collected()
+            return result
+        except (ValueError, IndexError, KeyError, RecursionError) as e:
+            msg = f"Failed to decode TOON columns: {e}"
+            raise DecodingError(msg) from e
+
+    def decode_rows(
+        self,
+        data_str: str,
+        key: str | None = None,
+        row_factory: Callable[..., Any] | None = None,
+    ) -> tuple[list[str], list[Any]]:
+        """Decode a tabular array into a header and lightweight rows.
+
+        Rows are tuples in header order by default, which is much lighter than
+        one dict per row. With ``row_factory`` each row is built as
+        ``row_factory(**row)`` instead, e.g. a dataclass or NamedTuple.
+        Missing cells follow ``missing_cells``: tuples are always padded with
+        None, while omitted cells are left out of the factory's keyword
+        arguments so its defaults apply.
+
+        Args:
+            data_str: TOON document whose root, or root field ``key``, is a
+                tabular array
+            key: Root field holding the table (None for a root array)
+            row_factory: Callable taking the fields as keyword arguments
+                (None for tuples)
+
+        Returns:
+            Tuple of (field names, rows)
+
+        Raises:
+            FormatNotSupportedError: If the target is not a tabular array
+
+        Examples:
+            >>> ToonDecoder().decode_rows("[2]{id,name}:\\n  1,Ada\\n  2,Bob")
+            (['id', 'name'], [(1, 'Ada'), (2, 'Bob')])
+        """
+        try:
+            self._tokenize(data_str)
+            header, level = self._seek_tabular(key, "Row decoding")
+        except (ValueError, IndexError, KeyError, RecursionError) as e:
+            msg = f"Failed to decode TOON rows: {e}"
+            raise DecodingError(msg) from e
+        fields: list[str] = header["fields"]
+
+        rows: list[Any] = []
+        for values in self._iter_row_values(header, level):
+            if row_factory is None:
+                values.extend([None] * (len(fields) - len(values)))
+                rows.append(tuple(values))
+                continue
+            row = dict(zip(fields, values, strict=False))
+            if self.options.missing_cells == "null":
+                for name in fields[len(values) :]:
+                    row[name] = None
+            # Outside the parse error wrapping: the factory's own errors propagate
+            rows.append(row_factory(**row))
+        return list(fields), rows
+
+    def _iter_row_values(self, header: dict[str, Any], level: int) -> Iterator[list[Any]]:
+        """Yield the converted values of each tabular row for decode_rows.
+
+        Raises:
+            DecodingError: If a row cannot be parsed
+        """
+        try:
+            columns = self._column_decoder(header)
+            for cells, _ in self._iter_tabular_rows(header, level):
+                yield self._row_values(cells, columns)
+            self._raise_collected()
+        except (ValueError, IndexError, KeyError, RecursionError) as e:
+            msg = f"Failed to decode TOON rows: {e}"
+            raise DecodingError(msg) from e
+
+    def _seek_tabular(self, key: str | None, action: str) -> tuple[dict[str, Any], int]:
+        """Move past the header of the tabular array at the root or a root field.
+
+        Args:
+            key: Root field holding the table (None for a root array)
+            action: Operation name used in error messages
+
+        Returns:
+            Tuple of (parsed header, indent level of the header line)
+
+        Raises:
+            FormatNotSupportedError: If there is no tabular array there
+        """
+        level = self._seek_array(key, action)
+        header = self._parse_array_header()
+        if header["form"] != ArrayForm.TABULAR:
+            target = "root" if key is None else f"field {key!r}"
+            msg = f"{action} requires a tabular array at the {target}"
+            raise FormatNotSupportedError(msg)
+        return header, level
+
+    def _seek_array(self, key: str | None, action: str) -> int:
+        """Move to the array header of the root or of a root field.
+
+        Args:
+            key: Root field holding the array (None for a root array)
+            action: Operation name used in error messages
+
+        Returns:
+            Indent level of the header line
+
+        Raises:
+            FormatNotSupportedError: If there is no such array
+        """
+        token = self._peek_content()
+        if key is None:
+            if token.type != TokenType.ARRAY_START:
+                msg = f"{action} requires a tabular array at the root"
+                raise FormatNotSupportedError(msg)
+            return token.indent_level
+
+        for index, candidate in enumerate(self.tokens[:-1]):
+            if (
+                candidate.indent_level == 0
+                and candidate.type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING)
+                and candidate.value == key
+                and self.tokens[index + 1].type == TokenType.ARRAY_START
+                and (index == 0 or self.tokens[index - 1].type in _LAYOUT_TOKENS)
+            ):
+                self.pos = index + 1
+                return 0
+        msg = f"{action} requires a tabular array at the field {key!r}"
+        raise FormatNotSupportedError(msg)
+
+    def _tokenize(self, data_str: str, fragment: bool = False) -> None:
+        """Tokenize input and reset the parse state."""
+        lexer = ToonLexer(
+            data_str,
+            indent_size=self.options.indent_size,
+            max_indent_depth=self.options.max_indent_depth,
+            strict_indent=self.options.strict,
+            max_line_length=self.options.max_line_length,
+            recover_rows=self.options.row_errors == "mark",
+            fragment=fragment,
+        )
+        self.tokens = [t for t in lexer.tokenize() if t.type != TokenType.COMMENT]
+        self._indent_size = lexer.indent_size
+        self.pos = 0
+        self._interned = {}
+        self.errors = []
+        self._path = []
+        if self._spans is not None:
+            self._source_lines = data_str.split("\n")
+
+    def _enter(self, segment: PathSegment) -> None:
+        """Descend into a child value while recording spans."""
+        if self._spans is not None:
+            self._path.append(segment)
+
+    def _leave(self, first: Token) -> None:
+        """Record the span of the child entered last, which started at ``first``."""
+        if self._spans is not None:
+            segment = self._path.pop()
+            self._record_span(first, self._last_token(), segment)
+
+    def _last_token(self) -> Token:
+        """Return the last non-layout token consumed."""
+        i = min(self.pos, len(self.tokens)) - 1
+        while i > 0 and self.tokens[i].type in _LAYOUT_TOKENS:
+            i -= 1
+        return self.tokens[i]
+
+    def _record_span(self, first: Token, last: Token, *segments: PathSegment) -> None:
+        """Store the span from ``first`` to ``last`` under the current path plus ``segments``."""
+        if self._spans is None:
+            return
+        start_line, start_col = self._token_start(first)
+        end_line, end_col = self._token_start(last)
+        end_col += _token_length(self._source_lines[end_line], end_col, last)
+        self._spans[format_pointer([*self._path, *segments])] = Span(
+            start_line, start_col, end_line, end_col
+        )
+
+    def _token_start(self, token: Token) -> tuple[int, int]:
+        """Line and column of a token in the source (token columns skip indentation)."""
+        line = self._source_lines[token.line]
+        return token.line, len(line) - len(line.lstrip()) + token.column
+
+    def _report(self, msg: str) -> None:
+        """Record a strict-mode row error, failing once max_errors are collected.
+
+        Args:
+            msg: Error message
+
+        Raises:
+            ValidationError: When the error limit is reached
+        """
+        self.errors.append(msg)
+        if len(self.errors) >= self.options.max_errors:
+            raise ValidationError(_format_errors(self.errors), list(self.errors))
+
+    def _raise_collected(self) -> None:
+        """Raise the row errors collected below the max_errors limit, if any."""
+        if self.errors:
+            raise ValidationError(_format_errors(self.errors), list(self.errors))
+
+    def _new_object(self) -> dict[str, Any]:
+        """Create an empty object node, keeping repeated keys when collecting them."""
+        return _MultiDict() if self.options.duplicate_keys == "collect" else {}
+
+    def _at_field_start(self) -> bool:
+        """Check whether the current token starts a ``key:`` or ``key[N]`` field."""
+        return (
+            self.pos + 1 < len(self.tokens)
+            and self.tokens[self.pos].type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING)
+            and self.tokens[self.pos + 1].type in (TokenType.COLON, TokenType.ARRAY_START)
+        )
+
+    def _parse_fragment_fields(self, level: int) -> dict[str, Any]:
+        """Parse fields at one indent level, stopping at anything that is not a field."""
+        result = self._new_object()
+        while True:
+            token = self._peek_content()
+            if token.indent_level != level or not self._at_field_start():
+                return result
+            key = str(token.value)
+            self.pos += 1
+            self._set_field(result, token, self._parse_field_value(key, level))
+
+    def _parse_inline_object(self) -> dict[str, Any]:
+        """Parse a brace-delimited object such as ``{a: 1, b: {c: x}}``.
+
+        A single trailing comma before the closing brace is ignored.
+        """
+        self.pos += 1  # Skip {
+        result = self._new_object()
+        separated = True  # Nothing to separate yet after the opening brace
+        while not self._at_line_end():
+            token = self.tokens[self.pos]
+            if token.type == TokenType.BRACE_END:
+                self.pos += 1
+                return result
+            if token.type == TokenType.COMMA:
+                if separated:
+                    msg = f"Unexpected ',' in inline object at line {token.line + 1}"
+                    raise DecodingError(msg)
+                separated = True
+                self.pos += 1
+                continue
+            separated = False
+            if not self._at_field_start() or self.tokens[self.pos + 1].type != TokenType.COLON:
+                msg = f"Expected 'key:' in inline object at line {token.line + 1}"
+                raise DecodingError(msg)
+            self.pos += 2
+            if self._at_line_end():
+                break
+            value_token = self.tokens[self.pos]
+            self._enter(str(token.value))
+            if value_token.type in (TokenType.COMMA, TokenType.BRACE_END):
+                colon = self.tokens[self.pos - 1]
+                result[str(token.value)] = self._missing_value(str(token.value), colon)
+            elif value_token.type == TokenType.BRACE_START:
+                result[str(token.value)] = self._parse_inline_object()
+            else:
+                result[str(token.value)] = self._token_to_value(value_token)
+                self.pos += 1
+            self._leave(token)
+            self._check_entries(result, token)
+        msg = "Unterminated inline object"
+        raise DecodingError(msg)
+
+    def _parse_inline_array_fragment(self, header: dict[str, Any]) -> list[Any]:
+        """Parse an inline array, ending at the first value not preceded by a delimiter."""
+        values: list[Any] = []
+        while not self._at_line_end():
+            values.append(self._token_to_value(self.tokens[self.pos]))
+            self.pos += 1
+            if (
+                len(values) == header["length"]
+                or self._at_line_end()
+                or self.tokens[self.pos].type != TokenType.COMMA
+            ):
+                break
+            self.pos += 1  # Skip delimiter
+
+        if self.options.strict and len(values) != header["length"]:
+            msg = f"Array length mismatch: declared {header['length']}, got {len(values)}"
+            raise ValidationError(msg)
+        return values
+
+    def _validate_indentation(self) -> None:
+        """Reject indentation that does not describe a valid block structure.
+
+        The first line must start at the root level, and each line may be at
+        most one level deeper than the line before it. The one exception is a
+        line after a list item: fields of an object opened by ``- key:`` sit
+        two levels below the hyphen.
+
+        Raises:
+            ValidationError: If the indentation is inconsistent
+        """
+        previous: Token | None = None
+        for token in self.tokens:
+            if token.type in _LAYOUT_TOKENS or token.type == TokenType.EOF:
+                continue
+            if previous is not None and token.line == previous.line:
+                continue
+
+            if previous is None:
+                if token.indent_level != 0:
+                    msg = f"Line {token.line + 1}: document must start at indent level 0"
+                    raise ValidationError(msg)
+            else:
+                max_step = 2 if previous.type == TokenType.DASH else 1
+                if token.indent_level - previous.indent_level > max_step:
+                    unit = self._indent_size
+                    msg = (
+                        f"Line {token.line + 1}: indent level {token.indent_level} skips levels "
+                        f"after level {previous.indent_level} (found {token.indent_level * unit} "
+                        f"spaces, expected at most {(previous.indent_level + max_step) * unit} "
+                        f"with {unit}-space indentation)"
+                    )
+                    raise ValidationError(msg)
+            previous = token
+
     def _detect_root_form(self) -> RootForm:
         """Detect the form of root document.
 
@@ -117,6 +897,7 @@ class ToonDecoder:
                 TokenType.NUMBER,
                 TokenType.COMMA,
                 TokenType.IDENTIFIER,
+                TokenType.QUOTED_STRING,
             ):
                 lookahead_pos += 1
                 continue
@@ -130,53 +911,16 @@ class ToonDecoder:
     def _parse_root_object(self) -> dict[str, Any]:
         """Parse root-level object.
 
+        A document starting with ``{`` is read as an inline object; any
+        fields after it are added to the same dictionary.
+
         Returns:
             Dictionary
         """
-        result: dict[str, Any] = {}
-
-        while self.pos < len(self.tokens):
-            token = self.tokens[self.pos]
-
-            # End of input
-            if token.type in (TokenType.EOF, TokenType.DEDENT):
-                break
-
-            # Skip newlines and indents at root level
-            if token.type in (TokenType.NEWLINE, TokenType.INDENT):
-                self.pos += 1
-                continue
-
-            # Parse key-value pair
-            if token.type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING):
-                key = str(token.value)
-                self.pos += 1
-
-                # Check if value is an array (key[N]: syntax)
-                if (
-                    self.pos < len(self.tokens)
-                    and self.tokens[self.pos].type == TokenType.ARRAY_START
-                ):
-                    # Array value - parse array header and content
-                    value = self._parse_value(depth=0)
-                    result[key] = value
-                else:
-                    # Regular value - expect colon
-                    if (
-                        self.pos >= len(self.tokens)
-                        or self.tokens[self.pos].type != TokenType.COLON
-                    ):
-                        msg = f"Expected ':' after key '{key}'"
-                        raise DecodingError(msg)
-                    self.pos += 1
-
-                    # Parse value
-                    value = self._parse_value(depth=0)
-                    result[key] = value
-            else:
-                self.pos += 1
-
-        return result
+        result = self._new_object()
+        if self._peek_content().type == TokenType.BRACE_START:
+            result = self._parse_inline_object()
+        return self._parse_object_fields(0, result)
 
     def _parse_root_array(self) -> list[Any]:
         """Parse root-level array.
@@ -184,16 +928,9 @@ class ToonDecoder:
         Returns:
             List
         """
-        # Parse array header
+        level = self.tokens[self.pos].indent_level
         header = self._parse_array_header()
-
-        # Parse array content based on form
-        if header["form"] == ArrayForm.INLINE:
-            return self._parse_inline_array(header)
-        if header["form"] == ArrayForm.TABULAR:
-            return self._parse_tabular_array(header)
-        # ArrayForm.LIST
-        return self._parse_list_array(header, depth=0)
+        return self._parse_array_body(header, level)
 
     def _parse_root_primitive(self) -> Any:
         """Parse root-level primitive value.
@@ -202,212 +939,248 @@ class ToonDecoder:
             Primitive value
         """
         token = self.tokens[self.pos]
+        self.pos += 1
+        if self.options.strict and self._peek_content().type != TokenType.EOF:
+            msg = "Extra tokens found after root primitive value"
+            raise DecodingError(msg)
         return self._token_to_value(token)
 
-    def _parse_value(self, depth: int) -> Any:
-        """Parse a value (primitive, object, or array).
+    def _peek_content(self) -> Token:
+        """Skip layout tokens (newlines, indents, dedents) and return the next token.
 
-        Args:
-            depth: Current nesting depth
+        Block structure is derived from each token's indent level rather than
+        from INDENT/DEDENT counts, so layout tokens carry no meaning here.
 
         Returns:
-            Parsed value
+            Next significant token (a synthetic EOF token past the end)
         """
-        # Skip whitespace/newlines
-        while self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.NEWLINE:
+        while self.pos < len(self.tokens) and self.tokens[self.pos].type in _LAYOUT_TOKENS:
             self.pos += 1
-
         if self.pos >= len(self.tokens):
-            return None
+            line = self.tokens[-1].line if self.tokens else 0
+            return Token(type=TokenType.EOF, value=None, line=line, column=0, indent_level=0)
+        return self.tokens[self.pos]
 
-        token = self.tokens[self.pos]
-
-        # Array: key[N]: or key[N]{fields}:
-        if token.type == TokenType.ARRAY_START:
-            header = self._parse_array_header()
-            if header["form"] == ArrayForm.INLINE:
-                return self._parse_inline_array(header)
-            if header["form"] == ArrayForm.TABULAR:
-                return self._parse_tabular_array(header)
-            return self._parse_list_array(header, depth)
-
-        # Nested object (current token is INDENT after skipping newlines)
-        if token.type == TokenType.INDENT:
-            return self._parse_nested_object(depth)
-
-        # Check for inline object: identifier followed by colon
-        # This handles cases like "- key: value" in list arrays
-        if token.type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING):
-            # Look ahead for colon
-            if (
-                self.pos + 1 < len(self.tokens)
-                and self.tokens[self.pos + 1].type == TokenType.COLON
-            ):
-                # This is an inline object, parse it
-                return self._parse_inline_object(depth)
+    def _at_line_end(self) -> bool:
+        """Check whether nothing but layout remains on the current line."""
+        return self.pos >= len(self.tokens) or self.tokens[self.pos].type in _LINE_END_TOKENS
 
-        # Primitive value
-        value = self._token_to_value(token)
-        self.pos += 1
-        return value
+    def _parse_object_fields(self, level: int, result: dict[str, Any]) -> dict[str, Any]:
+        """Parse key-value lines belonging to an object block.
 
-    def _parse_nested_object(self, depth: int) -> dict[str, Any]:
-        """Parse nested object.
+        The block ends at the first line indented less than ``level`` or at a
+        list item marker, which belongs to an enclosing list.
 
         Args:
-            depth: Current nesting depth
+            level: Indent level of the block's keys
+            result: Dictionary to add fields to
 
         Returns:
-            Dictionary
+            The populated dictionary
         """
-        result: dict[str, Any] = {}
-
-        # Skip to indented content
-        self.pos += 1  # Skip INDENT token
-
-        while self.pos < len(self.tokens):
-            token = self.tokens[self.pos]
-
-            # End of nested object
-            if token.type == TokenType.DEDENT:
-                self.pos += 1
+        while True:
+            token = self._peek_content()
+            if token.type in (TokenType.EOF, TokenType.DASH) or token.indent_level < level:
                 break
 
-            if token.type == TokenType.EOF:
-                break
-
-            # Skip newlines
-            if token.type == TokenType.NEWLINE:
-                self.pos += 1
-                continue
-
-            # Parse key-value pair
             if token.type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING):
                 key = str(token.value)
                 self.pos += 1
-
-                # Check if value is an array (key[N]: syntax)
-                if (
-                    self.pos < len(self.tokens)
-                    and self.tokens[self.pos].type == TokenType.ARRAY_START
-                ):
-                    # Array value - parse array header and content
-                    value = self._parse_value(depth + 1)
-                    result[key] = value
-                else:
-                    # Regular value - expect colon
-                    if (
-                        self.pos >= len(self.tokens)
-                        or self.tokens[self.pos].type != TokenType.COLON
-                    ):
-                        msg = f"Expected ':' after key '{key}'"
-                        raise DecodingError(msg)
-                    self.pos += 1
-
-                    # Parse value
-                    value = self._parse_value(depth + 1)
-                    result[key] = value
+                self._enter(key)
+                value = self._parse_field_value(key, max(level, token.indent_level))
+                self._leave(token)
+                self._set_field(result, token, value)
             else:
                 self.pos += 1
 
         return result
 
-    def _parse_inline_object(self, depth: int) -> dict[str, Any]:
-        """Parse inline object (first field on dash line, remaining at depth+1).
+    def _set_field(self, result: dict[str, Any], key_token: Token, value: Any) -> None:
+        """Store a parsed field, expanding dotted keys when expand_paths is "safe".
 
-        Per TOON spec: "First field on the hyphen line: - key: value
-        Remaining fields continue at depth +1"
+        Only unquoted keys whose segments are all identifiers are expanded, so
+        a quoted ``"a.b"`` stays a literal key. Expanded paths deep-merge with
+        objects already present.
 
         Args:
-            depth: Current nesting depth
+            result: Object being built
+            key_token: Token of the field's key
+            value: Parsed field value
+        """
+        key = str(key_token.value)
+        if self.options.expand_paths == "off":
+            result[key] = value
+            self._check_entries(result, key_token)
+            return
+
+        segments = key.split(KEY_FOLD_SEPARATOR)
+        if key_token.type != TokenType.IDENTIFIER or len(segments) < 2 or not all(
+            KEY_SEGMENT_PATTERN.match(segment) for segment in segments
+        ):
+            segments = [key]
+
+        target = result
+        for segment in segments[:-1]:
+            child = target.get(segment)
+            if not isinstance(child, dict):
+                self._check_path_conflict(target, segment, key)
+                child = target[segment] = self._new_object()
+            target = child
+        self._merge_field(target, segments[-1], value, key)
+        self._check_entries(target, key_token)
+
+    def _check_entries(self, obj: dict[str, Any], key_token: Token) -> None:
+        """Reject an object that has grown past max_dict_entries keys."""
+        limit = self.options.max_dict_entries
+        if limit is not None and len(obj) > limit:
+            msg = (
+                f"Object has more than max_dict_entries {limit} keys "
+                f"at line {key_token.line + 1}"
+            )
+            raise ValidationError(msg)
+
+    def _check_elements(self, values: list[Any], token: Token | None) -> None:
+        """Reject an array that has grown past max_list_elements elements."""
+        limit = self.options.max_list_elements
+        if limit is not None and len(values) > limit:
+            where = "" if token is None else f" at line {token.line + 1}"
+            msg = f"Array has more than max_list_elements {limit} elements{where}"
+            raise ValidationError(msg)
+
+    def _merge_field(self, target: dict[str, Any], key: str, value: Any, path: str) -> None:
+        """Assign a field, deep-merging objects produced by path expansion."""
+        existing = target.get(key)
+        if isinstance(existing, dict) and isinstance(value, dict):
+            for child_key, child_value in _fields(value):
+                self._merge_field(existing, child_key, child_value, path)
+            return
+        self._check_path_conflict(target, key, path)
+        target[key] = value
+
+    def _check_path_conflict(self, target: dict[str, Any], key: str, path: str) -> None:
+        """Reject overwriting an existing value during path expansion in strict mode."""
+        if self.options.strict and key in target:
+            msg = f"Path expansion conflict at {key!r} while expanding {path!r}"
+            raise ValidationError(msg)
+
+    def _parse_field_value(self, key: str, level: int) -> Any:
+        """Parse the value following a key: ``key: value``, ``key:`` + block, or ``key[N]...``.
+
+        Args:
+            key: Key being parsed (for error messages)
+            level: Indent level the key belongs to
 
         Returns:
-            Dictionary
+            Parsed value
         """
-        result: dict[str, Any] = {}
+        # Array value: key[N]: ... or key[N]{fields}:
+        if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.ARRAY_START:
+            header = self._parse_array_header()
+            return self._parse_array_body(header, level)
 
-        # Parse first field on the current line
-        token = self.tokens[self.pos]
-        if token.type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING):
-            key = str(token.value)
-            self.pos += 1
+        if self.pos >= len(self.tokens) or self.tokens[self.pos].type != TokenType.COLON:
+            msg = f"Expected ':' after key '{key}'"
+            raise DecodingError(msg)
+        colon = self.tokens[self.pos]
+        self.pos += 1
 
-            # Expect colon
-            if self.pos >= len(self.tokens) or self.tokens[self.pos].type != TokenType.COLON:
-                msg = f"Expected ':' after key '{key}' in inline object"
-                raise DecodingError(msg)
+        if not self._at_line_end():
+            if self.tokens[self.pos].type == TokenType.ARRAY_START:
+                header = self._parse_array_header()
+                return self._parse_array_body(header, level)
+            if self.tokens[self.pos].type == TokenType.BRACE_START:
+                return self._parse_inline_object()
+            value = self._token_to_value(self.tokens[self.pos])
             self.pos += 1
+            return value
 
-            # Parse value (primitive only on dash line)
-            if self.pos >= len(self.tokens) or self.tokens[self.pos].type in (
-                TokenType.NEWLINE,
-                TokenType.EOF,
-            ):
-                result[key] = None
-            else:
-                value = self._token_to_value(self.tokens[self.pos])
-                result[key] = value
-                self.pos += 1
+        # Nothing on the line: nested object if the next line is indented deeper
+        token = self._peek_content()
+        if token.type != TokenType.EOF and token.indent_level > level:
+            return self._parse_object_fields(token.indent_level, self._new_object())
+        return self._missing_value(key, colon)
 
-        # Skip newline if present
-        if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.NEWLINE:
-            self.pos += 1
+    def _missing_value(self, key: str, colon: Token) -> Any:
+        """Value of a key whose colon is followed by nothing, per ``missing_values``.
 
-        # Check for additional fields at depth+1 (INDENT)
-        if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.INDENT:
-            self.pos += 1  # Skip INDENT
+        Args:
+            key: Key being parsed
+            colon: The key's colon token
 
-            # Parse remaining fields at this indentation level
-            while self.pos < len(self.tokens):
-                token = self.tokens[self.pos]
+        Returns:
+            An empty object or None
 
-                # End of object - dedent or EOF
-                if token.type == TokenType.DEDENT:
-                    self.pos += 1
-                    break
+        Raises:
+            DecodingError: If missing_values is "error"
+        """
+        if self.options.missing_values == "error":
+            msg = f"Missing value for key {key!r} at line {colon.line + 1}"
+            raise DecodingError(msg)
+        if self.options.missing_values == "null":
+            return None
+        return {}
 
-                if token.type == TokenType.EOF:
-                    break
+    def _parse_list_item(self, level: int) -> Any:
+        """Parse the content of a list item after its ``-`` marker.
+
+        An object item carries its first field on the hyphen line; the
+        remaining fields follow one level deeper than the hyphen, until the
+        next hyphen or dedent.
+
+        Args:
+            level: Indent level of the hyphen line
+
+        Returns:
+            Parsed item value
+        """
+        if self._at_line_end():
+            # Bare hyphen: object fields may follow on deeper lines, else an empty item
+            token = self._peek_content()
+            if token.type not in (TokenType.EOF, TokenType.DASH) and token.indent_level > level:
+                return self._parse_object_fields(token.indent_level, self._new_object())
+            return None if self.options.empty_list_items == "null" else {}
 
-                # Skip newlines
-                if token.type == TokenType.NEWLINE:
-                    self.pos += 1
-                    continue
+        token = self.tokens[self.pos]
 
-                # Another dash means next list item
-                if token.type == TokenType.DASH:
-                    break
+        # Nested array item: - [N]: ...
+        if token.type == TokenType.ARRAY_START:
+            header = self._parse_array_header()
+            return self._parse_array_body(header, level)
 
-                # Parse key-value pair
-                if token.type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING):
-                    key = str(token.value)
-                    self.pos += 1
+        # Object item: - key: value / - key[N]...
+        if (
+            token.type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING)
+            and self.pos + 1 < len(self.tokens)
+            and self.tokens[self.pos + 1].type in (TokenType.COLON, TokenType.ARRAY_START)
+        ):
+            key = str(token.value)
+            self.pos += 1
+            result = self._new_object()
+            self._enter(key)
+            value = self._parse_field_value(key, level + 1)
+            self._leave(token)
+            self._set_field(result, token, value)
+            return self._parse_object_fields(level + 1, result)
+
+        # Primitive item
+        self.pos += 1
+        return self._token_to_value(token)
 
-                    # Check if value is an array (key[N]: syntax)
-                    if (
-                        self.pos < len(self.tokens)
-                        and self.tokens[self.pos].type == TokenType.ARRAY_START
-                    ):
-                        # Array value - parse array header and content
-                        value = self._parse_value(depth + 1)
-                        result[key] = value
-                    else:
-                        # Regular value - expect colon
-                        if (
-                            self.pos >= len(self.tokens)
-                            or self.tokens[self.pos].type != TokenType.COLON
-                        ):
-                            msg = f"Expected ':' after key '{key}'"
-                            raise DecodingError(msg)
-                        self.pos += 1
+    def _parse_array_body(self, header: dict[str, Any], level: int) -> list[Any]:
+        """Parse array content after its header.
 
-                        # Parse value
-                        value = self._parse_value(depth + 1)
-                        result[key] = value
-                else:
-                    self.pos += 1
+        Args:
+            header: Array header info
+            level: Indent level of the header line
 
-        return result
+        Returns:
+            List of values
+        """
+        if header["form"] == ArrayForm.INLINE:
+            return self._parse_inline_array(header, level)
+        if header["form"] == ArrayForm.TABULAR:
+            return self._parse_tabular_array(header, level)
+        return self._parse_list_array(header, level)
 
     def _parse_array_header(self) -> dict[str, Any]:
         """Parse array header: [N] or [N]{fields}
@@ -423,10 +1196,22 @@ class ToonDecoder:
 
         # Parse length
         length_token = self.tokens[self.pos]
-        if length_token.type != TokenType.NUMBER:
+        length = length_token.value
+        # Exponent and decimal literals lex as numbers too, but are not lengths
+        if (
+            length_token.type != TokenType.NUMBER
+            or not isinstance(length, int)
+            or isinstance(length, bool)
+        ):
             msg = "Expected array length number"
             raise DecodingError(msg)
-        length = int(length_token.value)  # type: ignore
+        limit = self.options.max_list_elements
+        if limit is not None and length > limit:
+            msg = (
+                f"Array on line {length_token.line + 1} declares {length} elements, "
+                f"more than max_list_elements {limit}"
+            )
+            raise ValidationError(msg)
         self.pos += 1
 
         # Check for delimiter marker
@@ -449,6 +1234,7 @@ class ToonDecoder:
 
         # Check for field spec {field1,field2}
         fields = None
+        encodings: dict[int, str] = {}
         form = ArrayForm.LIST  # Default
 
         if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.BRACE_START:
@@ -465,8 +1251,26 @@ class ToonDecoder:
                     break
 
                 if token.type in (TokenType.IDENTIFIER, TokenType.QUOTED_STRING):
+                    limit = self.options.max_tabular_fields
+                    if limit is not None and len(fields) >= limit:
+                        msg = f"Tabular header declares more than {limit} fields"
+                        raise ValidationError(msg)
+                    # Every row becomes an object with one key per field
+                    limit = self.options.max_dict_entries
+                    if limit is not None and len(fields) >= limit:
+                        msg = (
+                            f"Tabular header declares more than max_dict_entries {limit} keys "
+                            f"at line {token.line + 1}"
+                        )
+                        raise ValidationError(msg)
                     fields.append(str(token.value))
                     self.pos += 1
+                    if self.tokens[self.pos].type == TokenType.COLON:
+                        self.pos += 1
+                        encodings[len(fields) - 1] = self._parse_column_annotation(fields[-1])
+                elif token.type != TokenType.COMMA:
+                    msg = f"Unexpected {token.value!r} in tabular header on line {token.line + 1}"
+                    raise DecodingError(msg)
 
                 # Skip commas
                 if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.COMMA:
@@ -489,35 +1293,88 @@ class ToonDecoder:
             "fields": fields,
             "form": form,
             "delimiter": delimiter,
+            "encodings": encodings,
         }
 
-    def _parse_inline_array(self, header: dict[str, Any]) -> list[Any]:
+    def _parse_column_annotation(self, field: str) -> str:
+        """Parse the ``+delta`` or ``*rle`` after ``field:`` in a tabular header.
+
+        Args:
+            field: Name of the annotated field
+
+        Returns:
+            Column encoding name ("delta" or "rle")
+
+        Raises:
+            DecodingError: If the annotation is unknown, or column encoding
+                is not enabled
+        """
+        token = self.tokens[self.pos]
+        kind = _COLUMN_ANNOTATIONS.get(str(token.value))
+        if token.type != TokenType.IDENTIFIER or kind is None:
+            msg = f"Unknown encoding {token.value!r} for tabular field {field!r}"
+            raise DecodingError(msg)
+        if not self.options.column_encoding:
+            msg = (
+                f"Tabular field {field!r} is {kind}-encoded; "
+                "decode with column_encoding=True to restore it"
+            )
+            raise DecodingError(msg)
+        self.pos += 1
+        return kind
+
+    def _parse_inline_array(self, header: dict[str, Any], level: int) -> list[Any]:
         """Parse inline array: [3]: 1,2,3
 
+        As in tabular rows, a cell left empty between delimiters (``1,,3``)
+        decodes to None. A single trailing delimiter (``1,2,3,``) is dropped
+        and does not close an empty cell; a second one (``1,2,3,,``) is an
+        error.
+
+        An array with fewer values than declared may wrap: following lines
+        indented deeper than its key continue it, and each line break
+        separates values like a delimiter::
+
+            ids[6]: 1,2,3,
+              4,5,6
+
         Args:
             header: Array header info
+            level: Indent level of the array's key
 
         Returns:
             List of values
         """
         values: list[Any] = []
-        header["delimiter"]
 
-        while self.pos < len(self.tokens):
+        # A delimiter with no value before it closes an empty cell
+        cell_open = True
+        delimiters = 0  # Delimiters since the last value
+        token = self.tokens[self.pos]
+        while not self._at_line_end() or (
+            len(values) < header["length"] and self._at_inline_continuation(level)
+        ):
             token = self.tokens[self.pos]
+            self.pos += 1
 
-            if token.type in (TokenType.NEWLINE, TokenType.EOF):
-                break
-
-            # Skip delimiter tokens
             if token.type == TokenType.COMMA:
-                self.pos += 1
+                if cell_open:
+                    values.append(None)
+                    self._check_elements(values, token)
+                cell_open = True
+                delimiters += 1
                 continue
 
-            # Parse value
-            value = self._token_to_value(token)
-            values.append(value)
-            self.pos += 1
+            self._enter(len(values))
+            values.append(self._token_to_value(token))
+            self._leave(token)
+            self._check_elements(values, token)
+            cell_open = False
+            delimiters = 0
+
+        if delimiters > 1:
+            msg = f"Unexpected trailing {token.value!r} in inline array at line {token.line + 1}"
+            raise DecodingError(msg)
 
         # Validate length in strict mode
         if self.options.strict and len(values) != header["length"]:
@@ -526,109 +1383,229 @@ class ToonDecoder:
 
         return values
 
-    def _parse_tabular_array(self, header: dict[str, Any]) -> list[dict[str, Any]]:
+    def _at_inline_continuation(self, level: int) -> bool:
+        """Move to the next line if it continues a wrapped inline array.
+
+        A continuation is indented deeper than the array's key and is neither
+        a list item nor a ``key:`` field. Otherwise the position is unchanged.
+
+        Args:
+            level: Indent level of the array's key
+
+        Returns:
+            True if positioned at the first token of a continuation line
+        """
+        start = self.pos
+        token = self._peek_content()
+        if (
+            token.type not in (TokenType.EOF, TokenType.DASH)
+            and token.indent_level > level
+            and not self._at_field_start()
+        ):
+            return True
+        self.pos = start
+        return False
+
+    def _parse_tabular_array(self, header: dict[str, Any], level: int) -> list[dict[str, Any]]:
         """Parse tabular array: [N]{fields}: with data rows
 
+        Blank lines between rows are layout only and never produce a row. A
+        cell left empty between delimiters (the middle of ``1,,3``) decodes
+        to None, so a row of nulls is written ``null,null`` rather than left
+        blank. A single trailing delimiter (``1,2,``) is dropped and does not
+        close an empty cell; a second one (``1,2,,``) is an error. Cells missing
+        from a short row (non-strict mode only) are handled per
+        ``options.missing_cells``. With ``options.row_errors == "mark"`` a
+        malformed row decodes to ``{"__parse_error__": message}`` and does not
+        count toward ``max_errors``.
+
         Args:
             header: Array header info
+            level: Indent level of the header line
 
         Returns:
             List of dictionaries
         """
-        result: list[dict[str, Any]] = []
         fields = header["fields"]
-        header["delimiter"]
+        recover = self.options.row_errors == "mark"
+        columns = self._column_decoder(header)
+        # Full rows copy a dict that already holds every key, which skips
+        # growing and rehashing a new dict per row on wide tables
+        template = dict.fromkeys(fields)
+        result: list[dict[str, Any]] = []
+        for cells, error in self._iter_tabular_rows(header, level, recover):
+            if error is not None:
+                result.append({PARSE_ERROR_KEY: error})
+                continue
+            if self._spans is not None:
+                self._record_row_spans(len(result), cells, fields)
+            try:
+                row_values = self._row_values(cells, columns)
+            except ValueError as e:
+                if not recover:
+                    raise
+                result.append({PARSE_ERROR_KEY: str(e)})
+                continue
+            if self.options.missing_cells == "null" and len(row_values) < len(fields):
+                row_values.extend([None] * (len(fields) - len(row_values)))
+            if len(row_values) < len(fields):
+                result.append(dict(zip(fields, row_values, strict=False)))
+                continue
+            row = template.copy()
+            row.update(zip(fields, row_values, strict=False))
+            result.append(row)
+        return result
 
-        if not fields:
-            msg = "Tabular array must have fields"
-            raise DecodingError(msg)
+    def _column_decoder(self, header: dict[str, Any]) -> _ColumnDecoder | None:
+        """Create the column restorer for a tabular header, if it has encoded fields."""
+        if not header["encodings"]:
+            return None
+        return _ColumnDecoder(header["fields"], header["encodings"])
+
+    def _row_values(self, cells: list[Token | None], columns: _ColumnDecoder | None) -> list[Any]:
+        """Convert the cells of one tabular row, restoring encoded columns."""
+        values = [None if cell is None else self._token_to_value(cell) for cell in cells]
+        if columns is not None:
+            for index, value in columns.restore(cells, self._token_to_value).items():
+                values[index] = value
+        return values
 
-        # Skip newline after header
-        if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.NEWLINE:
-            self.pos += 1
+    def _record_row_spans(self, index: int, cells: list[Token | None], fields: list[str]) -> None:
+        """Record the spans of one tabular row and of its non-empty cells."""
+        present = [(name, cell) for name, cell in zip(fields, cells, strict=False) if cell]
+        if not present:
+            return
+        self._record_span(present[0][1], present[-1][1], index)
+        for name, cell in present:
+            self._record_span(cell, cell, index, name)
 
-        # Skip single INDENT token at start of block
-        if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.INDENT:
-            self.pos += 1
+    def _iter_tabular_rows(
+        self, header: dict[str, Any], level: int, recover: bool = False
+    ) -> Iterator[tuple[list[Token | None], str | None]]:
+        """Yield the raw cells of each tabular row, without converting them.
 
-        # Parse data rows
-        for _ in range(header["length"]):
-            # Parse row values
-            row_values: list[Any] = []
-            while self.pos < len(self.tokens):
-                token = self.tokens[self.pos]
+        Args:
+            header: Array header info
+            level: Indent level of the header line
+            recover: Yield a malformed row's first error instead of reporting it
 
-                if token.type in (TokenType.NEWLINE, TokenType.EOF, TokenType.DEDENT):
-                    break
+        Yields:
+            Tuple of (value tokens of one row with None for each empty cell,
+            error message if ``recover`` is set and the row is malformed)
 
-                # Skip delimiter tokens
-                if token.type == TokenType.COMMA:
-                    self.pos += 1
-                    continue
+        Raises:
+            ValidationError: If a row cannot be tokenized, or in strict mode if
+                a row width or the row count does not match the header
+        """
+        fields = header["fields"]
+        if not fields:
+            msg = "Tabular array must have fields"
+            raise DecodingError(msg)
 
-                # Parse value
-                value = self._token_to_value(token)
-                row_values.append(value)
+        tokens = self.tokens
+        end = len(tokens)
+        # Rows sit one level below the header (or on the header's level in compact mode)
+        row_count = 0
+        row_level: int | None = None
+        while row_count < header["length"]:
+            token = self._peek_content()
+            if token.type == TokenType.EOF or token.indent_level < level:
+                break
+            if row_level is None:
+                row_level = token.indent_level
+            elif token.indent_level != row_level:
+                break
+            row_count += 1
+            where = f"row {row_count} (line {token.line + 1})"
+
+            # A delimiter with no value before it closes an empty cell
+            cells: list[Token | None] = []
+            problems: list[str] = []
+            cell_open = True
+            delimiters = 0  # Delimiters since the last value
+            # Inlined _at_line_end(): this loop runs once per cell
+            while self.pos < end and tokens[self.pos].type not in _LINE_END_TOKENS:
+                token = tokens[self.pos]
                 self.pos += 1
 
-            # Create dict from fields and values
-            if len(row_values) != len(fields) and self.options.strict:
-                msg = f"Row width mismatch: expected {len(fields)}, got {len(row_values)}"
-                raise ValidationError(msg)
-
-            row_dict = dict(zip(fields, row_values, strict=False))
-            result.append(row_dict)
+                if token.type == TokenType.ERROR:
+                    # The lexer could not split the line; no cell is known
+                    problems.append(f"Malformed {where}: {token.value}")
+                    cells = [None] * len(fields)
+                    cell_open = False
+                    delimiters = 0
+                    continue
 
-            # Skip newline
-            if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.NEWLINE:
-                self.pos += 1
+                if token.type == TokenType.COMMA:
+                    if cell_open:
+                        cells.append(None)
+                    cell_open = True
+                    delimiters += 1
+                    continue
 
-        # Skip DEDENT token at end of block if present
-        if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.DEDENT:
-            self.pos += 1
+                if (
+                    self.options.strict
+                    and token.type not in _VALUE_TOKENS
+                    and len(cells) < len(fields)
+                ):
+                    column = fields[len(cells)]
+                    problems.append(
+                        f"Invalid cell at {where}, column {column!r}: "
+                        f"expected value, found {token.value!r}"
+                    )
+                    cells.append(None)
+                else:
+                    cells.append(token)
+                cell_open = False
+                delimiters = 0
+
+            if delimiters > 1:
+                problems.append(f"Unexpected trailing {token.value!r} at {where}")
+            elif len(cells) != len(fields) and self.options.strict:
+                msg = f"Row width mismatch at {where}: expected {len(fields)}, got {len(cells)}"
+                if len(cells) < len(fields):
+                    msg += f"; column {fields[len(cells)]!r} is missing"
+                problems.append(msg)
+
+            if problems and recover:
+                yield cells, problems[0]
+                continue
+            for msg in problems:
+                self._report(msg)
+            yield cells, None
 
-        return result
+        # Validate length in strict mode
+        if self.options.strict and row_count != header["length"]:
+            self._report(f"Array length mismatch: declared {header['length']}, got {row_count}")
 
-    def _parse_list_array(self, header: dict[str, Any], depth: int) -> list[Any]:
+    def _parse_list_array(self, header: dict[str, Any], level: int) -> list[Any]:
         """Parse list array: [N]: with - items
 
         Args:
             header: Array header info
-            depth: Current depth
+            level: Indent level of the header line
 
         Returns:
             List of values
         """
         values: list[Any] = []
 
-        # Skip newline after header
-        if self.pos < len(self.tokens) and self.tokens[self.pos].type == TokenType.NEWLINE:
-            self.pos += 1
-
-        # Parse list items
-        while len(values) < header["length"] and self.pos < len(self.tokens):
-            token = self.tokens[self.pos]
-
-            if token.type == TokenType.EOF:
+        # Items sit one level below the header (or on the header's level in compact mode)
+        item_level: int | None = None
+        while len(values) < header["length"]:
+            token = self._peek_content()
+            if token.type != TokenType.DASH or token.indent_level < level:
                 break
-
-            # Skip indents/dedents/newlines
-            if token.type in (TokenType.INDENT, TokenType.NEWLINE):
-                self.pos += 1
-                continue
-
-            if token.type == TokenType.DEDENT:
+            if item_level is None:
+                item_level = token.indent_level
+            elif token.indent_level != item_level:
                 break
 
-            # List item marker: -
-            if token.type == TokenType.DASH:
-                self.pos += 1
-
-                # Parse item value
-                item_value = self._parse_value(depth + 1)
-                values.append(item_value)
-            else:
-                self.pos += 1
+            self.pos += 1
+            self._enter(len(values))
+            values.append(self._parse_list_item(token.indent_level))
+            self._leave(token)
+            self._check_elements(values, token)
 
         # Validate length in strict mode
         if self.options.strict and len(values) != header["length"]:
@@ -648,11 +1625,10 @@ class ToonDecoder:
         """
         if token.type == TokenType.NULL:
             return None
-        if token.type in (TokenType.BOOLEAN, TokenType.NUMBER) or token.type in (
-            TokenType.STRING,
-            TokenType.QUOTED_STRING,
-        ):
+        if token.type in (TokenType.BOOLEAN, TokenType.NUMBER):
             return token.value
+        if token.type in (TokenType.STRING, TokenType.QUOTED_STRING):
+            return self._string_value(token.value)
         if token.type == TokenType.IDENTIFIER:
             # Unquoted identifier - type inference
             if self.options.type_inference:
@@ -665,15 +1641,161 @@ class ToonDecoder:
                 if value_str == "false":
                     return False
                 # Try number
-                try:
-                    if "." in value_str:
-                        return float(value_str)
-                    return int(value_str)
-                except ValueError:
-                    pass
-            return token.value
+                number = parse_number(value_str)
+                if number is not None:
+                    return number
+            if self.options.nonfinite_tokens == "error" and _NONFINITE_PATTERN.fullmatch(
+                str(token.value)
+            ):
+                msg = (
+                    f"Unquoted non-finite number {token.value!r} at line {token.line + 1}; "
+                    "quote it to keep it as a string"
+                )
+                raise ValueError(msg)
+            return self._string_value(token.value)
         return token.value
 
+    def _string_value(self, value: Any) -> Any:
+        """Apply the string-level options to a decoded string value.
+
+        Args:
+            value: Decoded string
+
+        Returns:
+            The coerced value for strings matching ``coerce_types`` or boolean
+            strings, otherwise the (interned) string
+        """
+        if self.options.coerce_types and isinstance(value, str):
+            coerced = _coerce_string(value, self.options.coerce_types)
+            if coerced is not _NOT_COERCED:
+                return coerced
+        if self.options.coerce_string_booleans and isinstance(value, str):
+            text = value if self.options.string_booleans_case_sensitive else value.lower()
+            if text in ("true", "false"):
+                return text == "true"
+        return self._intern(value)
+
+    def _intern(self, value: Any) -> Any:
+        """Return the shared instance of a string value when interning is enabled.
+
+        Args:
+            value: Decoded value
+
+        Returns:
+            Previously seen equal string, or the value itself
+        """
+        if not self.options.intern_strings or not isinstance(value, str):
+            return value
+        return self._interned.setdefault(value, value)
+
+
+def _coerce_string(value: str, types: tuple[str, ...]) -> Any:
+    """Convert a string matching the literal form of one of ``types``.
+
+    Args:
+        value: Decoded string value
+        types: Enabled types ("int", "float", "bool", "null")
+
+    Returns:
+        The typed value, or ``_NOT_COERCED`` if no enabled type matches
+    """
+    if "int" in types and _INT_FORM.fullmatch(value):
+        return int(value)
+    if "float" in types and _FLOAT_FORM.fullmatch(value):
+        return float(value)
+    if "bool" in types and value in ("true", "false"):
+        return value == "true"
+    if "null" in types and value == "null":
+        return None
+    return _NOT_COERCED
+
+
+def _typed_cell(kind: str, value: Any) -> Any:
+    """Check a cell value against a schema column type.
+
+    Args:
+        kind: Column type from COLUMN_TYPES
+        value: Cell value as lexed (None for null)
+
+    Returns:
+        The value (a float for ints in "float" columns), or ``_MISMATCH``
+    """
+    if value is None or kind == "any":
+        return value
+    value_type = type(value)
+    if kind == "int":
+        return value if value_type is int else _MISMATCH
+    if kind == "float":
+        return float(value) if value_type in (int, float) else _MISMATCH
+    if kind == "str":
+        return value if value_type is str else _MISMATCH
+    return value if value_type is bool else _MISMATCH
+
+
+def _fields(obj: dict[str, Any]) -> Iterable[tuple[str, Any]]:
+    """Key-value pairs of an object node, including collected repeats."""
+    return obj.pairs if isinstance(obj, _MultiDict) else obj.items()
+
+
+def _to_pairs(value: Any) -> Any:
+    """Convert every object in a decoded value to a list of (key, value) tuples."""
+    if isinstance(value, dict):
+        return [(key, _to_pairs(item)) for key, item in _fields(value)]
+    if isinstance(value, list):
+        return [_to_pairs(item) for item in value]
+    return value
+
+
+def _comment_text(line: str) -> str:
+    """Text of a full-line comment without the ``#`` and one following space."""
+    text = line[1:].rstrip()
+    return text[1:] if text.startswith(" ") else text
+
+
+def _token_length(line: str, start: int, token: Token) -> int:
+    """Length of a token's source text, which starts at column ``start`` of ``line``."""
+    if token.type == TokenType.QUOTED_STRING:
+        i = start + 1
+        while i < len(line) and line[i] != '"':
+            i += 2 if line[i] == "\\" else 1
+        return i + 1 - start
+    if token.type == TokenType.IDENTIFIER:
+        return len(str(token.value))
+    if token.type == TokenType.NUMBER:
+        match = _NUMBER_TEXT.match(line, start)
+        return match.end() - start if match else 1
+    if token.type == TokenType.BOOLEAN:
+        return 4 if token.value else 5
+    if token.type == TokenType.NULL:
+        return 4
+    return 1
+
+
+def _token_offset(data_str: str, token: Token) -> int:
+    """Character offset of a token in the text it was lexed from."""
+    lines = data_str.split("\n")
+    line = lines[token.line]
+    line_start = sum(len(text) + 1 for text in lines[: token.line])
+    return line_start + len(line) - len(line.lstrip()) + token.column
+
+
+def _format_errors(errors: list[str]) -> str:
+    """Join collected errors into one exception message."""
+    if len(errors) == 1:
+        return errors[0]
+    return f"{len(errors)} errors:\n" + "\n".join(f"  {error}" for error in errors)
+
+
+def _looks_like_json(data_str: str) -> bool:
+    """Check whether text is a JSON object or array (used only after a decode failure)."""
+    if not data_str.lstrip().startswith(("{", "[")):
+        return False
+    try:
+        json.loads(data_str)
+    except (ValueError, RecursionError):
+        return False
+    return True
+
 
 def decode(data_str: str, options: ToonDecodeOptions | None = None) -> ToonValue:
     """Convenience function to decode TOON format.
@@ -695,3 +1817,297 @@ def decode(data_str: str, options: ToonDecodeOptions | None = None) -> ToonValue
     """
     decoder = ToonDecoder(options)
     return decoder.decode(data_str)
+
+
+class ChunkedDecoder:
+    """Decoder that accepts a TOON document in arbitrary chunks.
+
+    Chunks are buffered until :meth:`finish` is called, so they may split a
+    line, a token or even a multibyte UTF-8 character. This suits HTTP
+    handlers that receive a request body piece by piece.
+
+    Examples:
+        >>> decoder = ChunkedDecoder()
+        >>> decoder.feed("rows[2]{id}:\\n  1\\n")
+        >>> decoder.feed(b"  2\\n")
+        >>> decoder.finish()
+        {'rows': [{'id': 1}, {'id': 2}]}
+    """
+
+    def __init__(self, options: ToonDecodeOptions | None = None) -> None:
+        """Initialize chunked decoder.
+
+        Args:
+            options: Decode options
+        """
+        self.options = options
+        self._utf8 = Utf8ChunkDecoder("TOON input")
+        self._parts: list[str] = []
+        self._finished = False
+
+    def feed(self, chunk: str | bytes) -> None:
+        """Buffer the next piece of the document.
+
+        Args:
+            chunk: Text, or UTF-8 bytes, of any length
+
+        Raises:
+            DecodingError: If called after finish() or the bytes are not valid
+                UTF-8, including input that starts with a UTF-16 byte order mark
+        """
+        if self._finished:
+            msg = "Cannot feed a ChunkedDecoder after finish()"
+            raise DecodingError(msg)
+        try:
+            self._parts.append(self._utf8.decode(chunk))
+        except UnicodeDecodeError as e:
+            msg = f"TOON input is not valid UTF-8: {e}"
+            raise DecodingError(msg) from e
+        except UnicodeError as e:
+            raise DecodingError(str(e)) from e
+
+    def finish(self) -> ToonValue:
+        """Decode everything fed so far.
+
+        Returns:
+            Python data structure
+
+        Raises:
+            DecodingError: If called twice, the input ends inside a UTF-8
+                sequence, or the document is not valid TOON
+        """
+        if self._finished:
+            msg = "ChunkedDecoder.finish() was already called"
+            raise DecodingError(msg)
+        self._finished = True
+        try:
+            self._parts.append(self._utf8.finish())
+        except UnicodeDecodeError as e:
+            msg = f"TOON input is not valid UTF-8: {e}"
+            raise DecodingError(msg) from e
+        text = "".join(self._parts)
+        self._parts = []
+        return decode(text, self.options)
+
+
+def decode_fragment(
+    data_str: str, options: ToonDecodeOptions | None = None
+) -> tuple[ToonValue, str]:
+    """Decode one TOON value from the start of a string and return the rest.
+
+    Useful for TOON embedded in templates or command-line arguments, where
+    the value is followed by unrelated text.
+
+    Args:
+        data_str: Text starting with a TOON value
+        options: Decode options
+
+    Returns:
+        Tuple of (value, remaining text)
+
+    Raises:
+        DecodingError: If the input is empty or the value is malformed
+
+    Examples:
+        >>> decode_fragment("{a: 1} trailing")
+        ({'a': 1}, 'trailing')
+        >>> decode_fragment("[2]: 1,2 rest")
+        ([1, 2], 'rest')
+    """
+    value, consumed = ToonDecoder(options).decode_fragment(data_str)
+    return value, data_str[consumed:]
+
+
+def decode_spans(
+    data_str: str, options: ToonDecodeOptions | None = None
+) -> list[tuple[str, Span]]:
+    """Map every value of a TOON document to its source range.
+
+    Args:
+        data_str: TOON formatted string
+        options: Decode options
+
+    Returns:
+        ``(JSON Pointer, Span)`` pairs in document order; the root is ``""``
+
+    Raises:
+        DecodingError: If decoding fails
+
+    Examples:
+        >>> for path, span in decode_spans("id: 1\\nname: Ada"):
+        ...     print(repr(path), span.start_line, span.start_col, span.end_col)
+        '' 0 0 9
+        '/id' 0 0 5
+        '/name' 1 0 9
+    """
+    _, spans = ToonDecoder(options).decode_with_spans(data_str)
+    return list(spans.items())
+
+
+def decode_columns(
+    data_str: str,
+    key: str | None = None,
+    columns: list[str] | None = None,
+    options: ToonDecodeOptions | None = None,
+) -> dict[str, list[Any]]:
+    """Decode a tabular array into a mapping of column name to values.
+
+    Args:
+        data_str: TOON document whose root, or root field ``key``, is a tabular array
+        key: Root field holding the table (None for a root array)
+        columns: Columns to return, in this order (None for all)
+        options: Decode options
+
+    Returns:
+        Mapping of column name to list of values
+
+    Raises:
+        FormatNotSupportedError: If the target is not a tabular array
+
+    Examples:
+        >>> decode_columns("rows[2]{a,b}:\\n  1,x\\n  2,y", key="rows", columns=["b"])
+        {'b': ['x', 'y']}
+    """
+    return ToonDecoder(options).decode_columns(data_str, key=key, columns=columns)
+
+
+def decode_typed_columns(
+    data_str: str,
+    schema: Mapping[str, str],
+    key: str | None = None,
+    mismatch: Literal["raise", "null"] = "raise",
+    options: ToonDecodeOptions | None = None,
+) -> dict[str, list[Any]]:
+    """Decode a tabular array into columns of declared types.
+
+    Args:
+        data_str: TOON document whose root, or root field ``key``, is a tabular array
+        schema: Column name to type ("int", "float", "str", "bool" or "any")
+        key: Root field holding the table (None for a root array)
+        mismatch: "raise" fails on a cell of the wrong type, "null" stores None
+        options: Decode options
+
+    Returns:
+        Mapping of column name to list of values
+
+    Raises:
+        FormatNotSupportedError: If the target is not a tabular array
+        ValidationError: If a cell does not match its type and mismatch is "raise"
+
+    Examples:
+        >>> text = "[2]{id,ok}:\\n  1,true\\n  x,false"
+        >>> decode_typed_columns(text, {"id": "int"}, mismatch="null")
+        {'id': [1, None]}
+    """
+    return ToonDecoder(options).decode_typed_columns(data_str, schema, key=key, mismatch=mismatch)
+
+
+def decode_rows(
+    data_str: str,
+    key: str | None = None,
+    row_factory: Callable[..., Any] | None = None,
+    options: ToonDecodeOptions | None = None,
+) -> tuple[list[str], list[Any]]:
+    """Decode a tabular array into its field names and tuple (or typed) rows.
+
+    Args:
+        data_str: TOON document whose root, or root field ``key``, is a tabular array
+        key: Root field holding the table (None for a root array)
+        row_factory: Callable building each row from keyword arguments (None for tuples)
+        options: Decode options
+
+    Returns:
+        Tuple of (field names, rows)
+
+    Raises:
+        FormatNotSupportedError: If the target is not a tabular array
+
+    Examples:
+        >>> from collections import namedtuple
+        >>> Row = namedtuple("Row", "a b")
+        >>> decode_rows("rows[1]{a,b}:\\n  1,x", key="rows", row_factory=Row)
+        (['a', 'b'], [Row(a=1, b='x')])
+    """
+    return ToonDecoder(options).decode_rows(data_str, key=key, row_factory=row_factory)
+
+
+def validate_toon(data_str: str, options: ToonDecodeOptions | None = None) -> list[str]:
+    """Check a TOON document and list the problems found.
+
+    Strict-mode tabular row errors are collected up to ``options.max_errors``
+    so a batch of bad rows can be fixed at once; any other error ends the
+    check and is reported after them.
+
+    Args:
+        data_str: TOON formatted string
+        options: Decode options (max_errors controls how many row errors are collected)
+
+    Returns:
+        Error messages, empty if the document is valid
+
+    Examples:
+        >>> validate_toon("[2]{a,b}:\\n  1,2\\n  3", ToonDecodeOptions(max_errors=10))
+        ["Row width mismatch at row 2 (line 3): expected 2, got 1; column 'b' is missing"]
+    """
+    decoder = ToonDecoder(options)
+    try:
+        decoder.decode(data_str)
+    except (DecodingError, ValidationError) as e:
+        errors = list(decoder.errors)
+        if getattr(e, "errors", None) != errors:
+            errors.append(str(e))
+        return errors
+    return []
+
+
+def decode_fileobj(
+    source: Any,
+    options: ToonDecodeOptions | None = None,
+    chunk_size: int = 65536,
+) -> ToonValue:
+    """Decode TOON read from a file-like object or an iterable of lines.
+
+    Objects with a ``read`` method (open files, ``io.StringIO``,
+    ``gzip.GzipFile``, streaming HTTP bodies) are read in ``chunk_size``
+    pieces; anything else is iterated as lines until exhausted. Each item is
+    one line whether or not it ends with its own line break, so an empty
+    item is a blank line. Text and binary sources both work; bytes must be
+    valid UTF-8. Errors raised by the source itself propagate unchanged.
+
+    Args:
+        source: File-like object or iterable of str/bytes lines
+        options: Decode options
+        chunk_size: Number of characters/bytes per read call
+
+    Returns:
+        Python data structure
+
+    Raises:
+        ValueError: If chunk_size is not positive
+        DecodingError: If the content is not valid UTF-8 or not valid TOON
+
+    Examples:
+        >>> import io
+        >>> decode_fileobj(io.StringIO("name: Alice"))
+        {'name': 'Alice'}
+        >>> decode_fileobj(io.BytesIO(b"[2]: 1,2"))
+        [1, 2]
+    """
+    if chunk_size <= 0:
+        msg = "chunk_size must be positive"
+        raise ValueError(msg)
+
+    decoder = ChunkedDecoder(options)
+    read = getattr(source, "read", None)
+    if callable(read):
+        # Text and binary readers signal EOF with "" and b"" respectively
+        while chunk := read(chunk_size):
+            decoder.feed(chunk)
+        return decoder.finish()
+
+    for line in source:
+        decoder.feed(line)
+        newline = b"\n" if isinstance(line, bytes) else "\n"
+        if not line.endswith(newline):
+            decoder.feed(newline)
+    return decoder.finish()
@@ -1,6 +1,6 @@
 """ToonDiff module."""
 
-from .engine import ToonDiffer
+from .engine import ToonDiffer, loosely_equal
 from .formatter import DiffFormatter
 from .models import ChangeType, DiffChange, DiffResult
 
@@ -11,4 +11,5 @@ __all__ = [
     "DiffFormatter",
     "DiffResult",
     "ToonDiffer",
+    "loosely_equal",
 ]
@@ -8,6 +8,15 @@ from .models import ChangeType, DiffChange, DiffResult
 class ToonDiffer:
     """Recursive difference engine for structured data."""
 
+    def __init__(self, loose_numbers: bool = False) -> None:
+        """Initialize differ.
+
+        Args:
+            loose_numbers: Compare ints and floats by value, so a ``1.0`` that
+                round-tripped as ``1`` is not reported as a change
+        """
+        self.loose_numbers = loose_numbers
+
     def diff(self, obj1: Any, obj2: Any) -> DiffResult:
         """Compute the difference between two objects.
 
@@ -23,6 +32,14 @@ class ToonDiffer:
         return DiffResult(changes=changes)
 
     def _diff_recursive(self, obj1: Any, obj2: Any, path: str, changes: list[DiffChange]) -> None:
+        # 0. Numbers compared by value across int/float
+        if self.loose_numbers and _is_number(obj1) and _is_number(obj2):
+            if obj1 != obj2:
+                changes.append(
+                    DiffChange(path=path, type=ChangeType.CHANGE, old_value=obj1, new_value=obj2)
+                )
+            return
+
         # 1. Type Mismatch
         if type(obj1) is not type(obj2):
             changes.append(
@@ -108,3 +125,31 @@ class ToonDiffer:
                     new_value=obj2,
                 )
             )
+
+
+def loosely_equal(obj1: Any, obj2: Any) -> bool:
+    """Check whether two values are equal, comparing numbers by value.
+
+    Dict key order is ignored and ints equal floats of the same value, but
+    booleans never equal numbers. Useful for round-trip assertions where
+    ``1.0`` decodes back as ``1``.
+
+    Args:
+        obj1: First value
+        obj2: Second value
+
+    Returns:
+        True if the values have no differences
+
+    Examples:
+        >>> loosely_equal({"a": 1.0, "b": [2]}, {"b": [2.0], "a": 1})
+        True
+        >>> loosely_equal({"a": True}, {"a": 1})
+        False
+    """
+    return ToonDiffer(loose_numbers=True).diff(obj1, obj2).match
+
+
+def _is_number(value: Any) -> bool:
+    """Check for an int or float that is not a bool."""
+    return isinstance(value, (int, float)) and not isinstance(value, bool)
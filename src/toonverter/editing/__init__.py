@@ -0,0 +1,6 @@
+"""Editing of TOON documents in place, preserving untouched source text."""
+
+from .document import ToonDocument, patch_toon
+
+
+__all__ = ["ToonDocument", "patch_toon"]
@@ -0,0 +1,228 @@
+"""Splice re-encoded values into TOON source text.
+
+A config-editing tool that decodes a document, changes one value and encodes
+the result loses comments, blank lines and any layout the canonical encoder
+does not reproduce. :class:`ToonDocument` instead re-encodes only the field
+that changed and swaps its text in using the source spans recorded by
+:meth:`ToonDecoder.decode_with_spans`; every other byte stays as it was.
+
+The unit of replacement is a block-form object field (``key: ...`` on its
+own line, or the first field of a list item). Values that are not such a
+field - tabular cells, inline array items, list items - are replaced by
+re-encoding the nearest enclosing field.
+"""
+
+from typing import Any
+
+from toonverter.core.spec import Delimiter, ToonDecodeOptions, ToonEncodeOptions
+from toonverter.decoders.lexer import ToonLexer
+from toonverter.decoders.toon_decoder import Span, ToonDecoder
+from toonverter.encoders.toon_encoder import ToonEncoder
+from toonverter.utils.pointer import PathSegment, format_pointer, parse_pointer
+
+
+class ToonDocument:
+    """A decoded TOON document that can be edited without reformatting it.
+
+    Attributes:
+        text: Original source text
+        value: Decoded value of ``text``
+        spans: Source span of every value, keyed by JSON Pointer
+    """
+
+    def __init__(self, text: str, options: ToonDecodeOptions | None = None) -> None:
+        """Decode a document and record its spans.
+
+        Args:
+            text: TOON source text
+            options: Decode options
+
+        Raises:
+            DecodingError: If the text is not valid TOON
+        """
+        options = options or ToonDecodeOptions()
+        self.text = text
+        self.value, self.spans = ToonDecoder(options).decode_with_spans(text)
+        self._lines = text.split("\n")
+        self._line_starts = [0]
+        for line in self._lines[:-1]:
+            self._line_starts.append(self._line_starts[-1] + len(line) + 1)
+        # Re-encoded text follows the document's own indent unit and delimiter
+        layout = ToonLexer(text, indent_size=options.indent_size)
+        self._indent_size = layout.indent_size
+        self._encoder = ToonEncoder(
+            ToonEncodeOptions(
+                indent_size=layout.indent_size,
+                delimiter=Delimiter.from_string(layout.delimiter),
+            )
+        )
+
+    def splice(self, pointer: str, value: Any) -> str:
+        """Return the source with the value at ``pointer`` replaced or added.
+
+        A missing object key is inserted after the object's last field; a
+        list index equal to the length (or ``-``) appends. Indentation of the
+        new text matches its position in the document.
+
+        Args:
+            pointer: JSON Pointer of the value to set ("" replaces the root)
+            value: New value
+
+        Returns:
+            Updated TOON source; text outside the rewritten field is unchanged
+
+        Raises:
+            LookupError: If the parent of ``pointer`` does not exist or the
+                last segment is not a valid list index
+
+        Examples:
+            >>> doc = ToonDocument("# settings\\nname: demo  # shown in UI\\nport: 80")
+            >>> print(doc.splice("/port", 8080))
+            # settings
+            name: demo  # shown in UI
+            port: 8080
+        """
+        return self._splice(list(parse_pointer(pointer)), value)
+
+    def _splice(self, segments: list[PathSegment], value: Any) -> str:
+        """Replace the value at ``segments``, widening to the parent when needed."""
+        if not segments:
+            return self._encoder.encode(value)
+
+        parent_segments = segments[:-1]
+        parent = self._resolve(parent_segments)
+        key = segments[-1]
+        if isinstance(parent, dict):
+            key = str(key)
+            if key in parent:
+                if self._is_block_field([*parent_segments, key]):
+                    return self._replace_field([*parent_segments, key], value)
+            else:
+                anchor = self._last_block_field(parent_segments, parent)
+                if anchor is not None:
+                    return self._insert_field(anchor, key, value)
+            new_parent: Any = {**parent, key: value}
+        elif isinstance(parent, list):
+            index = _list_index(key, len(parent), format_pointer(segments))
+            new_parent = [*parent[:index], value, *parent[index + 1 :]]
+        else:
+            msg = f"Cannot set {format_pointer(segments)!r}: parent is not an object or array"
+            raise LookupError(msg)
+        return self._splice(parent_segments, new_parent)
+
+    def _resolve(self, segments: list[PathSegment]) -> Any:
+        """Return the decoded value at ``segments``."""
+        value = self.value
+        for depth, segment in enumerate(segments, start=1):
+            try:
+                value = value[int(segment)] if isinstance(value, list) else value[segment]
+            except (LookupError, TypeError, ValueError) as e:
+                msg = f"No value at {format_pointer(segments[:depth])!r}"
+                raise LookupError(msg) from e
+        return value
+
+    def _is_block_field(self, segments: list[PathSegment]) -> bool:
+        """Check whether an object field is written as ``key: ...`` on its own line."""
+        span = self.spans.get(format_pointer(segments))
+        if span is None:
+            return False
+        before = self._lines[span.start_line][: span.start_col]
+        if before.strip() and not (before.endswith("- ") and not before[:-2].strip()):
+            return False
+        if len(segments) == 1:
+            return True
+        # A tabular row starts at its first cell; a block object starts before its fields
+        parent_span = self.spans.get(format_pointer(segments[:-1]))
+        return parent_span is not None and (parent_span.start_line, parent_span.start_col) != (
+            span.start_line,
+            span.start_col,
+        )
+
+    def _last_block_field(
+        self, segments: list[PathSegment], obj: dict[str, Any]
+    ) -> list[PathSegment] | None:
+        """Return the path of the last field of ``obj`` if it is a block field."""
+        if not obj:
+            return None
+        last = [*segments, list(obj)[-1]]
+        return last if self._is_block_field(last) else None
+
+    def _replace_field(self, segments: list[PathSegment], value: Any) -> str:
+        """Re-encode one block field and swap it into the source."""
+        span = self.spans[format_pointer(segments)]
+        lines = self._encoder.encode({segments[-1]: value}).split("\n")
+        prefix = " " * self._field_indent(span)
+        new_text = "\n".join([lines[0], *(prefix + line for line in lines[1:])])
+        return self._replace(span, new_text)
+
+    def _insert_field(self, anchor: list[PathSegment], key: str, value: Any) -> str:
+        """Add a block field on the lines after the field at ``anchor``."""
+        span = self.spans[format_pointer(anchor)]
+        prefix = " " * self._field_indent(span)
+        lines = self._encoder.encode({key: value}).split("\n")
+        end = self._offset(span.end_line, span.end_col)
+        line_end = self.text.find("\n", end)
+        if line_end == -1:
+            line_end = len(self.text)
+        new_text = "".join("\n" + prefix + line for line in lines)
+        return self.text[:line_end] + new_text + self.text[line_end:]
+
+    def _field_indent(self, span: Span) -> int:
+        """Column of the fields of the object containing the field at ``span``."""
+        line = self._lines[span.start_line]
+        indent = len(line) - len(line.lstrip())
+        # The first field of a list item sits after "- " one level deeper
+        return indent + self._indent_size if span.start_col > indent else indent
+
+    def _replace(self, span: Span, new_text: str) -> str:
+        """Swap the source text covered by ``span`` for ``new_text``."""
+        start = self._offset(span.start_line, span.start_col)
+        end = self._offset(span.end_line, span.end_col)
+        return self.text[:start] + new_text + self.text[end:]
+
+    def _offset(self, line: int, column: int) -> int:
+        """Convert a line and column into an offset in the source text."""
+        return self._line_starts[line] + column
+
+
+def _list_index(segment: PathSegment, length: int, pointer: str) -> int:
+    """Parse a list index segment; ``-`` or ``length`` means append."""
+    if segment == "-":
+        return length
+    try:
+        index = int(segment)
+    except ValueError as e:
+        msg = f"Invalid array index in {pointer!r}"
+        raise LookupError(msg) from e
+    if not 0 <= index <= length:
+        msg = f"Array index out of range in {pointer!r}"
+        raise LookupError(msg)
+    return index
+
+
+def patch_toon(
+    text: str, pointer: str, value: Any, options: ToonDecodeOptions | None = None
+) -> str:
+    """Set one value in a TOON document, leaving the rest of the text untouched.
+
+    Args:
+        text: TOON source text
+        pointer: JSON Pointer of the value to set or add
+        value: New value
+        options: Decode options for reading ``text``
+
+    Returns:
+        Updated TOON source
+
+    Raises:
+        DecodingError: If ``text`` is not valid TOON
+        LookupError: If ``pointer`` cannot be set
+
+    Examples:
+        >>> print(patch_toon("a: 1\\n\\nb: 2", "/c", [1, 2]))
+        a: 1
+        <BLANKLINE>
+        b: 2
+        c[2]: 1,2
+    """
+    return ToonDocument(text, options).splice(pointer, value)
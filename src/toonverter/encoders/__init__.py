@@ -1,7 +1,16 @@
 """Encoders module for TOON Converter - Official TOON v2.0 Specification."""
 
 from .stream_encoder import ToonStreamEncoder
-from .toon_encoder import ToonEncoder, encode
+from .toon_encoder import ToonEncoder, encode, encode_minified, encode_pretty
+from .value_normalizer import FALLBACK_DECLINED, set_fallback_encoder
 
 
-__all__ = ["ToonEncoder", "ToonStreamEncoder", "encode"]
+__all__ = [
+    "FALLBACK_DECLINED",
+    "ToonEncoder",
+    "ToonStreamEncoder",
+    "encode",
+    "encode_minified",
+    "encode_pretty",
+    "set_fallback_encoder",
+]
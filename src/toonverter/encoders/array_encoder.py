@@ -6,10 +6,12 @@ Implements the three array forms from TOON v2.0 specification:
 3. List: key[N]:\n  - item1\n  - item2
 """
 
+from collections.abc import Iterator, Mapping
 from typing import Any
 
 from toonverter.core.spec import ArrayForm
 
+from .column_encoding import REPEATED, ColumnEncoder
 from .indentation import IndentationManager
 from .number_encoder import NumberEncoder
 from .string_encoder import StringEncoder
@@ -29,6 +31,8 @@ class ArrayEncoder:
         string_encoder: StringEncoder,
         number_encoder: NumberEncoder,
         indent_mgr: IndentationManager,
+        align_columns: bool = False,
+        column_encoding: Mapping[str, str] | None = None,
     ) -> None:
         """Initialize array encoder.
 
@@ -36,11 +40,15 @@ class ArrayEncoder:
             string_encoder: String encoder for quoting
             number_encoder: Number encoder for canonical form
             indent_mgr: Indentation manager
+            align_columns: Pad tabular cells so columns line up
+            column_encoding: "delta" or "rle" per tabular field name
         """
         self.str_enc = string_encoder
         self.num_enc = number_encoder
         self.indent_mgr = indent_mgr
         self.delimiter = string_encoder.delimiter
+        self.align_columns = align_columns
+        self.column_encoding = column_encoding or {}
 
     def detect_array_form(self, arr: list[Any]) -> ArrayForm:
         """Detect which array form to use.
@@ -79,7 +87,10 @@ class ArrayEncoder:
                     # Check keys consistency
                     # Use tuple of sorted keys for stable comparison
                     current_keys = tuple(sorted(item.keys()))
-                    if i == 0:
+                    if not current_keys:
+                        # Empty objects have no fields to form a header
+                        is_tabular = False
+                    elif i == 0:
                         tabular_keys = current_keys
                     elif current_keys != tabular_keys:
                         is_tabular = False
@@ -160,19 +171,70 @@ class ArrayEncoder:
         fields = list(arr[0].keys())
 
         # Header line: key[N]{field1,field2}: (with delimiter marker if not comma)
-        fields_str = self.delimiter.join(fields)
+        fields_str = self._header_fields(fields)
         delimiter_marker = "" if self.delimiter == "," else self.delimiter
         header = f"{indent}{key}[{length}{delimiter_marker}]{{{fields_str}}}:"
 
         lines = [header]
+        lines.extend(f"{row_indent}{row}" for row in self._encode_rows(arr, fields))
+        return lines
 
-        # Data rows
-        for item in arr:
-            values = [self._encode_value(item[field]) for field in fields]
-            row = self.delimiter.join(values)
-            lines.append(f"{row_indent}{row}")
+    def _header_fields(self, fields: list[str]) -> str:
+        """Encode the field list of a tabular header, with column encoding annotations."""
+        keys = [self.str_enc.encode_key(f, header=True) for f in fields]
+        if any(f in self.column_encoding for f in fields):
+            columns = ColumnEncoder(fields, self.column_encoding)
+            keys = [columns.header_field(i, key) for i, key in enumerate(keys)]
+        return self.delimiter.join(keys)
 
-        return lines
+    def _encode_rows(self, arr: list[dict[str, Any]], fields: list[str]) -> Iterator[str]:
+        """Encode the data rows of a tabular array one at a time.
+
+        Each row is joined as soon as its cells are encoded, so only the
+        finished lines are kept, never a table of cells. With
+        ``align_columns`` every cell except the last is padded to the widest
+        cell of its column; the widths come from a first pass that encodes
+        and discards the cells. Decoding trims the padding.
+
+        Args:
+            arr: Array of dicts with uniform keys
+            fields: Field names in column order
+
+        Yields:
+            One joined row per item, without indentation
+        """
+        encode = self._encode_value
+        join = self.delimiter.join
+        encoded_columns = any(f in self.column_encoding for f in fields)
+        if not self.align_columns and not encoded_columns:
+            for item in arr:
+                yield join([encode(item[field]) for field in fields])
+            return
+        if not self.align_columns:
+            for cells in self._encoded_cells(arr, fields):
+                yield join(cells)
+            return
+
+        widths = [0] * (len(fields) - 1)
+        for cells in self._encoded_cells(arr, fields):
+            for index, width in enumerate(widths):
+                widths[index] = max(width, len(cells[index]))
+        for cells in self._encoded_cells(arr, fields):
+            for index, width in enumerate(widths):
+                cells[index] = cells[index].ljust(width)
+            yield join(cells)
+
+    def _encoded_cells(self, arr: list[dict[str, Any]], fields: list[str]) -> Iterator[list[str]]:
+        """Yield the encoded cells of each row, applying column encodings."""
+        encode = self._encode_value
+        if not any(f in self.column_encoding for f in fields):
+            for item in arr:
+                yield [encode(item[field]) for field in fields]
+            return
+        columns = ColumnEncoder(fields, self.column_encoding, self.delimiter, self.align_columns)
+        for item in arr:
+            values = columns.encode_row([item[field] for field in fields])
+            yield ["" if value is REPEATED else encode(value) for value in values]
 
     def encode_list(self, key: str, arr: list[Any], depth: int, value_encoder: Any) -> list[str]:
         """Encode list array with - notation.
@@ -191,46 +253,12 @@ class ArrayEncoder:
             ['items[3]:', '  - 1', '  - a', '  - true']
         """
         indent = self.indent_mgr.indent(depth)
-        item_indent = self.indent_mgr.indent(depth + 1)
 
         length = len(arr)
         header = f"{indent}{key}[{length}]:"
 
         lines = [header]
-
-        for item in arr:
-            if isinstance(item, dict):
-                # Dict item - encode as nested object
-                # Per TOON spec: first field on dash line, remaining fields at depth+2
-                item_lines = value_encoder.encode_object(item, depth + 2)
-                if item_lines:
-                    # First line with "- " prefix at depth+1
-                    lines.append(f"{item_indent}- {item_lines[0]}")
-                    # Rest of lines already at depth+2 from encode_object
-                    lines.extend(item_lines[1:])
-            elif isinstance(item, list):
-                # Nested array - encode recursively
-                if not item:
-                    # Empty array
-                    lines.append(f"{item_indent}- [0]:")
-                else:
-                    # Detect form and encode nested array
-                    nested_form = self.detect_array_form(item)
-                    if nested_form == ArrayForm.INLINE:
-                        # Inline nested array: - [3]: 1,2,3
-                        nested_inline = self._encode_inline_values(item)
-                        lines.append(f"{item_indent}- [{len(item)}]: {nested_inline}")
-                    else:
-                        # List or tabular form - needs full recursion
-                        nested_lines = self._encode_nested_array_item(
-                            item, depth + 1, value_encoder
-                        )
-                        lines.extend(nested_lines)
-            else:
-                # Primitive item
-                encoded = self._encode_value(item)
-                lines.append(f"{item_indent}- {encoded}")
-
+        lines.extend(self._encode_list_items(arr, depth + 1, value_encoder))
         return lines
 
     def encode_root_array_inline(self, arr: list[Any]) -> str:
@@ -272,18 +300,12 @@ class ArrayEncoder:
         fields = list(arr[0].keys())
 
         # Header: [N]{fields}: (with delimiter marker if not comma)
-        fields_str = self.delimiter.join(fields)
+        fields_str = self._header_fields(fields)
         delimiter_marker = "" if self.delimiter == "," else self.delimiter
         header = f"[{length}{delimiter_marker}]{{{fields_str}}}:"
 
         lines = [header]
-
-        # Data rows
-        for item in arr:
-            values = [self._encode_value(item[field]) for field in fields]
-            row = self.delimiter.join(values)
-            lines.append(f"{row_indent}{row}")
-
+        lines.extend(f"{row_indent}{row}" for row in self._encode_rows(arr, fields))
         return lines
 
     def encode_root_array_list(self, arr: list[Any], value_encoder: Any) -> list[str]:
@@ -300,29 +322,18 @@ class ArrayEncoder:
             >>> encoder.encode_root_array_list([1, 2])
             ['[2]:', '  - 1', '  - 2']
         """
-        item_indent = self.indent_mgr.indent(1)
-        length = len(arr)
-        header = f"[{length}]:"
+        header = f"[{len(arr)}]:"
 
         lines = [header]
-
-        for item in arr:
-            if isinstance(item, dict):
-                item_lines = value_encoder.encode_object(item, 1)
-                if item_lines:
-                    lines.append(f"{item_indent}- {item_lines[0]}")
-                    lines.extend(item_lines[1:])
-            else:
-                encoded = self._encode_value(item)
-                lines.append(f"{item_indent}- {encoded}")
-
+        lines.extend(self._encode_list_items(arr, 1, value_encoder))
         return lines
 
-    def _encode_value(self, val: Any) -> str:
+    def _encode_value(self, val: Any, standalone: bool = False) -> str:
         """Encode a single value.
 
         Args:
             val: Value to encode
+            standalone: The value is a "- " list item rather than a delimited cell
 
         Returns:
             Encoded string
@@ -334,9 +345,9 @@ class ArrayEncoder:
         if isinstance(val, (int, float)):
             return self.num_enc.encode(val)
         if isinstance(val, str):
-            return self.str_enc.encode(val)
+            return self.str_enc.encode(val, standalone)
         # Fallback: convert to string
-        return self.str_enc.encode(str(val))
+        return self.str_enc.encode(str(val), standalone)
 
     def _encode_inline_values(self, arr: list[Any]) -> str:
         """Encode array values as inline comma-separated string.
@@ -373,6 +384,7 @@ class ArrayEncoder:
             >>> #         - item2
         """
         item_indent = self.indent_mgr.indent(depth)
+
         nested_item_indent = self.indent_mgr.indent(depth + 1)
 
         header = f"{item_indent}- [{len(arr)}]:"
@@ -381,17 +393,82 @@ class ArrayEncoder:
         for item in arr:
             if isinstance(item, dict):
                 # Nested dict
-                item_lines = value_encoder.encode_object(item, depth + 1)
-                if item_lines:
-                    lines.append(f"{nested_item_indent}- {item_lines[0]}")
-                    lines.extend(item_lines[1:])
+                lines.extend(self._encode_object_item(item, depth + 1, value_encoder))
             elif isinstance(item, list):
                 # Double-nested array - recursion
                 nested_nested_lines = self._encode_nested_array_item(item, depth + 1, value_encoder)
                 lines.extend(nested_nested_lines)
             else:
                 # Primitive
-                encoded = self._encode_value(item)
+                encoded = self._encode_value(item, standalone=True)
                 lines.append(f"{nested_item_indent}- {encoded}")
 
         return lines
+
+    def _encode_object_item(
+        self, item: dict[str, Any], depth: int, value_encoder: Any
+    ) -> list[str]:
+        """Encode an object as a list item.
+
+        Per TOON spec, the first field goes on the hyphen line and the
+        remaining fields one level deeper than the hyphen.
+
+        Args:
+            item: Object to encode
+            depth: Indentation depth of the hyphen line
+            value_encoder: Encoder for complex values
+
+        Returns:
+            List of lines for the item
+
+        Examples:
+            >>> encoder._encode_object_item({"a": 1, "b": 2}, 1, ...)
+            ['  - a: 1', '    b: 2']
+        """
+        item_indent = self.indent_mgr.indent(depth)
+        if not item:
+            # Empty object item: bare hyphen
+            return [f"{item_indent}-"]
+
+        # Encode fields one level deeper, then move the first onto the hyphen line
+        item_lines = value_encoder.encode_object(item, depth + 1)
+        first = item_lines[0].removeprefix(self.indent_mgr.indent(depth + 1))
+        return [f"{item_indent}- {first}", *item_lines[1:]]
+
+    def _encode_list_items(self, arr: list[Any], depth: int, value_encoder: Any) -> list[str]:
+        """Encode the "- " items of a list array.
+
+        Args:
+            arr: Array items
+            depth: Indentation depth of the hyphen lines
+            value_encoder: Encoder for complex values
+
+        Returns:
+            List of item lines
+
+        Examples:
+            >>> encoder._encode_list_items([{"a": 1, "b": 2}, 3], 1, ...)
+            ['  - a: 1', '    b: 2', '  - 3']
+        """
+        item_indent = self.indent_mgr.indent(depth)
+        lines: list[str] = []
+
+        for item in arr:
+            if isinstance(item, dict):
+                lines.extend(self._encode_object_item(item, depth, value_encoder))
+            elif isinstance(item, list):
+                if not item:
+                    lines.append(f"{item_indent}- [0]:")
+                elif self.detect_array_form(item) == ArrayForm.INLINE:
+                    # Inline nested array: - [3]: 1,2,3
+                    delimiter_marker = "" if self.delimiter == "," else self.delimiter
+                    nested_inline = self._encode_inline_values(item)
+                    lines.append(f"{item_indent}- [{len(item)}{delimiter_marker}]: {nested_inline}")
+                else:
+                    # List or tabular form - needs full recursion
+                    lines.extend(self._encode_nested_array_item(item, depth, value_encoder))
+            else:
+                # Primitive item
+                lines.append(f"{item_indent}- {self._encode_value(item, standalone=True)}")
+
+        return lines
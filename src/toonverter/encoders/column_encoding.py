@@ -0,0 +1,100 @@
+"""Delta and run-length encoding of tabular columns.
+
+An opt-in extension of the tabular form: a header field annotated as
+``ts:+delta`` holds integer differences, and one annotated as ``status:*rle``
+leaves a cell empty when it repeats the previous row's value. See
+``ToonEncodeOptions.column_encoding``.
+"""
+
+import math
+from collections.abc import Mapping, Sequence
+from typing import Any
+
+from toonverter.core.exceptions import ValidationError
+from toonverter.core.spec import COLUMN_ENCODINGS
+
+
+# Stands in for an rle cell that repeats the previous row; written as an empty cell
+REPEATED = object()
+
+
+class ColumnEncoder:
+    """Rewrites the delta and rle cells of one tabular array, row by row."""
+
+    def __init__(
+        self,
+        fields: Sequence[str],
+        column_encoding: Mapping[str, str],
+        delimiter: str = ",",
+        align_columns: bool = False,
+    ) -> None:
+        """Initialize the per-table state.
+
+        Args:
+            fields: Field names in column order
+            column_encoding: Encoding ("delta" or "rle") per field name
+            delimiter: Cell delimiter of the rows
+            align_columns: Whether cells are padded to their column width
+        """
+        self.fields = list(fields)
+        self.kinds = {
+            index: column_encoding[name]
+            for index, name in enumerate(self.fields)
+            if name in column_encoding
+        }
+        # Columns whose empty cell would not survive decoding, so repeats are
+        # written out: an empty last cell leaves a trailing delimiter, which is
+        # dropped, tab rows lose a leading tab, and a padded empty first cell
+        # reads as indentation
+        self.written = {len(self.fields) - 1}
+        if delimiter == "\t" or align_columns:
+            self.written.add(0)
+        self.previous: dict[int, Any] = {}
+        self.row = 0
+
+    def header_field(self, index: int, key: str) -> str:
+        """Annotate an encoded header field, e.g. ``ts`` as ``ts:+delta``."""
+        kind = self.kinds.get(index)
+        return key if kind is None else f"{key}:{COLUMN_ENCODINGS[kind]}"
+
+    def encode_row(self, values: list[Any]) -> list[Any]:
+        """Replace the values of encoded columns with their encoded form.
+
+        Args:
+            values: Cell values of one row in column order (modified in place)
+
+        Returns:
+            The row, with deltas for delta columns and ``REPEATED`` for
+            repeated rle cells
+
+        Raises:
+            ValidationError: If a delta column holds a value that is not an
+                integer or None
+        """
+        self.row += 1
+        for index, kind in self.kinds.items():
+            value = values[index]
+            if kind == "delta":
+                if value is None:
+                    continue
+                if not isinstance(value, int) or isinstance(value, bool):
+                    msg = (
+                        f"Column {self.fields[index]!r} row {self.row} holds {value!r}; "
+                        "delta encoding needs integers or nulls"
+                    )
+                    raise ValidationError(msg)
+                values[index] = value - self.previous.get(index, 0)
+                self.previous[index] = value
+            else:
+                repeated = index in self.previous and _same(self.previous[index], value)
+                if repeated and index not in self.written:
+                    values[index] = REPEATED
+                self.previous[index] = value
+        return values
+
+
+def _same(a: Any, b: Any) -> bool:
+    """Check whether two cell values encode identically (1, 1.0 and True differ)."""
+    if type(a) is not type(b) or a != b:
+        return False
+    return not isinstance(a, float) or math.copysign(1, a) == math.copysign(1, b)
@@ -4,7 +4,8 @@ The TOON spec requires numbers in canonical decimal form:
 - No leading zeros (except "0" itself)
 - No trailing zeros after decimal point
 - No exponent notation in output
-- -0 becomes 0
+- -0 becomes 0 (or -0.0 when preserve_negative_zero is set)
+- Whole numbers have no fraction (unless int_as_float is set)
 - NaN and Infinity become null
 """
 
@@ -15,6 +16,25 @@ from decimal import Decimal, InvalidOperation
 class NumberEncoder:
     """Encoder for numbers in canonical TOON format."""
 
+    def __init__(
+        self,
+        float_precision: int | None = None,
+        preserve_negative_zero: bool = False,
+        int_as_float: bool = False,
+    ) -> None:
+        """Initialize number encoder.
+
+        Args:
+            float_precision: Round floats to this many significant digits
+                (None keeps the shortest exact representation)
+            preserve_negative_zero: Write -0.0 as "-0.0" instead of "0"
+            int_as_float: Write integers and whole floats with a ".0" fraction
+                so every number decodes as a float
+        """
+        self.float_precision = float_precision
+        self.preserve_negative_zero = preserve_negative_zero
+        self.int_as_float = int_as_float
+
     def encode(self, n: int | float) -> str:
         """Encode number to canonical form per TOON spec.
 
@@ -34,23 +54,38 @@ class NumberEncoder:
             '3'
             >>> encoder.encode(-0.0)
             '0'
+            >>> NumberEncoder(preserve_negative_zero=True).encode(-0.0)
+            '-0.0'
             >>> encoder.encode(float('nan'))
             'null'
+            >>> NumberEncoder(float_precision=2).encode(0.1 + 0.2)
+            '0.3'
+            >>> NumberEncoder(int_as_float=True).encode(42)
+            '42.0'
         """
         # Handle special float values -> null
         if isinstance(n, float) and (math.isnan(n) or math.isinf(n)):
             return "null"
 
-        # Handle negative zero -> 0
+        # Round to significant digits before canonicalizing
+        if isinstance(n, float) and self.float_precision is not None:
+            n = float(f"{n:.{self.float_precision}g}")
+
+        # Handle negative zero -> 0, unless the sign is preserved
         if n == 0:
-            # Check for negative zero
-            if isinstance(n, float) and math.copysign(1.0, n) == -1.0:
-                return "0"
-            return "0"
+            # "-0" would decode as the integer 0, so keep the fraction
+            if (
+                self.preserve_negative_zero
+                and isinstance(n, float)
+                and math.copysign(1.0, n) == -1.0
+            ):
+                return "-0.0"
+            return "0.0" if self.int_as_float else "0"
 
         # Integer (or float that's a whole number)
         if isinstance(n, int) or (isinstance(n, float) and n.is_integer()):
-            return str(int(n))
+            # Keep the integer digits exact rather than going through float()
+            return f"{int(n)}.0" if self.int_as_float else str(int(n))
 
         # Float with decimal part
         return self._format_decimal(n)
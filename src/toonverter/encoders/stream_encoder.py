@@ -4,14 +4,16 @@ This encoder provides memory-efficient streaming encoding for large datasets,
 avoiding recursion limits and memory overhead of building full strings.
 """
 
+import itertools
 from collections import deque
-from collections.abc import Iterator
+from collections.abc import Iterable, Iterator, Mapping, Sequence
 from dataclasses import dataclass
 from enum import Enum, auto
 from typing import Any
 
 from toonverter.core.exceptions import EncodingError
-from toonverter.core.spec import ToonEncodeOptions, ToonValue
+from toonverter.core.spec import LINE_ENDINGS, ToonEncodeOptions, ToonValue
+from toonverter.encoders.column_encoding import REPEATED, ColumnEncoder
 from toonverter.encoders.indentation import IndentationManager
 from toonverter.encoders.number_encoder import NumberEncoder
 from toonverter.encoders.string_encoder import StringEncoder
@@ -52,12 +54,20 @@ class ToonStreamEncoder:
     - O(1) Memory usage (generator-based)
     - Stack-based iteration (unlimited depth, no RecursionError)
     - Lazy array processing
+
+    Values are written as they are read, so the value options applied by the
+    document encoders' pre-pass, and a converter registered with
+    ``set_fallback_encoder``, are not consulted here.
     """
 
     def __init__(self, options: ToonEncodeOptions | None = None) -> None:
         self.options = options or ToonEncodeOptions()
-        self.str_enc = StringEncoder(self.options.delimiter)
-        self.num_enc = NumberEncoder()
+        self.str_enc = StringEncoder(self.options.delimiter, self.options.reserved_literals)
+        self.num_enc = NumberEncoder(
+            self.options.float_precision,
+            self.options.preserve_negative_zero,
+            self.options.int_as_float,
+        )
         self.indent_mgr = IndentationManager(self.options.indent_size)
 
     def iterencode(self, data: ToonValue | StreamList) -> Iterator[str]:
@@ -69,6 +79,10 @@ class ToonStreamEncoder:
         Yields:
             Chunks of the encoded string.
         """
+        return self._with_line_ending(self._iterencode(data))
+
+    def _iterencode(self, data: ToonValue | StreamList) -> Iterator[str]:
+        """Generate the chunks of :meth:`iterencode` with ``\\n`` separators."""
         try:
             # 1. Root Primitive (excluding StreamList)
             if not isinstance(data, StreamList) and self._is_primitive(data):
@@ -77,6 +91,11 @@ class ToonStreamEncoder:
 
             stack: deque[EncoderContext] = deque()
 
+            # We maintain a 'pending_newline' state to strictly emulate "\n".join()
+            # The first line yielded never has a prefix \n.
+            # All subsequent lines get a prefix \n.
+            first_yield = True
+
             # 2. Initialize Root Context
             if isinstance(data, dict):
                 if not data:
@@ -94,6 +113,7 @@ class ToonStreamEncoder:
 
                 # Default to LIST form for streaming root arrays
                 yield f"[{len(data)}]:"
+                first_yield = False
 
                 stack.append(
                     EncoderContext(
@@ -106,6 +126,7 @@ class ToonStreamEncoder:
                     return
 
                 yield f"[{data.length}]:"
+                first_yield = False
 
                 stack.append(
                     EncoderContext(
@@ -118,17 +139,13 @@ class ToonStreamEncoder:
                 raise EncodingError(msg)
 
             # 3. Process Stack
-            # We maintain a 'pending_newline' state to strictly emulate "\n".join()
-            # The first line yielded never has a prefix \n.
-            # All subsequent lines get a prefix \n.
-            first_yield = True
-
             while stack:
                 ctx = stack[-1]
 
                 try:
                     if ctx.type == ContextType.DICT:
                         key, value = next(ctx.iterator)
+                        key = self.str_enc.encode_key(str(key))
 
                         # Prepare prefix
                         prefix = "" if first_yield else "\n"
@@ -252,11 +269,108 @@ class ToonStreamEncoder:
             msg = f"Streaming encoding failed: {e}"
             raise EncodingError(msg) from e
 
+    def _with_line_ending(self, chunks: Iterator[str]) -> Iterator[str]:
+        """Rewrite the ``\\n`` separators in chunks to the configured line ending.
+
+        With ``trailing_newline`` the line ending is also yielded after the
+        last chunk, unless the output is empty, matching ``ToonEncoder``.
+        """
+        line_ending = LINE_ENDINGS[self.options.line_ending]
+        written = False
+        for chunk in chunks:
+            written = written or bool(chunk)
+            yield chunk if line_ending == "\n" else chunk.replace("\n", line_ending)
+        if self.options.trailing_newline and written:
+            yield line_ending
+
+    def iterencode_rows(
+        self,
+        rows: Iterable[Mapping[str, Any]],
+        length: int,
+        fields: Sequence[str] | None = None,
+        key: str | None = None,
+    ) -> Iterator[str]:
+        """Encode rows from any iterable as a tabular array, one line at a time.
+
+        Rows are pulled lazily, so a generator of millions of rows never has
+        to be materialized as a list. The header declares ``length`` up front,
+        and the iterable must yield exactly that many rows.
+
+        Args:
+            rows: Iterable of mappings (generators, cursors, ...)
+            length: Number of rows the iterable yields
+            fields: Column order (default: keys of the first row)
+            key: Root field holding the table (None for a root array)
+
+        Yields:
+            The header, then one chunk per row, joined the same way as
+            :meth:`iterencode`
+
+        Raises:
+            EncodingError: If a row lacks a field or the row count differs
+                from ``length``
+
+        Examples:
+            >>> rows = ({"id": i, "name": f"u{i}"} for i in range(2))
+            >>> "".join(ToonStreamEncoder().iterencode_rows(rows, 2, key="users"))
+            'users[2]{id,name}:\\n  0,u0\\n  1,u1'
+        """
+        return self._with_line_ending(self._iterencode_rows(rows, length, fields, key))
+
+    def _iterencode_rows(
+        self,
+        rows: Iterable[Mapping[str, Any]],
+        length: int,
+        fields: Sequence[str] | None,
+        key: str | None,
+    ) -> Iterator[str]:
+        """Generate the chunks of :meth:`iterencode_rows` with ``\\n`` separators."""
+        iterator = iter(rows)
+        first = next(iterator, None)
+        if fields is None:
+            fields = list(first.keys()) if first is not None else []
+        if first is not None:
+            iterator = itertools.chain([first], iterator)
+
+        delimiter = self.str_enc.delimiter
+        names = [str(f) for f in fields]
+        keys = [self.str_enc.encode_key(f, header=True) for f in names]
+        columns = None
+        column_encoding = self.options.column_encoding or {}
+        if any(f in column_encoding for f in names):
+            columns = ColumnEncoder(names, column_encoding, delimiter)
+            keys = [columns.header_field(i, k) for i, k in enumerate(keys)]
+        fields_str = delimiter.join(keys)
+        delimiter_marker = "" if delimiter == "," else delimiter
+        name = "" if key is None else self.str_enc.encode_key(key)
+        if fields:
+            yield f"{name}[{length}{delimiter_marker}]{{{fields_str}}}:"
+        else:
+            yield f"{name}[{length}{delimiter_marker}]:"
+
+        row_indent = self.indent_mgr.indent(1)
+        count = 0
+        for row in iterator:
+            count += 1
+            try:
+                values = [row[field] for field in fields]
+            except KeyError as e:
+                msg = f"Row {count} has no field {e}"
+                raise EncodingError(msg) from e
+            if columns is not None:
+                values = columns.encode_row(values)
+            cells = ["" if v is REPEATED else self._encode_value(v, False) for v in values]
+            yield f"\n{row_indent}{delimiter.join(cells)}"
+
+        if count != length:
+            msg = f"Declared {length} rows but the iterable yielded {count}"
+            raise EncodingError(msg)
+
     def _is_primitive(self, data: Any) -> bool:
         return not isinstance(data, (dict, list, StreamList))
 
-    def _encode_value(self, val: Any) -> str:
-        """Encode single primitive."""
+    def _encode_value(self, val: Any, standalone: bool = True) -> str:
+        """Encode single primitive; tabular cells pass standalone=False."""
         if val is None:
             return "null"
         if isinstance(val, bool):
@@ -264,6 +378,6 @@ class ToonStreamEncoder:
         if isinstance(val, (int, float)):
             return self.num_enc.encode(val)
         if isinstance(val, str):
-            return self.str_enc.encode(val)
+            return self.str_enc.encode(val, standalone)
         msg = f"Unsupported type: {type(val)}"
         raise EncodingError(msg)
@@ -4,8 +4,13 @@ Implements the string quoting and escaping rules from the official TOON specific
 Strings are only quoted when necessary to avoid ambiguity.
 """
 
+import re
+from collections.abc import Iterable
+
 from toonverter.core.spec import (
+    COMMENT_CHAR,
     ESCAPE_CHARS,
+    KEY_FOLD_SEPARATOR,
     NUMBER_PATTERN,
     QUOTE_REQUIRED_CHARS,
     RESERVED_WORDS,
@@ -13,34 +18,79 @@ from toonverter.core.spec import (
 )
 
 
+_HEX_BYTE_PATTERN = re.compile(r"[0-9a-fA-F]{2}")
+
+
+def decode_hex_escape(s: str, start: int) -> str:
+    """Decode the two hex digits of a ``\\xNN`` escape.
+
+    The decoder accepts ``\\xNN`` in quoted strings in addition to the five
+    spec escapes, for data produced by tools that write byte escapes. The
+    result is the character with that code point (U+0000 to U+00FF); the
+    encoder never writes these escapes.
+
+    Args:
+        s: Text containing the escape
+        start: Index of the first hex digit (just after ``\\x``)
+
+    Returns:
+        The escaped character
+
+    Raises:
+        ValueError: If ``start`` is not followed by exactly two hex digits
+
+    Examples:
+        >>> decode_hex_escape("\\\\x41", 2)
+        'A'
+        >>> decode_hex_escape("\\\\xe9", 2)
+        'é'
+    """
+    digits = _HEX_BYTE_PATTERN.match(s, start)
+    if digits is None:
+        found = s[start : start + 2]
+        msg = f"Invalid escape sequence: \\x{found} needs two hex digits"
+        raise ValueError(msg)
+    return chr(int(digits.group(), 16))
+
+
 class StringEncoder:
     """Encoder for strings following TOON specification quoting rules.
 
     The TOON spec requires minimal quoting - only when the string:
     - Is empty
     - Has leading/trailing whitespace
-    - Matches a reserved word (true, false, null)
+    - Matches a reserved word (true, false, null, plus any custom literals)
     - Looks like a number
     - Contains special characters
-    - Contains the active delimiter
+    - Contains the active delimiter (or a comma, outside delimited arrays)
     - Equals or starts with "-"
+    - Starts with "#" (would be read as a comment)
     """
 
     _TRANS_TABLE = str.maketrans(ESCAPE_CHARS)
 
-    def __init__(self, delimiter: Delimiter) -> None:
+    def __init__(self, delimiter: Delimiter, reserved_literals: Iterable[str] = ()) -> None:
         """Initialize string encoder.
 
         Args:
             delimiter: Active delimiter for arrays/fields
+            reserved_literals: Extra words to quote like the reserved words
+
+        Examples:
+            >>> StringEncoder(Delimiter.COMMA, ["yes", "no"]).encode("Yes")
+            '"Yes"'
         """
         self.delimiter = delimiter.value
+        self.reserved_words = RESERVED_WORDS | {word.lower() for word in reserved_literals}
 
-    def encode(self, s: str) -> str:
+    def encode(self, s: str, standalone: bool = False) -> str:
         """Encode string, adding quotes if necessary.
 
         Args:
             s: String to encode
+            standalone: The string is an object value, list item or root value
+                rather than a delimited array cell. Outside delimited arrays the
+                decoder always splits on commas, so commas are quoted too.
 
         Returns:
             Encoded string (quoted if necessary)
@@ -55,11 +105,52 @@ class StringEncoder:
             'hello world'
             >>> encoder.encode("hello: world")
             '"hello: world"'
+            >>> StringEncoder(Delimiter.PIPE).encode("a,b", standalone=True)
+            '"a,b"'
         """
-        if self._needs_quoting(s):
+        if self._needs_quoting(s) or (standalone and "," in s):
             return self._quote_and_escape(s)
         return s
 
+    def encode_key(self, key: str, quote_separator: bool = False, header: bool = False) -> str:
+        """Encode a key or tabular field name, quoting it if necessary.
+
+        Keys only need quotes when they are empty or contain structural
+        characters or the active delimiter. A key on an object line is also
+        quoted if it contains a comma, since the decoder splits on commas
+        outside delimited arrays. Reserved words and numeric-looking keys stay
+        bare there because the colon marks them as keys, but a tabular field
+        name has no colon after it and is quoted like a value.
+
+        Args:
+            key: Key to encode
+            quote_separator: Also quote keys containing the key folding
+                separator, so they are not mistaken for folded paths
+            header: The key is a field name in a tabular header
+
+        Returns:
+            Encoded key (quoted if necessary)
+
+        Examples:
+            >>> encoder = StringEncoder(Delimiter.COMMA)
+            >>> encoder.encode_key("id")
+            'id'
+            >>> encoder.encode_key("a,b")
+            '"a,b"'
+            >>> encoder.encode_key("null"), encoder.encode_key("null", header=True)
+            ('null', '"null"')
+        """
+        if (
+            not key
+            or key.startswith(COMMENT_CHAR)
+            or (quote_separator and KEY_FOLD_SEPARATOR in key)
+            or any(c in QUOTE_REQUIRED_CHARS or c == self.delimiter for c in key)
+            or (not header and "," in key)
+            or (header and (key in RESERVED_WORDS or NUMBER_PATTERN.match(key)))
+        ):
+            return self._quote_and_escape(key)
+        return key
+
     def _needs_quoting(self, s: str) -> bool:
         """Check if string requires quotes per TOON spec.
 
@@ -78,6 +169,11 @@ class StringEncoder:
         if s.startswith("-"):
             return True
 
+        # A leading "#" after a delimiter or space would start a comment;
+        # "a#b" is fine since "#" inside a value is literal
+        if s.startswith(COMMENT_CHAR):
+            return True
+
         # Leading or trailing whitespace
         if s[0].isspace() or s[-1].isspace():
             return True
@@ -88,7 +184,7 @@ class StringEncoder:
             return True
 
         # Reserved words (case-insensitive)
-        if s.lower() in RESERVED_WORDS:
+        if s.lower() in self.reserved_words:
             return True
 
         # Looks like a number
@@ -165,7 +261,7 @@ class StringEncoder:
 
                 next_char = s[i + 1]
 
-                # Only 5 valid escapes
+                # The 5 spec escapes plus \xNN byte escapes
                 if next_char == "\\":
                     result.append("\\")
                 elif next_char == '"':
@@ -176,10 +272,13 @@ class StringEncoder:
                     result.append("\r")
                 elif next_char == "t":
                     result.append("\t")
+                elif next_char == "x":
+                    result.append(decode_hex_escape(s, i + 2))
+                    i += 2
                 else:
                     msg = (
                         f"Invalid escape sequence: \\{next_char}. "
-                        f'Only \\\\, \\", \\n, \\r, \\t are allowed.'
+                        f'Only \\\\, \\", \\n, \\r, \\t and \\xNN are allowed.'
                     )
                     raise ValueError(msg)
 
@@ -4,17 +4,32 @@ This is the primary encoder that orchestrates all encoding logic
 according to the official TOON specification from github.com/toon-format/spec
 """
 
+from collections.abc import Callable
 from typing import Any
 
 from toonverter.core.exceptions import EncodingError, ValidationError
-from toonverter.core.spec import ArrayForm, Delimiter, RootForm, ToonEncodeOptions, ToonValue
+from toonverter.core.spec import (
+    LINE_ENDINGS,
+    ArrayForm,
+    Delimiter,
+    RootForm,
+    ToonEncodeOptions,
+    ToonValue,
+)
 from toonverter.core.types import EncodeOptions
+from toonverter.utils.fingerprint import fingerprint_line
+from toonverter.utils.pointer import format_pointer
 
 from .array_encoder import ArrayEncoder
 from .indentation import IndentationManager
 from .key_folding import KeyFolder
 from .number_encoder import NumberEncoder
 from .string_encoder import StringEncoder
+from .value_normalizer import ValueNormalizer
+
+
+# First line of minified output that needs indentation, see encode_minified()
+MINIFIED_DIRECTIVE = "#!toon indent=1"
 
 
 # Avoid circular import at top level if possible, or handle carefully
@@ -45,11 +60,27 @@ class ToonEncoder:
         self.options = options or ToonEncodeOptions()
 
         # Initialize sub-encoders
-        self.str_enc = StringEncoder(self.options.delimiter)
-        self.num_enc = NumberEncoder()
+        self.str_enc = StringEncoder(self.options.delimiter, self.options.reserved_literals)
+        self.num_enc = NumberEncoder(
+            self.options.float_precision,
+            self.options.preserve_negative_zero,
+            self.options.int_as_float,
+        )
         self.indent_mgr = IndentationManager(self.options.indent_size)
-        self.array_enc = ArrayEncoder(self.str_enc, self.num_enc, self.indent_mgr)
+        self.array_enc = ArrayEncoder(
+            self.str_enc,
+            self.num_enc,
+            self.indent_mgr,
+            self.options.align_columns,
+            self.options.column_encoding,
+        )
         self.key_folder = KeyFolder(enabled=self.options.key_folding == "safe")
+        self.normalizer = ValueNormalizer(self.options)
+
+    @property
+    def warnings(self) -> list[str]:
+        """Messages about values skipped by on_unsupported="skip" in the last encode."""
+        return self.normalizer.warnings
 
     def encode(self, data: ToonValue) -> str:
         """Encode Python data to TOON format.
@@ -62,7 +93,7 @@ class ToonEncoder:
 
         Raises:
             EncodingError: If encoding fails
-            ValidationError: If data contains unsupported types
+            ValidationError: If data contains unsupported types or rejected values
 
         Examples:
             >>> encoder = ToonEncoder()
@@ -70,6 +101,10 @@ class ToonEncoder:
             'name: Alice\\nage: 30'
         """
         try:
+            # Value-level options (e.g. nonfinite="error") run as a path-aware pre-pass
+            if self.normalizer.active:
+                data = self.normalizer.normalize(data)
+
             # OPTIMIZATION HOOK:
             # If a token budget is set, run the ContextOptimizer first
             if self.options.token_budget and ContextOptimizer is not None:
@@ -78,7 +113,29 @@ class ToonEncoder:
                 )
                 data = optimizer.optimize(data)
 
-            return self._encode_root(data)
+            text = self._encode_root(data)
+            root_comments = self._comment_lines("")
+            if root_comments:
+                # The blank line keeps the block from attaching to the first key
+                text = "\n".join([*root_comments, "", text]) if text else "\n".join(root_comments)
+            line_ending = LINE_ENDINGS[self.options.line_ending]
+            if line_ending != "\n":
+                # Values never contain a raw newline, so every one is a separator
+                text = text.replace("\n", line_ending)
+            if self.options.fingerprint:
+                trailer = fingerprint_line(
+                    text, self.options.delimiter.value, self.options.indent_size
+                )
+                text = f"{text}{line_ending}{trailer}" if text else trailer
+            if self.options.trailing_newline and text:
+                text += line_ending
+            return text
+        except ValidationError:
+            # Without the pre-pass, run it now so the error names the path
+            # of the unsupported value
+            if not self.normalizer.active:
+                self.normalizer.normalize(data)
+            raise
         except (TypeError, ValueError, RecursionError) as e:
             msg = f"Failed to encode data: {e}"
             raise EncodingError(msg) from e
@@ -107,8 +164,54 @@ class ToonEncoder:
         # Root-level object (default)
         assert isinstance(data, dict)
         lines = self.encode_object(data, depth=0)
+        if self.options.blank_lines:
+            lines = self._separate_entries(lines, lambda line: not line[0].isspace())
+        if self.options.comments:
+            lines = self._attach_key_comments(lines, list(data))
         return "\n".join(lines)
 
+    def _comment_lines(self, pointer: str) -> list[str]:
+        """Comment lines for a pointer in the comments option, as ``# text``."""
+        comments = self.options.comments or {}
+        return [f"# {line}" if line else "#" for line in comments.get(pointer, ())]
+
+    def _attach_key_comments(self, lines: list[str], keys: list[Any]) -> list[str]:
+        """Insert each top-level key's comment block above its first line.
+
+        Args:
+            lines: Encoded lines of the root object
+            keys: Root keys, in the order their entries appear in ``lines``
+
+        Returns:
+            Lines with comment blocks
+        """
+        result: list[str] = []
+        remaining = iter(keys)
+        for line in lines:
+            if line and not line[0].isspace():
+                result.extend(self._comment_lines(format_pointer([next(remaining)])))
+            result.append(line)
+        return result
+
+    def _separate_entries(
+        self, lines: list[str], starts_entry: Callable[[str], bool]
+    ) -> list[str]:
+        """Insert a blank line before every root-level entry except the first.
+
+        Args:
+            lines: Encoded lines
+            starts_entry: Predicate telling whether a line begins a new entry
+
+        Returns:
+            Lines with blank separators
+        """
+        result: list[str] = []
+        for line in lines:
+            if result and starts_entry(line):
+                result.append("")
+            result.append(line)
+        return result
+
     def _detect_root_form(self, data: ToonValue) -> RootForm:
         """Detect the form of root data.
 
@@ -146,6 +249,11 @@ class ToonEncoder:
             return "\n".join(lines)
         # ArrayForm.LIST
         lines = self.array_enc.encode_root_array_list(arr, self)
+        if self.options.blank_lines:
+            item_prefix = self.indent_mgr.indent(1) + "-"
+            header, *items = lines
+            items = self._separate_entries(items, lambda line: line.startswith(item_prefix))
+            lines = [header, *items]
         return "\n".join(lines)
 
     def encode_object(self, obj: dict[str, Any], depth: int) -> list[str]:
@@ -171,36 +279,36 @@ class ToonEncoder:
 
         # Process each key-value pair
         for key, value in obj.items():
-            # Check if this key can be folded
+            # Fold single-key chains into a dotted key, then encode the chain's
+            # final value like any other field value
+            can_fold = False
             if self.key_folder.should_fold_key(key, value, obj):
                 can_fold, key_chain = self.key_folder.can_fold_chain({key: value})
-                if can_fold:
-                    folded_key = self.key_folder.fold_key_chain(key_chain)
-                    final_value = self.key_folder.get_folded_value({key: value}, key_chain)
-                    # Encode as single line
-                    value_str = self._encode_value(final_value)
-                    lines.append(f"{indent}{folded_key}: {value_str}")
-                    continue
-
-            # Regular key-value encoding
+            if can_fold:
+                name = self.key_folder.fold_key_chain(key_chain)
+                value = self.key_folder.get_folded_value({key: value}, key_chain)
+            else:
+                # Literal dotted keys are quoted so path expansion leaves them alone
+                name = self.str_enc.encode_key(str(key), quote_separator=self.key_folder.enabled)
+
             if isinstance(value, dict):
                 # Nested object
-                lines.append(f"{indent}{key}:")
+                lines.append(f"{indent}{name}:")
                 nested_lines = self.encode_object(value, depth + 1)
                 lines.extend(nested_lines)
 
             elif isinstance(value, list):
                 # Array - detect form and encode
                 if not value:
-                    lines.append(f"{indent}{key}[0]:")
+                    lines.append(f"{indent}{name}[0]:")
                 else:
-                    array_lines = self._encode_array(key, value, depth)
+                    array_lines = self._encode_array(name, value, depth)
                     lines.extend(array_lines)
 
             else:
                 # Primitive value
                 value_str = self._encode_value(value)
-                lines.append(f"{indent}{key}: {value_str}")
+                lines.append(f"{indent}{name}: {value_str}")
 
         return lines
 
@@ -208,7 +316,7 @@ class ToonEncoder:
         """Encode array with key.
 
         Args:
-            key: Array key
+            key: Array key, already encoded
             arr: Array data
             depth: Current depth
 
@@ -241,7 +349,7 @@ class ToonEncoder:
         if isinstance(val, (int, float)):
             return self.num_enc.encode(val)
         if isinstance(val, str):
-            return self.str_enc.encode(val)
+            return self.str_enc.encode(val, standalone=True)
         msg = f"Unsupported type for TOON encoding: {type(val).__name__}"
         raise ValidationError(msg)
 
@@ -273,10 +381,29 @@ def _convert_options(options: EncodeOptions | ToonEncodeOptions | None) -> ToonE
     return ToonEncodeOptions(
         indent_size=indent_size,
         delimiter=delimiter,
-        key_folding="none",  # EncodeOptions doesn't have key_folding
+        key_folding="safe" if options.collapse_single_key_chains else "none",
         strict=True,
         token_budget=options.token_budget,
         optimization_policy=options.optimization_policy,
+        nonfinite=options.nonfinite,
+        integer_keyed_dict_as_list=options.integer_keyed_dict_as_list,
+        blank_lines=options.blank_lines,
+        float_precision=options.float_precision,
+        preserve_negative_zero=options.preserve_negative_zero,
+        int_as_float=options.int_as_float,
+        align_columns=options.align_columns,
+        redact_paths=tuple(options.redact_paths),
+        redact_with=options.redact_with,
+        empty_strings=options.empty_strings,
+        on_unsupported=options.on_unsupported,
+        reserved_literals=tuple(options.reserved_literals),
+        column_encoding=options.column_encoding,
+        normalize_numbers=options.normalize_numbers,
+        enum_mode=options.enum_mode,
+        comments=options.comments,
+        line_ending=options.line_ending,
+        fingerprint=options.fingerprint,
+        trailing_newline=options.trailing_newline,
     )
 
 
@@ -297,3 +424,61 @@ def encode(data: ToonValue, options: EncodeOptions | ToonEncodeOptions | None =
     toon_options = _convert_options(options)
     encoder = ToonEncoder(toon_options)
     return encoder.encode(data)
+
+
+def encode_pretty(data: ToonValue) -> str:
+    """Encode data for human readers.
+
+    Uses 2-space indentation and a blank line between root-level fields and
+    list items.
+
+    Args:
+        data: Data to encode
+
+    Returns:
+        TOON-formatted string
+
+    Examples:
+        >>> print(encode_pretty({"a": 1, "b": [1, 2]}))
+        a: 1
+        <BLANKLINE>
+        b[2]: 1,2
+    """
+    return encode(data, ToonEncodeOptions(indent_size=2, blank_lines=True))
+
+
+def encode_minified(data: ToonValue) -> str:
+    """Encode data with the fewest characters that still decode to the same data.
+
+    Flat data - primitive values, inline and tabular arrays - is written
+    without indentation. Indentation is what separates a nested block from
+    its parent's siblings, so any other data is indented by one space and
+    starts with a ``#!toon indent=1`` directive, which the decoder reads.
+
+    Args:
+        data: Data to encode
+
+    Returns:
+        TOON-formatted string
+
+    Examples:
+        >>> encode_minified({"a": 1, "b": [1, 2]})
+        'a: 1\\nb[2]: 1,2'
+        >>> encode_minified({"a": {"b": 1}, "c": 2})
+        '#!toon indent=1\\na:\\n b: 1\\nc: 2'
+    """
+    compact = ToonEncoder(_convert_options(EncodeOptions.create_compact()))
+    if _is_flat(data, compact.array_enc):
+        return compact.encode(data)
+    return f"{MINIFIED_DIRECTIVE}\n{encode(data, EncodeOptions(indent=1))}"
+
+
+def _is_flat(data: ToonValue, array_enc: ArrayEncoder) -> bool:
+    """Whether data has no block nested under a key or list item."""
+    values = data.values() if isinstance(data, dict) else [data]
+    for value in values:
+        if isinstance(value, dict) and value:
+            return False
+        if isinstance(value, list) and array_enc.detect_array_form(value) == ArrayForm.LIST:
+            return False
+    return True
@@ -0,0 +1,244 @@
+"""Pre-encoding value normalization with path tracking.
+
+The normalizer walks the input once before encoding and keeps track of the
+JSON Pointer path of every value it visits. Encoder options that need to
+inspect individual values (such as rejecting non-finite floats) hook in here
+so the errors they raise can name the offending location, e.g. /rows/17/score.
+The same paths drive value transforms and redaction.
+"""
+
+import math
+import numbers
+from collections.abc import Callable
+from decimal import Decimal
+from enum import Enum
+from typing import Any, ClassVar
+
+from toonverter.core.exceptions import ValidationError
+from toonverter.core.spec import ToonEncodeOptions
+from toonverter.utils.pointer import PathSegment, format_pointer, parse_pointer
+
+
+_WILDCARD = "*"
+_PRIMITIVES = (str, int, float, bool, type(None))
+# Marks a value dropped by on_unsupported="skip"
+_SKIP = object()
+# Returned by a fallback encoder to leave a value to the on_unsupported policy
+FALLBACK_DECLINED = object()
+
+
+class ValueNormalizer:
+    """Path-aware pre-pass applied to data before TOON encoding."""
+
+    # Process-wide converter for unsupported values, see set_fallback_encoder()
+    fallback_encoder: ClassVar[Callable[[Any], Any] | None] = None
+
+    def __init__(self, options: ToonEncodeOptions) -> None:
+        """Initialize normalizer.
+
+        Args:
+            options: Encoding options controlling the normalization
+
+        Raises:
+            ValueError: If a redact path is not a valid JSON Pointer
+        """
+        self.options = options
+        self._redact = [parse_pointer(p) for p in options.redact_paths]
+        # Set while normalizing a fallback result, which is never converted again
+        self._in_fallback = False
+        # Messages about values dropped by on_unsupported="skip" in the last run
+        self.warnings: list[str] = []
+
+    @property
+    def active(self) -> bool:
+        """Whether any option requires the pre-pass to run."""
+        return (
+            self.options.nonfinite == "error"
+            or self.options.integer_keyed_dict_as_list
+            or self.options.transform is not None
+            or bool(self._redact)
+            or self.options.empty_strings == "null"
+            or self.options.on_unsupported != "error"
+            or self.options.normalize_numbers
+            or self.options.enum_mode is not None
+            or ValueNormalizer.fallback_encoder is not None
+        )
+
+    def normalize(self, data: Any) -> Any:
+        """Walk the data and apply value-level options.
+
+        Args:
+            data: Data to normalize
+
+        Returns:
+            Normalized data
+
+        Raises:
+            ValidationError: If a value is rejected by the options
+
+        Examples:
+            >>> opts = ToonEncodeOptions(nonfinite="error")
+            >>> ValueNormalizer(opts).normalize({"a": [1.5, float("nan")]})
+            Traceback (most recent call last):
+            ...
+            toonverter.core.exceptions.ValidationError: Non-finite number nan at /a/1
+        """
+        self.warnings = []
+        result = self._normalize(data, [])
+        return None if result is _SKIP else result
+
+    def _normalize(self, value: Any, path: list[PathSegment]) -> Any:
+        """Normalize a single value at the given path."""
+        if self._redact and any(_matches(pattern, path) for pattern in self._redact):
+            return self.options.redact_with
+        if self.options.transform is not None:
+            replacement = self.options.transform(format_pointer(path), value)
+            if replacement is not value:
+                return replacement
+        value = self._enum_member(value)
+
+        if isinstance(value, dict):
+            result: dict[Any, Any] = {}
+            for raw_key, item in value.items():
+                # Keys follow enum_mode too, instead of becoming str(member)
+                key = self._enum_member(raw_key)
+                path.append(key)
+                normalized = self._normalize(item, path)
+                if normalized is not _SKIP:
+                    result[key] = normalized
+                path.pop()
+            if self.options.integer_keyed_dict_as_list and _is_index_keyed(result):
+                return [result[k] for k in sorted(result, key=int)]
+            return result
+
+        if isinstance(value, list):
+            items: list[Any] = []
+            for index, item in enumerate(value):
+                path.append(index)
+                normalized = self._normalize(item, path)
+                if normalized is not _SKIP:
+                    items.append(normalized)
+                path.pop()
+            return items
+
+        if self.options.normalize_numbers and not isinstance(value, _PRIMITIVES):
+            value = _canonical_number(value)
+
+        if not isinstance(value, _PRIMITIVES):
+            return self._unsupported(value, path)
+
+        if isinstance(value, float) and not math.isfinite(value):
+            if self.options.nonfinite == "error":
+                msg = f"Non-finite number {value!r} at {format_pointer(path) or '/'}"
+                raise ValidationError(msg)
+
+        if isinstance(value, str) and not value and self.options.empty_strings == "null":
+            return None
+
+        return value
+
+    def _enum_member(self, value: Any) -> Any:
+        """Replace an Enum member by its value or name, as enum_mode selects."""
+        if self.options.enum_mode is None or not isinstance(value, Enum):
+            return value
+        return value.value if self.options.enum_mode == "value" else value.name
+
+    def _unsupported(self, value: Any, path: list[PathSegment]) -> Any:
+        """Convert a value that has no TOON form, or apply the on_unsupported policy."""
+        pointer = format_pointer(path) or "/"
+        fallback = ValueNormalizer.fallback_encoder
+        if fallback is not None:
+            if self._in_fallback:
+                msg = (
+                    f"Fallback encoder returned unsupported type {type(value).__name__} "
+                    f"at {pointer}"
+                )
+                raise ValidationError(msg)
+            replacement = fallback(value)
+            if replacement is not FALLBACK_DECLINED:
+                self._in_fallback = True
+                try:
+                    return self._normalize(replacement, path)
+                finally:
+                    self._in_fallback = False
+
+        mode = self.options.on_unsupported
+        if mode == "null":
+            return None
+        if mode == "repr":
+            return repr(value)
+        if mode == "skip":
+            self.warnings.append(f"Skipped unsupported {type(value).__name__} at {pointer}")
+            return _SKIP
+        msg = f"Unsupported type for TOON encoding: {type(value).__name__} at {pointer}"
+        raise ValidationError(msg)
+
+
+def _canonical_number(value: Any) -> Any:
+    """Convert a real number of another type (Decimal, numpy scalar, ...) to int or float.
+
+    Integral values become ints so their digits stay exact; other values
+    return unchanged.
+
+    Examples:
+        >>> from decimal import Decimal
+        >>> _canonical_number(Decimal("1E+3")), _canonical_number(Decimal("2.50"))
+        (1000, 2.5)
+    """
+    # Decimal is a numbers.Number but not a numbers.Real
+    if not isinstance(value, (numbers.Real, Decimal)) or isinstance(value, bool):
+        return value
+    if isinstance(value, numbers.Integral):
+        return int(value)
+    try:
+        integral = value == int(value)
+    except (ValueError, OverflowError):
+        # NaN or infinity, handled like the float
+        integral = False
+    return int(value) if integral else float(value)
+
+
+def _matches(pattern: list[str], path: list[PathSegment]) -> bool:
+    """Check whether a path matches a pointer pattern with ``*`` wildcards."""
+    if len(pattern) != len(path):
+        return False
+    return all(
+        expected in (_WILDCARD, str(segment))
+        for expected, segment in zip(pattern, path, strict=True)
+    )
+
+
+def _is_index_keyed(value: dict[Any, Any]) -> bool:
+    """Check whether a non-empty dict's keys are exactly "0".."N-1" (or 0..N-1)."""
+    keys = [str(k) for k in value if not isinstance(k, bool)]
+    if not keys or len(keys) != len(value) or len(set(keys)) != len(keys):
+        return False
+    return set(keys) == {str(i) for i in range(len(keys))}
+
+
+def set_fallback_encoder(fallback: Callable[[Any], Any] | None) -> None:
+    """Register a process-wide converter for values that have no TOON form.
+
+    The converter is called with each unsupported value (a set, a date, an
+    instance of a custom class) before the ``on_unsupported`` policy applies,
+    and returns a JSON-like replacement - dict, list, str, number, bool or
+    None - that is encoded in its place. Returning ``FALLBACK_DECLINED``
+    leaves the value to ``on_unsupported``; exceptions propagate. Replacements
+    are never passed to the converter again, so an unsupported value inside
+    one is an error rather than a recursion.
+
+    Only the document encoders consult the converter. ``ToonStreamEncoder``
+    writes each value as it reads it, without this pre-pass, and raises
+    EncodingError for unsupported values.
+
+    Args:
+        fallback: Converter, or None to remove the registered one
+
+    Examples:
+        >>> import toonverter as toon
+        >>> set_fallback_encoder(lambda v: sorted(v) if isinstance(v, set) else str(v))
+        >>> toon.encode({"tags": {"b", "a"}})
+        'tags[2]: a,b'
+        >>> set_fallback_encoder(None)
+    """
+    ValueNormalizer.fallback_encoder = fallback
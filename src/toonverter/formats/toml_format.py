@@ -67,6 +67,48 @@ else:
     TOML_WRITE_AVAILABLE = False
 
 
+def apply_toml_null_policy(data: Any, policy: str = "error", path: str = "") -> Any:
+    """Remove None values, which TOML cannot represent, from a value tree.
+
+    Args:
+        data: Value to convert
+        policy: "omit" drops keys and array items holding None,
+            "empty_string" replaces None with "", "error" raises
+        path: JSON Pointer of ``data`` (used in error messages)
+
+    Returns:
+        Copy of ``data`` without None values
+
+    Raises:
+        EncodingError: If ``policy`` is "error" and a None value is found
+
+    Examples:
+        >>> apply_toml_null_policy({"a": None, "b": [1, None]}, "omit")
+        {'b': [1]}
+        >>> apply_toml_null_policy({"a": None}, "empty_string")
+        {'a': ''}
+    """
+    if isinstance(data, dict):
+        result = {}
+        for key, value in data.items():
+            if value is None and policy == "omit":
+                continue
+            result[key] = apply_toml_null_policy(value, policy, f"{path}/{key}")
+        return result
+    if isinstance(data, list):
+        return [
+            apply_toml_null_policy(item, policy, f"{path}/{i}")
+            for i, item in enumerate(data)
+            if item is not None or policy != "omit"
+        ]
+    if data is None:
+        if policy == "empty_string":
+            return ""
+        msg = f"TOML has no null value at {path or '/'}; set toml_null to omit or empty_string"
+        raise EncodingError(msg)
+    return data
+
+
 class TomlFormatAdapter(BaseFormatAdapter):
     """Adapter for TOML format.
 
@@ -91,6 +133,9 @@ class TomlFormatAdapter(BaseFormatAdapter):
             data: Data to encode (must be a dictionary)
             options: Encoding options
 
+        None values follow ``options.toml_null`` (see
+        :func:`apply_toml_null_policy`).
+
         Returns:
             TOML formatted string
 
@@ -100,6 +145,7 @@ class TomlFormatAdapter(BaseFormatAdapter):
         if not isinstance(data, dict):
             msg = "TOML format only supports dictionary data at the top level"
             raise EncodingError(msg)
+        data = apply_toml_null_policy(data, getattr(options, "toml_null", "error"))
 
         if not TOML_WRITE_AVAILABLE:
             msg = (
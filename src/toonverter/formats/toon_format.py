@@ -14,7 +14,7 @@ from toonverter.encoders.toon_encoder import _convert_options as _convert_encode
 from .base import BaseFormatAdapter
 
 
-def _convert_decode_options(
+def convert_decode_options(
     options: DecodeOptions | ToonDecodeOptions | None,
 ) -> ToonDecodeOptions | None:
     """Convert decode options to ToonDecodeOptions.
@@ -33,6 +33,26 @@ def _convert_decode_options(
     return ToonDecodeOptions(
         strict=options.strict,
         type_inference=options.type_inference,
+        intern_strings=options.intern_strings,
+        max_tabular_fields=options.max_tabular_fields,
+        max_indent_depth=options.max_indent_depth,
+        max_dict_entries=options.max_dict_entries,
+        max_list_elements=options.max_list_elements,
+        max_line_length=options.max_line_length,
+        missing_cells=options.missing_cells,
+        max_errors=options.max_errors,
+        expand_paths=options.expand_paths,
+        coerce_string_booleans=options.coerce_string_booleans,
+        string_booleans_case_sensitive=options.string_booleans_case_sensitive,
+        indent_size=options.indent_size,
+        nonfinite_tokens=options.nonfinite_tokens,
+        coerce_types=tuple(options.coerce_types),
+        row_errors=options.row_errors,
+        as_pairs=options.as_pairs,
+        duplicate_keys=options.duplicate_keys,
+        missing_values=options.missing_values,
+        empty_list_items=options.empty_list_items,
+        column_encoding=options.column_encoding,
     )
 
 
@@ -65,7 +85,7 @@ class ToonFormatAdapter(BaseFormatAdapter):
         Returns:
             Decoded Python data
         """
-        toon_options = _convert_decode_options(options)
+        toon_options = convert_decode_options(options)
         return toon_decode(data_str, toon_options)
 
     def validate(self, data_str: str) -> bool:
@@ -122,7 +142,7 @@ class ToonFormatAdapter(BaseFormatAdapter):
             Iterator[Any]: An iterator yielding decoded objects
         """
         options_obj = kwargs.get("options")
-        toon_options = _convert_decode_options(options_obj)
+        toon_options = convert_decode_options(options_obj)
 
         decoder = StreamDecoder(toon_options)
         return decoder.decode_stream(stream)
@@ -2,12 +2,28 @@
 
 # Pandas integration
 try:
-    from .pandas_integration import pandas_to_toon, pandas_to_toon_stream, toon_to_pandas
+    from .pandas_integration import (
+        ColumnTypeWarning,
+        pandas_to_toon,
+        pandas_to_toon_stream,
+        toon_to_pandas,
+    )
 
-    __all_pandas__ = ["pandas_to_toon", "pandas_to_toon_stream", "toon_to_pandas"]
+    __all_pandas__ = [
+        "ColumnTypeWarning",
+        "pandas_to_toon",
+        "pandas_to_toon_stream",
+        "toon_to_pandas",
+    ]
 except ImportError:
     __all_pandas__ = []
 
+# Arrow integration (duck-typed, no pyarrow import required)
+from .arrow_integration import arrow_to_columns, encode_from_arrow
+
+
+__all_arrow__ = ["arrow_to_columns", "encode_from_arrow"]
+
 # Pydantic integration
 try:
     from .pydantic_integration import pydantic_to_toon, toon_to_pydantic
@@ -181,8 +197,15 @@ try:
 except ImportError:
     __all_redis__ = []
 
+# MessagePack integration
+from .msgpack_integration import msgpack_to_toon, toon_to_msgpack
+
+
+__all_msgpack__ = ["msgpack_to_toon", "toon_to_msgpack"]
+
 __all__ = (
     __all_pandas__
+    + __all_arrow__
     + __all_pydantic__
     + __all_langchain__
     + __all_fastapi__
@@ -193,4 +216,5 @@ __all__ = (
     + __all_dspy__
     + __all_instructor__
     + __all_redis__
+    + __all_msgpack__
 )
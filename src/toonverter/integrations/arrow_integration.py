@@ -0,0 +1,115 @@
+"""Arrow-style columnar data integration.
+
+Works with any object exposing the PyArrow ``Table``/``RecordBatch`` interface
+(``column_names`` and ``columns``) without importing pyarrow itself.
+"""
+
+from collections.abc import Iterator
+from typing import Any
+
+from toonverter.core.exceptions import ConversionError, ValidationError
+from toonverter.core.types import EncodeOptions
+from toonverter.encoders import encode
+
+
+def is_arrow_like(obj: Any) -> bool:
+    """Check whether an object exposes the Arrow columnar interface.
+
+    Args:
+        obj: Object to inspect
+
+    Returns:
+        True if the object has ``column_names`` and ``columns`` attributes
+    """
+    return hasattr(obj, "column_names") and hasattr(obj, "columns")
+
+
+def arrow_to_columns(table: Any) -> dict[str, list[Any]]:
+    """Read an Arrow-like table into plain Python columns.
+
+    Each column is read chunk by chunk; nulls become None.
+
+    Args:
+        table: Arrow-like table or record batch
+
+    Returns:
+        Ordered mapping of column name to values
+
+    Raises:
+        TypeError: If the object does not expose the Arrow interface
+        ValidationError: If names and columns disagree or columns differ in length
+    """
+    if not is_arrow_like(table):
+        msg = (
+            "Expected an Arrow-like table with column_names and columns, "
+            f"got {type(table).__name__}"
+        )
+        raise TypeError(msg)
+
+    names = table.column_names
+    if callable(names):
+        names = names()
+    arrays = table.columns
+    if callable(arrays):
+        arrays = arrays()
+    names = [str(name) for name in names]
+    arrays = list(arrays)
+
+    if len(names) != len(arrays):
+        msg = f"Table has {len(names)} column names but {len(arrays)} columns"
+        raise ValidationError(msg)
+
+    columns = {name: list(_iter_column(array)) for name, array in zip(names, arrays, strict=True)}
+
+    lengths = {name: len(values) for name, values in columns.items()}
+    if len(set(lengths.values())) > 1:
+        msg = f"Columns have different lengths: {lengths}"
+        raise ValidationError(msg)
+    return columns
+
+
+def encode_from_arrow(table: Any, options: EncodeOptions | None = None) -> str:
+    """Encode an Arrow-like table as a TOON tabular array.
+
+    Args:
+        table: Arrow-like table or record batch (e.g. ``pyarrow.Table``)
+        options: Encoding options (defaults to tabular preset)
+
+    Returns:
+        TOON format string
+
+    Raises:
+        TypeError: If the object does not expose the Arrow interface
+        ValidationError: If the columns are inconsistent
+        ConversionError: If encoding fails
+
+    Examples:
+        >>> import pyarrow as pa
+        >>> table = pa.table({"id": [1, 2], "name": ["a", None]})
+        >>> print(encode_from_arrow(table))
+        [2]{id,name}:
+          1,a
+          2,null
+    """
+    columns = arrow_to_columns(table)
+    names = list(columns)
+    row_count = len(columns[names[0]]) if names else 0
+    rows = [{name: columns[name][i] for name in names} for i in range(row_count)]
+
+    try:
+        return encode(rows, options or EncodeOptions.tabular())
+    except Exception as e:
+        msg = f"Failed to encode Arrow table to TOON: {e}"
+        raise ConversionError(msg) from e
+
+
+def _iter_column(array: Any) -> Iterator[Any]:
+    """Yield Python values from a chunked or single-chunk Arrow column."""
+    chunks = getattr(array, "chunks", None)
+    for chunk in chunks if chunks is not None else [array]:
+        if hasattr(chunk, "to_pylist"):
+            yield from chunk.to_pylist()
+            continue
+        for item in chunk:
+            # Arrow scalars (including null scalars) convert via as_py()
+            yield item.as_py() if hasattr(item, "as_py") else item
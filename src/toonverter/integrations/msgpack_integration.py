@@ -0,0 +1,129 @@
+"""MessagePack integration.
+
+Bridges MessagePack payloads (e.g. from an event bus) and TOON without the
+caller unpacking them first. MessagePack types TOON cannot represent are
+mapped on the way in: binary blobs become base64 strings and non-string map
+keys are stringified.
+"""
+
+import base64
+from typing import Any
+
+from toonverter.core.exceptions import ConversionError
+from toonverter.core.types import DecodeOptions, EncodeOptions
+from toonverter.decoders import decode
+from toonverter.encoders import encode
+from toonverter.formats.toon_format import convert_decode_options
+
+
+# Optional dependency
+try:
+    import msgpack
+
+    MSGPACK_AVAILABLE = True
+except ImportError:
+    MSGPACK_AVAILABLE = False
+
+
+def _require_msgpack() -> None:
+    if not MSGPACK_AVAILABLE:
+        msg = "msgpack is required. Install with: pip install toonverter[msgpack]"
+        raise ImportError(msg)
+
+
+def msgpack_key(key: Any) -> str:
+    """Stringify a MessagePack map key the way JSON would.
+
+    Args:
+        key: Unpacked map key
+
+    Returns:
+        The key as a string: booleans and nil use their JSON spelling, binary
+        keys are base64-encoded and anything else goes through ``str``
+
+    Examples:
+        >>> [msgpack_key(k) for k in ("id", 7, True, None, b"\\x00")]
+        ['id', '7', 'true', 'null', 'AA==']
+    """
+    if isinstance(key, str):
+        return key
+    if isinstance(key, bool):
+        return "true" if key else "false"
+    if key is None:
+        return "null"
+    if isinstance(key, bytes):
+        return base64.b64encode(key).decode("ascii")
+    return str(key)
+
+
+def from_msgpack_value(value: Any) -> Any:
+    """Convert an unpacked MessagePack value into TOON-encodable data.
+
+    Args:
+        value: Value returned by ``msgpack.unpackb``
+
+    Returns:
+        The value with binary blobs as base64 strings and every map key a string
+
+    Examples:
+        >>> from_msgpack_value({1: b"hi", "list": [b"\\xff"]})
+        {'1': 'aGk=', 'list': ['/w==']}
+    """
+    if isinstance(value, dict):
+        return {msgpack_key(k): from_msgpack_value(v) for k, v in value.items()}
+    if isinstance(value, (list, tuple)):
+        return [from_msgpack_value(item) for item in value]
+    if isinstance(value, (bytes, bytearray)):
+        return base64.b64encode(value).decode("ascii")
+    return value
+
+
+def msgpack_to_toon(data: bytes, options: EncodeOptions | None = None) -> str:
+    """Convert a MessagePack payload to TOON format.
+
+    Args:
+        data: Packed MessagePack bytes
+        options: Encoding options
+
+    Returns:
+        TOON format string
+
+    Raises:
+        ImportError: If msgpack is not installed
+        ConversionError: If the payload cannot be unpacked or encoded
+    """
+    _require_msgpack()
+
+    try:
+        value = msgpack.unpackb(data, raw=False, strict_map_key=False)
+        return encode(from_msgpack_value(value), options)
+    except Exception as e:
+        msg = f"Failed to convert MessagePack to TOON: {e}"
+        raise ConversionError(msg) from e
+
+
+def toon_to_msgpack(toon_str: str, options: DecodeOptions | None = None) -> bytes:
+    """Convert TOON format to a MessagePack payload.
+
+    Base64 strings produced by :func:`msgpack_to_toon` stay strings; TOON
+    does not record that they were binary.
+
+    Args:
+        toon_str: TOON format string
+        options: Decoding options
+
+    Returns:
+        Packed MessagePack bytes
+
+    Raises:
+        ImportError: If msgpack is not installed
+        ConversionError: If the document cannot be decoded or packed
+    """
+    _require_msgpack()
+
+    try:
+        value = decode(toon_str, convert_decode_options(options))
+        return msgpack.packb(value, use_bin_type=True)
+    except Exception as e:
+        msg = f"Failed to convert TOON to MessagePack: {e}"
+        raise ConversionError(msg) from e
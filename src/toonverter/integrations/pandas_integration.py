@@ -1,9 +1,12 @@
 """Pandas DataFrame integration."""
 
-from collections.abc import Iterator
+import math
+import numbers
+from collections.abc import Iterator, Sequence
+from dataclasses import dataclass
 from typing import Any
 
-from toonverter.core.exceptions import ConversionError
+from toonverter.core.exceptions import ConversionError, FormatNotSupportedError, ValidationError
 from toonverter.core.types import EncodeOptions
 from toonverter.encoders import encode
 from toonverter.encoders.stream_encoder import StreamList, ToonStreamEncoder
@@ -19,8 +22,131 @@ except ImportError:
     PANDAS_AVAILABLE = False
 
 
+COLUMN_TYPES = frozenset({"int", "float", "str", "bool", "any"})
+
+
+@dataclass
+class ColumnTypeWarning:
+    """A cell that did not match its declared column type and was coerced.
+
+    Attributes:
+        column: Column name
+        row: Zero-based row position in the DataFrame
+        expected: Declared column type
+        value: Original cell value
+        coerced: Value written to the output (None if coercion failed)
+    """
+
+    column: str
+    row: int
+    expected: str
+    value: Any
+    coerced: Any
+
+    def __str__(self) -> str:
+        return (
+            f"Column {self.column!r} row {self.row}: expected {self.expected}, "
+            f"got {self.value!r}; coerced to {self.coerced!r}"
+        )
+
+
+def _matches_type(value: Any, expected: str) -> bool:
+    """Check whether a cell value matches a declared column type."""
+    if expected == "any" or value is None:
+        return True
+    if isinstance(value, float) and math.isnan(value):
+        # Missing values are represented as NaN by pandas
+        return True
+    is_bool = isinstance(value, bool) or type(value).__name__ == "bool_"
+    if expected == "bool":
+        return is_bool
+    if expected == "int":
+        return isinstance(value, numbers.Integral) and not is_bool
+    if expected == "float":
+        return isinstance(value, numbers.Real) and not is_bool
+    return isinstance(value, str)
+
+
+def _coerce_value(value: Any, expected: str) -> Any:
+    """Coerce a cell value to a declared column type, returning None on failure."""
+    try:
+        if expected == "int":
+            if isinstance(value, str):
+                return int(value.strip())
+            as_float = float(value)
+            return int(as_float) if as_float.is_integer() else None
+        if expected == "float":
+            return float(value)
+        if expected == "bool":
+            if isinstance(value, str):
+                lowered = value.strip().lower()
+                if lowered in ("true", "false"):
+                    return lowered == "true"
+                return None
+            return bool(value)
+        return str(value)
+    except (TypeError, ValueError, OverflowError):
+        return None
+
+
+def _apply_column_types(
+    records: list[dict[str, Any]], column_types: dict[str, str], coerce: bool
+) -> list[ColumnTypeWarning]:
+    """Validate (and optionally coerce) record cells against declared column types.
+
+    Args:
+        records: Rows as produced by DataFrame.to_dict(orient="records")
+        column_types: Mapping of column name to declared type
+        coerce: Coerce violating cells in place instead of raising
+
+    Returns:
+        Warnings for every coerced cell (empty when nothing was coerced)
+
+    Raises:
+        ValidationError: If a cell violates its column type and coerce is False
+    """
+    warnings: list[ColumnTypeWarning] = []
+    for row_index, record in enumerate(records):
+        for column, expected in column_types.items():
+            value = record.get(column)
+            if _matches_type(value, expected):
+                continue
+            if not coerce:
+                msg = (
+                    f"Column {column!r} row {row_index}: expected {expected}, "
+                    f"got {type(value).__name__} {value!r}"
+                )
+                raise ValidationError(msg)
+            coerced = _coerce_value(value, expected)
+            record[column] = coerced
+            warnings.append(ColumnTypeWarning(column, row_index, expected, value, coerced))
+    return warnings
+
+
+def _null_missing(df: "pd.DataFrame", na_values: Sequence[Any]) -> "pd.DataFrame":
+    """Replace every missing cell with None so it encodes as null.
+
+    Missing cells are those pandas reports as NA (None, NaN, ``pd.NA`` and
+    ``pd.NaT``) plus cells equal to one of ``na_values``.
+
+    Args:
+        df: DataFrame to clean
+        na_values: Additional values that mark a missing cell, e.g. "N/A"
+
+    Returns:
+        The DataFrame itself when nothing is missing, else a copy with object
+        columns holding None in the missing cells
+    """
+    mask = df.isna()
+    if na_values:
+        mask |= df.isin(list(na_values))
+    if not mask.to_numpy().any():
+        return df
+    return df.astype(object).mask(mask, None)
+
+
 def pandas_to_toon_stream(
-    df: "pd.DataFrame", options: EncodeOptions | None = None
+    df: "pd.DataFrame", options: EncodeOptions | None = None, na_values: Sequence[Any] = ()
 ) -> Iterator[str]:
     """Convert pandas DataFrame to TOON format stream.
 
@@ -29,6 +155,8 @@ def pandas_to_toon_stream(
     Args:
         df: DataFrame to convert
         options: Encoding options
+        na_values: Values to encode as null besides pandas' own missing
+            markers (NaN, ``pd.NA``, ``pd.NaT``), e.g. ``["N/A"]``
 
     Returns:
         Iterator yielding chunks of TOON string
@@ -42,6 +170,7 @@ def pandas_to_toon_stream(
         raise ImportError(msg)
 
     try:
+        df = _null_missing(df, na_values)
         count = len(df)
         columns = df.columns.tolist()
 
@@ -64,7 +193,14 @@ def pandas_to_toon_stream(
         raise ConversionError(msg) from e
 
 
-def pandas_to_toon(df: "pd.DataFrame", options: EncodeOptions | None = None, **kwargs: Any) -> str:
+def pandas_to_toon(
+    df: "pd.DataFrame",
+    options: EncodeOptions | None = None,
+    column_types: dict[str, str] | None = None,
+    coerce: bool = False,
+    na_values: Sequence[Any] = (),
+    **kwargs: Any,
+) -> str | tuple[str, list[ColumnTypeWarning]]:
     """Convert pandas DataFrame to TOON format.
 
     This uses the optimized tabular encoding for maximum efficiency.
@@ -72,27 +208,48 @@ def pandas_to_toon(df: "pd.DataFrame", options: EncodeOptions | None = None, **k
     Args:
         df: DataFrame to convert
         options: Encoding options (defaults to tabular preset)
+        column_types: Declared type per column ("int", "float", "str", "bool",
+            or "any"), checked cell by cell before encoding
+        coerce: Coerce cells that violate column_types instead of raising.
+            When set together with column_types, a (toon_string, warnings)
+            tuple is returned.
+        na_values: Values to encode as null besides pandas' own missing
+            markers (NaN, ``pd.NA``, ``pd.NaT``), e.g. ``["N/A", ""]``;
+            cells are nulled before column_types is checked
         **kwargs: Additional pandas export options:
             - orient: format for to_dict (default: "records")
             - include_index: whether to include index (default: False)
             - compress: enable compression (ignored, for API compatibility)
 
     Returns:
-        TOON format string
+        TOON format string, or (TOON string, coercion warnings) when both
+        column_types and coerce are given
 
     Raises:
         ImportError: If pandas is not installed
+        ValidationError: If a cell violates column_types and coerce is False
         ConversionError: If conversion fails
 
     Examples:
         >>> import pandas as pd
         >>> df = pd.DataFrame({'name': ['Alice', 'Bob'], 'age': [30, 25]})
         >>> toon_str = pandas_to_toon(df)
+        >>> toon_str, warnings = pandas_to_toon(df, column_types={'age': 'int'}, coerce=True)
+        >>> toon_str = pandas_to_toon(df, na_values=["N/A"])
     """
     if not PANDAS_AVAILABLE:
         msg = "pandas is required. Install with: pip install toon-converter[integrations]"
         raise ImportError(msg)
 
+    if column_types is not None:
+        unknown = {t for t in column_types.values() if t not in COLUMN_TYPES}
+        if unknown:
+            msg = f"Unknown column types {sorted(unknown)}; expected one of {sorted(COLUMN_TYPES)}"
+            raise ValueError(msg)
+        if kwargs.get("orient", "records") != "records":
+            msg = "column_types requires orient='records'"
+            raise ValueError(msg)
+
     try:
         # Handle Series
         if isinstance(df, pd.Series):
@@ -102,23 +259,49 @@ def pandas_to_toon(df: "pd.DataFrame", options: EncodeOptions | None = None, **k
         if kwargs.get("include_index"):
             df = df.reset_index()
 
+        # pd.NA and pd.NaT have no TOON form; write them, and na_values, as null
+        df = _null_missing(df, na_values)
+
         # Convert DataFrame to list of dicts (optimal for tabular TOON encoding)
         orient = kwargs.get("orient", "records")
         data = df.to_dict(orient=orient)
 
+        warnings: list[ColumnTypeWarning] = []
+        if column_types is not None:
+            missing = [c for c in column_types if c not in df.columns]
+            if missing:
+                msg = f"column_types refers to unknown columns: {missing}"
+                raise ValidationError(msg)
+            warnings = _apply_column_types(data, column_types, coerce)
+
         options = options or EncodeOptions.tabular()
-        return encode(data, options)
+        toon_str = encode(data, options)
+        if column_types is not None and coerce:
+            return toon_str, warnings
+        return toon_str
+    except ValidationError:
+        raise
     except Exception as e:
         msg = f"Failed to convert DataFrame to TOON: {e}"
         raise ConversionError(msg) from e
 
 
-def toon_to_pandas(toon_str: str, as_series: bool = False) -> "pd.DataFrame | pd.Series":
+def toon_to_pandas(
+    toon_str: str,
+    as_series: bool = False,
+    column_encoding: bool = False,
+    column_types: dict[str, str] | None = None,
+) -> "pd.DataFrame | pd.Series":
     """Convert TOON format to pandas DataFrame.
 
     Args:
         toon_str: TOON format string (tabular format)
         as_series: Return as Series if possible (default: False)
+        column_encoding: Restore delta and rle encoded columns, as written
+            with ``EncodeOptions(column_encoding=...)`` (default: False)
+        column_types: Declared type per column ("int", "float", "str", "bool"
+            or "any"); the table is decoded straight into these columns,
+            in this order, and a cell of another type raises
 
     Returns:
         pandas DataFrame or Series
@@ -136,9 +319,24 @@ def toon_to_pandas(toon_str: str, as_series: bool = False) -> "pd.DataFrame | pd
         raise ImportError(msg)
 
     try:
-        from toonverter.decoders import decode
-
-        data = decode(toon_str)
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import decode, decode_columns, decode_typed_columns
+
+        decode_options = ToonDecodeOptions(column_encoding=column_encoding)
+        if column_types is not None:
+            columns = decode_typed_columns(toon_str, column_types, options=decode_options)
+            df = pd.DataFrame(columns)
+            return df.squeeze() if as_series else df
+
+        # Tabular documents go straight to the column-oriented constructor
+        try:
+            df = pd.DataFrame(decode_columns(toon_str, options=decode_options))
+        except FormatNotSupportedError:
+            pass
+        else:
+            return df.squeeze() if as_series else df
+
+        data = decode(toon_str, decode_options)
 
         # Handle empty list or valid list of dicts
         if isinstance(data, list):
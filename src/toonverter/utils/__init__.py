@@ -1,13 +1,22 @@
 """Utilities module."""
 
-from .io import read_file, write_file
+from .fingerprint import verify_toon_fingerprint
+from .io import read_file, read_stream, write_file, write_stream
+from .pointer import format_pointer, parse_pointer, project, resolve_pointer
 from .validation import validate_data_not_empty, validate_file_exists, validate_format_name
 
 
 __all__ = [
+    "format_pointer",
+    "parse_pointer",
+    "project",
     "read_file",
+    "read_stream",
+    "resolve_pointer",
     "validate_data_not_empty",
     "validate_file_exists",
     "validate_format_name",
+    "verify_toon_fingerprint",
     "write_file",
+    "write_stream",
 ]
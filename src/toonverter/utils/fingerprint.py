@@ -0,0 +1,80 @@
+"""Fingerprint trailers that record how a TOON document was encoded."""
+
+import hashlib
+from typing import Any
+
+from toonverter.__version__ import __version__
+from toonverter.core.spec import FINGERPRINT_PREFIX
+
+
+# Delimiter spellings in the trailer; a tab would be invisible in a comment
+_DELIMITER_NAMES = {",": ",", "|": "|", "\t": "tab"}
+
+
+def fingerprint_line(body: str, delimiter: str, indent: int) -> str:
+    """Build the trailer comment for an encoded document.
+
+    Args:
+        body: Encoded document the trailer follows, without its final line break
+        delimiter: Delimiter the document was encoded with
+        indent: Spaces per indent level the document was encoded with
+
+    Returns:
+        Trailer line, e.g. ``# toonverter: v=1.1.2 delimiter=, indent=2 sha256=...``
+    """
+    digest = hashlib.sha256(body.encode("utf-8")).hexdigest()
+    return (
+        f"{FINGERPRINT_PREFIX} v={__version__} delimiter={_DELIMITER_NAMES[delimiter]} "
+        f"indent={indent} sha256={digest}"
+    )
+
+
+def verify_toon_fingerprint(text: str) -> dict[str, Any]:
+    """Check the fingerprint trailer written by ``ToonEncodeOptions(fingerprint=True)``.
+
+    The hash is recomputed over everything above the trailer line, so any
+    edit to the document after encoding makes it invalid. A trailing line
+    break after the trailer, as added by many editors, is ignored.
+
+    Args:
+        text: TOON document
+
+    Returns:
+        Dictionary with ``fingerprinted`` (a trailer was found), ``valid``
+        (the recorded hash matches) and the recorded ``version``,
+        ``delimiter``, ``indent`` and ``sha256`` (None when absent)
+
+    Examples:
+        >>> from toonverter import encode
+        >>> text = encode({"a": 1}, fingerprint=True)
+        >>> report = verify_toon_fingerprint(text)
+        >>> report["valid"], report["delimiter"], report["indent"]
+        (True, ',', 2)
+        >>> verify_toon_fingerprint(text.replace("1", "2"))["valid"]
+        False
+    """
+    report: dict[str, Any] = {
+        "fingerprinted": False,
+        "valid": False,
+        "version": None,
+        "delimiter": None,
+        "indent": None,
+        "sha256": None,
+    }
+    body, _, last = text.rstrip("\r\n").rpartition("\n")
+    if not last.startswith(FINGERPRINT_PREFIX):
+        return report
+
+    recorded = dict(item.partition("=")[::2] for item in last[len(FINGERPRINT_PREFIX) :].split())
+    delimiters = {name: delimiter for delimiter, name in _DELIMITER_NAMES.items()}
+    indent = recorded.get("indent", "")
+    digest = hashlib.sha256(body.removesuffix("\r").encode("utf-8")).hexdigest()
+    report.update(
+        fingerprinted=True,
+        valid=recorded.get("sha256") == digest,
+        version=recorded.get("v"),
+        delimiter=delimiters.get(recorded.get("delimiter", "")),
+        indent=int(indent) if indent.isdigit() else None,
+        sha256=recorded.get("sha256"),
+    )
+    return report
@@ -1,13 +1,95 @@
 """File I/O utilities."""
 
+import codecs
+import io
+import os
+import uuid
 from pathlib import Path
+from typing import Any
 
 from toonverter.core.exceptions import FileOperationError
 
 
+UTF16_BOMS = (codecs.BOM_UTF16_LE, codecs.BOM_UTF16_BE)
+
+
+def reject_utf16(data: bytes, source: str) -> None:
+    """Fail early on bytes that start with a UTF-16 byte order mark.
+
+    Such input would otherwise surface as an opaque UTF-8 decoding error.
+
+    Args:
+        data: Leading bytes of the input
+        source: What the bytes are, for the message (e.g. "file")
+
+    Raises:
+        UnicodeError: If ``data`` starts with a UTF-16 LE or BE byte order mark
+    """
+    if data.startswith(UTF16_BOMS):
+        msg = f"{source} appears to be UTF-16; re-encode as UTF-8"
+        raise UnicodeError(msg)
+
+
+class Utf8ChunkDecoder:
+    """Turn text or UTF-8 byte chunks into text, however the chunks split.
+
+    A multibyte character split across chunks is held back until the rest
+    arrives, and a leading UTF-8 byte order mark is dropped.
+
+    Examples:
+        >>> utf8 = Utf8ChunkDecoder("stream")
+        >>> utf8.decode(b"caf\\xc3"), utf8.decode(b"\\xa9"), utf8.finish()
+        ('caf', 'é', '')
+    """
+
+    def __init__(self, source: str) -> None:
+        """Initialize decoder.
+
+        Args:
+            source: What the bytes are, for error messages (e.g. "stream")
+        """
+        self.source = source
+        self._utf8 = codecs.getincrementaldecoder("utf-8-sig")()
+        self._started = False
+        # Leading bytes held back until the UTF-16 check can see a whole BOM
+        self._head = b""
+
+    def decode(self, chunk: str | bytes) -> str:
+        """Decode the next chunk; text chunks pass through unchanged.
+
+        Raises:
+            UnicodeError: If the input starts with a UTF-16 byte order mark
+                or the bytes are not valid UTF-8
+        """
+        if isinstance(chunk, str):
+            pending, self._head = self._head, b""
+            self._started = True
+            return self._utf8.decode(pending) + chunk
+        if not self._started:
+            self._head += chunk
+            if len(self._head) < len(codecs.BOM_UTF16_LE):
+                return ""
+            reject_utf16(self._head, self.source)
+            chunk, self._head = self._head, b""
+            self._started = True
+        return self._utf8.decode(chunk)
+
+    def finish(self) -> str:
+        """Flush the decoder at the end of the input.
+
+        Raises:
+            UnicodeDecodeError: If the input ends inside a UTF-8 sequence
+        """
+        pending, self._head = self._head, b""
+        return self._utf8.decode(pending, final=True)
+
+
 def read_file(file_path: str) -> str:
     """Read file content.
 
+    A leading UTF-8 byte order mark, as written by some Windows tools, is
+    dropped.
+
     Args:
         file_path: Path to file
 
@@ -15,30 +97,112 @@ def read_file(file_path: str) -> str:
         File content as string
 
     Raises:
-        FileOperationError: If reading fails
+        FileOperationError: If reading fails or the file is not UTF-8
     """
     try:
-        path = Path(file_path)
-        return path.read_text(encoding="utf-8")
+        data = Path(file_path).read_bytes()
+        reject_utf16(data, "file")
+        return data.decode("utf-8-sig")
     except Exception as e:
         msg = f"Failed to read file {file_path}: {e}"
         raise FileOperationError(msg) from e
 
 
-def write_file(file_path: str, content: str) -> None:
+def write_file(
+    file_path: str, content: str, max_bytes: int | None = None, append: bool = False
+) -> None:
     """Write content to file.
 
+    The content is written to a temporary file next to the target and moved
+    into place, so a failed write never leaves a partial file behind. With
+    ``append`` an existing non-empty file is extended in place instead,
+    starting on a new line; that write is not atomic.
+
     Args:
         file_path: Path to file
         content: Content to write
+        max_bytes: Optional cap on the UTF-8 encoded size of the content
+        append: Add the content to the end of an existing file
 
     Raises:
-        FileOperationError: If writing fails
+        FileOperationError: If writing fails or the content exceeds max_bytes
     """
+    data = content.encode("utf-8")
+    if max_bytes is not None and len(data) > max_bytes:
+        msg = f"Output for {file_path} is {len(data)} bytes, exceeding limit of {max_bytes}"
+        raise FileOperationError(msg)
+
+    tmp_name = None
     try:
-        path = Path(file_path)
+        # Resolve symlinks so the swap below replaces the real file, not the link
+        path = Path(file_path).resolve()
+        if append and path.is_file() and path.stat().st_size > 0:
+            with path.open("rb+") as f:
+                f.seek(-1, os.SEEK_END)
+                separator = b"" if f.read(1) == b"\n" else b"\n"
+                f.write(separator + data)
+            return
         path.parent.mkdir(parents=True, exist_ok=True)
-        path.write_text(content, encoding="utf-8")
+        candidate = path.with_name(f".{path.name}.{uuid.uuid4().hex}.tmp")
+        # 0o666 lets the kernel apply the current umask, as open() would
+        flags = os.O_CREAT | os.O_EXCL | os.O_WRONLY | getattr(os, "O_BINARY", 0)
+        fd = os.open(candidate, flags, 0o666)
+        tmp_name = candidate
+        with os.fdopen(fd, "wb") as f:
+            f.write(data)
+        if path.exists():
+            os.chmod(tmp_name, path.stat().st_mode & 0o777)
+        os.replace(tmp_name, path)
     except Exception as e:
+        if tmp_name is not None and os.path.exists(tmp_name):
+            os.unlink(tmp_name)
         msg = f"Failed to write file {file_path}: {e}"
         raise FileOperationError(msg) from e
+
+
+def read_stream(reader: Any, chunk_size: int = 65536) -> str:
+    """Read all content from a file-like object.
+
+    Text and binary readers are both accepted; bytes must be valid UTF-8,
+    and a leading UTF-8 byte order mark is dropped.
+
+    Args:
+        reader: Object with a ``read(size)`` method (file, socket file, buffer)
+        chunk_size: Number of characters/bytes per read call
+
+    Returns:
+        Content as string
+
+    Raises:
+        FileOperationError: If reading fails or the bytes are not valid UTF-8
+    """
+    utf8 = Utf8ChunkDecoder("stream")
+    parts: list[str] = []
+    try:
+        while chunk := reader.read(chunk_size):
+            parts.append(utf8.decode(chunk))
+        parts.append(utf8.finish())
+    except Exception as e:
+        msg = f"Failed to read stream: {e}"
+        raise FileOperationError(msg) from e
+    return "".join(parts)
+
+
+def write_stream(writer: Any, content: str) -> None:
+    """Write content to a file-like object.
+
+    Binary writers receive the content encoded as UTF-8.
+
+    Args:
+        writer: Object with a ``write`` method
+        content: Content to write
+
+    Raises:
+        FileOperationError: If writing fails
+    """
+    binary = isinstance(writer, (io.RawIOBase, io.BufferedIOBase))
+    try:
+        writer.write(content.encode("utf-8") if binary else content)
+    except Exception as e:
+        msg = f"Failed to write stream: {e}"
+        raise FileOperationError(msg) from e
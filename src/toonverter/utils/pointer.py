@@ -0,0 +1,164 @@
+"""JSON Pointer (RFC 6901) helpers for addressing values inside TOON data."""
+
+from collections.abc import Iterable, Sequence
+from typing import Any, Literal
+
+
+PathSegment = str | int
+
+
+def format_pointer(segments: Sequence[PathSegment]) -> str:
+    """Format path segments as a JSON Pointer string.
+
+    Args:
+        segments: Dict keys and list indices from the root to a value
+
+    Returns:
+        JSON Pointer string ("" addresses the root)
+
+    Examples:
+        >>> format_pointer(["rows", 17, "score"])
+        '/rows/17/score'
+        >>> format_pointer(["a/b"])
+        '/a~1b'
+    """
+    return "".join("/" + str(seg).replace("~", "~0").replace("/", "~1") for seg in segments)
+
+
+def parse_pointer(pointer: str) -> list[str]:
+    """Split a JSON Pointer string into unescaped segments.
+
+    Args:
+        pointer: JSON Pointer string
+
+    Returns:
+        List of path segments (list indices are returned as strings)
+
+    Raises:
+        ValueError: If the pointer is non-empty and does not start with "/"
+
+    Examples:
+        >>> parse_pointer("/rows/17/score")
+        ['rows', '17', 'score']
+        >>> parse_pointer("")
+        []
+    """
+    if not pointer:
+        return []
+    if not pointer.startswith("/"):
+        msg = f"Invalid JSON Pointer {pointer!r}: must be empty or start with '/'"
+        raise ValueError(msg)
+    return [seg.replace("~1", "/").replace("~0", "~") for seg in pointer[1:].split("/")]
+
+
+def resolve_pointer(data: Any, pointer: str) -> Any:
+    """Return the value a JSON Pointer addresses.
+
+    Args:
+        data: Decoded document
+        pointer: JSON Pointer string
+
+    Returns:
+        Value at the pointer
+
+    Raises:
+        LookupError: If no value exists at the pointer
+
+    Examples:
+        >>> resolve_pointer({"rows": [{"id": 1}, {"id": 2}]}, "/rows/1/id")
+        2
+    """
+    value = data
+    for depth, segment in enumerate(parse_pointer(pointer), start=1):
+        value = _child(value, segment, pointer, depth)
+    return value
+
+
+def project(
+    data: Any,
+    pointers: Iterable[str],
+    missing: Literal["skip", "error"] = "skip",
+) -> Any:
+    """Build a copy of a document containing only the addressed values.
+
+    Dicts and lists along each pointer are rebuilt so the projection keeps the
+    original nesting and key order; list items keep their relative order, so
+    selecting ``/rows/2`` alone yields a one-item ``rows`` list. Selected values
+    themselves are shared with the input, not copied.
+
+    Args:
+        data: Decoded document
+        pointers: JSON Pointers of the values to keep ("" keeps everything)
+        missing: "skip" ignores pointers with no value, "error" raises
+
+    Returns:
+        Projected document
+
+    Raises:
+        ValueError: If missing is not "skip" or "error" or a pointer is malformed
+        LookupError: If missing="error" and a pointer has no value
+
+    Examples:
+        >>> doc = {"user": {"name": "Ada", "email": "a@x"}, "rows": [1, 2, 3]}
+        >>> project(doc, ["/user/name", "/rows/2"])
+        {'user': {'name': 'Ada'}, 'rows': [3]}
+    """
+    if missing not in ("skip", "error"):
+        msg = "missing must be 'skip' or 'error'"
+        raise ValueError(msg)
+
+    # Selection tree: segment -> child selection, or None to keep the whole value
+    selection: dict[str, Any] = {}
+    for pointer in pointers:
+        try:
+            resolve_pointer(data, pointer)
+        except LookupError:
+            if missing == "error":
+                raise
+            continue
+
+        segments = parse_pointer(pointer)
+        if not segments:
+            return data
+        node = selection
+        for segment in segments[:-1]:
+            child = node.setdefault(segment, {})
+            if child is None:
+                # An ancestor is already kept whole
+                break
+            node = child
+        else:
+            node[segments[-1]] = None
+
+    return _apply_selection(data, selection)
+
+
+def _child(value: Any, segment: str, pointer: str, depth: int) -> Any:
+    """Step one pointer segment into a dict or list."""
+    if isinstance(value, dict) and segment in value:
+        return value[segment]
+    if (
+        isinstance(value, list)
+        and segment.isdigit()
+        and (segment == "0" or not segment.startswith("0"))
+        and int(segment) < len(value)
+    ):
+        return value[int(segment)]
+    prefix = "/".join(pointer.split("/")[: depth + 1])
+    msg = f"No value at {pointer!r} (missing {prefix!r})"
+    raise LookupError(msg)
+
+
+def _apply_selection(value: Any, selection: dict[str, Any] | None) -> Any:
+    """Rebuild the selected parts of a value."""
+    if selection is None:
+        return value
+    if isinstance(value, dict):
+        return {k: _apply_selection(v, selection[k]) for k, v in value.items() if k in selection}
+    if isinstance(value, list):
+        return [
+            _apply_selection(item, selection[str(i)])
+            for i, item in enumerate(value)
+            if str(i) in selection
+        ]
+    return None
@@ -0,0 +1 @@
+"""Tests over a hand-written corpus of TOON/JSON fixture pairs."""
@@ -0,0 +1,110 @@
+"""Decoding and round-trip tests over a hand-written TOON/JSON fixture corpus.
+
+Each fixture is a ``<name>.toon`` document with the JSON it should decode to
+in ``<name>.json``. The expected JSON was written by hand from the spec; it
+has not been generated by the reference implementation, so this suite checks
+our reading of the spec rather than conformance. For every pair the decoder
+must produce the expected JSON, and the encoder's output for that JSON must
+decode back to it.
+
+The suite is opt-in: set ``TOONVERTER_FIXTURE_CORPUS=1`` to run it. Compare
+the expectations with the reference implementation using
+``python tests/fixture_corpus/update_fixtures.py --check``.
+"""
+
+import difflib
+import json
+import os
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+from toonverter.decoders import decode
+from toonverter.encoders import encode
+
+
+FIXTURES_DIR = Path(__file__).parent / "fixtures"
+
+# Fixtures where the decoder is known to differ from the expected JSON
+KNOWN_DIVERGENCES = {
+    "numbers-leading-zero": "007 decodes as the number 7 instead of a string",
+    "primitives-unquoted-spaces": "unquoted values with inner spaces are split",
+    "tabular-tab": "tab-delimited cells with inner spaces are split",
+}
+
+pytestmark = pytest.mark.skipif(
+    os.environ.get("TOONVERTER_FIXTURE_CORPUS") != "1",
+    reason="set TOONVERTER_FIXTURE_CORPUS=1 to run the fixture corpus suite",
+)
+
+
+def _fixture_names() -> list[str]:
+    return sorted(path.stem for path in FIXTURES_DIR.glob("*.toon"))
+
+
+def _load(name: str) -> tuple[str, Any]:
+    toon_str = (FIXTURES_DIR / f"{name}.toon").read_text(encoding="utf-8")
+    expected = json.loads((FIXTURES_DIR / f"{name}.json").read_text(encoding="utf-8"))
+    return toon_str, expected
+
+
+def _normalize(value: Any) -> Any:
+    """Map values to their JSON meaning, where 1.0 and 1 are the same number."""
+    if isinstance(value, dict):
+        return {key: _normalize(item) for key, item in value.items()}
+    if isinstance(value, list):
+        return [_normalize(item) for item in value]
+    if isinstance(value, float) and value.is_integer():
+        return int(value)
+    return value
+
+
+def _assert_same_json(actual: Any, expected: Any, label: str) -> None:
+    """Compare two values as JSON trees and fail with a readable diff."""
+    actual_text = json.dumps(_normalize(actual), indent=2, ensure_ascii=False)
+    expected_text = json.dumps(_normalize(expected), indent=2, ensure_ascii=False)
+    if actual_text != expected_text:
+        diff = difflib.unified_diff(
+            expected_text.splitlines(),
+            actual_text.splitlines(),
+            fromfile=f"{label} (expected)",
+            tofile=f"{label} (actual)",
+            lineterm="",
+        )
+        pytest.fail("\n".join(diff), pytrace=False)
+
+
+def _decode_params() -> list[Any]:
+    return [
+        pytest.param(
+            name,
+            marks=pytest.mark.xfail(reason=KNOWN_DIVERGENCES[name], strict=True),
+        )
+        if name in KNOWN_DIVERGENCES
+        else name
+        for name in _fixture_names()
+    ]
+
+
+class TestFixtureCorpus:
+    """Check decoding and re-encoding of every fixture."""
+
+    def test_fixture_corpus(self):
+        """Test every fixture has its expected JSON and the corpus is not empty."""
+        names = _fixture_names()
+        assert len(names) >= 30
+        assert [n for n in names if not (FIXTURES_DIR / f"{n}.json").exists()] == []
+        assert set(KNOWN_DIVERGENCES) <= set(names)
+
+    @pytest.mark.parametrize("name", _decode_params())
+    def test_decode_matches_expected(self, name):
+        """Test the decoder produces the expected JSON."""
+        toon_str, expected = _load(name)
+        _assert_same_json(decode(toon_str), expected, name)
+
+    @pytest.mark.parametrize("name", _fixture_names())
+    def test_encode_round_trips(self, name):
+        """Test the encoder's output for the expected JSON decodes back to it."""
+        _, expected = _load(name)
+        _assert_same_json(decode(encode(expected)), expected, name)
@@ -0,0 +1,88 @@
+"""Check or replace the hand-written expected JSON with the reference implementation's.
+
+Each ``fixtures/<name>.toon`` is decoded by the reference implementation and
+compared with ``fixtures/<name>.json``, which is rewritten unless ``--check``
+is given. The reference command is run once per fixture with ``{path}``
+replaced by the ``.toon`` file and must print the decoded JSON to stdout.
+
+Usage:
+    python tests/fixture_corpus/update_fixtures.py [--check] [--reference-cmd CMD] [NAME ...]
+"""
+
+import argparse
+import json
+import shlex
+import subprocess
+import sys
+from pathlib import Path
+
+
+FIXTURES_DIR = Path(__file__).parent / "fixtures"
+DEFAULT_REFERENCE_CMD = "npx --yes @toon-format/cli --decode {path}"
+
+
+def reference_json(path: Path, reference_cmd: str) -> str:
+    """Decode one fixture with the reference implementation.
+
+    Args:
+        path: Path of the ``.toon`` fixture
+        reference_cmd: Command template containing ``{path}``
+
+    Returns:
+        The decoded JSON, pretty-printed with a trailing newline
+
+    Raises:
+        RuntimeError: If the reference command fails or prints invalid JSON
+    """
+    command = [part.replace("{path}", str(path)) for part in shlex.split(reference_cmd)]
+    completed = subprocess.run(command, capture_output=True, text=True, check=False)
+    if completed.returncode != 0:
+        msg = f"Reference decoder failed on {path.name}: {completed.stderr.strip()}"
+        raise RuntimeError(msg)
+    try:
+        value = json.loads(completed.stdout)
+    except json.JSONDecodeError as e:
+        msg = f"Reference decoder printed invalid JSON for {path.name}: {e}"
+        raise RuntimeError(msg) from e
+    return json.dumps(value, indent=2, ensure_ascii=False) + "\n"
+
+
+def main(argv: list[str] | None = None) -> int:
+    """Update (or check) the expected JSON of the selected fixtures.
+
+    Args:
+        argv: Command-line arguments (default: sys.argv[1:])
+
+    Returns:
+        Exit code: 0 when nothing changed or all files were written, 1 when
+        ``--check`` found stale fixtures
+    """
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("names", nargs="*", help="fixture names (default: all)")
+    parser.add_argument(
+        "--check", action="store_true", help="report stale fixtures without writing"
+    )
+    parser.add_argument("--reference-cmd", default=DEFAULT_REFERENCE_CMD)
+    args = parser.parse_args(argv)
+
+    names = args.names or sorted(path.stem for path in FIXTURES_DIR.glob("*.toon"))
+    stale = []
+    for name in names:
+        expected_path = FIXTURES_DIR / f"{name}.json"
+        new_text = reference_json(FIXTURES_DIR / f"{name}.toon", args.reference_cmd)
+        old_text = expected_path.read_text(encoding="utf-8") if expected_path.exists() else None
+        if new_text == old_text:
+            continue
+        stale.append(name)
+        if not args.check:
+            expected_path.write_text(new_text, encoding="utf-8")
+
+    verb = "stale" if args.check else "updated"
+    for name in stale:
+        sys.stdout.write(f"{verb}: {name}\n")
+    sys.stdout.write(f"{len(stale)} of {len(names)} fixtures {verb}\n")
+    return 1 if args.check and stale else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,104 @@
+"""Integration tests for Arrow-like columnar input."""
+
+import pytest
+
+from toonverter import decode
+from toonverter.core.exceptions import ValidationError
+from toonverter.integrations.arrow_integration import arrow_to_columns, encode_from_arrow
+
+
+class MockArray:
+    """Arrow array chunk exposing to_pylist()."""
+
+    def __init__(self, values):
+        self.values = values
+
+    def to_pylist(self):
+        return list(self.values)
+
+
+class MockScalar:
+    """Arrow scalar exposing as_py()."""
+
+    def __init__(self, value):
+        self.value = value
+
+    def as_py(self):
+        return self.value
+
+
+class MockChunkedArray:
+    """Arrow chunked array exposing chunks."""
+
+    def __init__(self, *chunks):
+        self.chunks = list(chunks)
+
+
+class MockTable:
+    """Arrow table exposing column_names and columns."""
+
+    def __init__(self, column_names, columns):
+        self.column_names = column_names
+        self.columns = columns
+
+
+class TestEncodeFromArrow:
+    """Test encoding from Arrow-like columnar objects."""
+
+    def test_chunked_columns_encode_tabular(self):
+        """Test chunks are concatenated and encoded as a tabular array."""
+        table = MockTable(
+            ["id", "name"],
+            [
+                MockChunkedArray(MockArray([1, 2]), MockArray([3])),
+                MockChunkedArray(MockArray(["a"]), MockArray(["b", "c"])),
+            ],
+        )
+
+        toon = encode_from_arrow(table)
+
+        assert toon.startswith("[3]{id,name}:")
+        assert decode(toon) == [
+            {"id": 1, "name": "a"},
+            {"id": 2, "name": "b"},
+            {"id": 3, "name": "c"},
+        ]
+
+    def test_nulls_become_null(self):
+        """Test null cells are encoded as null."""
+        table = MockTable(
+            ["id", "score"],
+            [MockChunkedArray(MockArray([1, 2])), MockChunkedArray(MockArray([None, 1.5]))],
+        )
+
+        assert decode(encode_from_arrow(table)) == [
+            {"id": 1, "score": None},
+            {"id": 2, "score": 1.5},
+        ]
+
+    def test_record_batch_columns_and_scalars(self):
+        """Test unchunked columns and scalar items with as_py()."""
+        table = MockTable(
+            lambda: ["flag"],
+            [[MockScalar(True), MockScalar(None)]],
+        )
+
+        assert arrow_to_columns(table) == {"flag": [True, None]}
+
+    def test_empty_table(self):
+        """Test a table with no rows encodes as an empty array."""
+        table = MockTable(["id"], [MockChunkedArray()])
+
+        assert decode(encode_from_arrow(table)) == []
+
+    def test_rejects_non_columnar_input(self):
+        """Test objects without the Arrow interface are rejected."""
+        with pytest.raises(TypeError, match="Arrow-like"):
+            encode_from_arrow([{"id": 1}])
+
+    def test_rejects_ragged_columns(self):
+        """Test columns of different lengths are rejected."""
+        table = MockTable(["a", "b"], [MockArray([1, 2]), MockArray([1])])
+
+        with pytest.raises(ValidationError, match="different lengths"):
+            encode_from_arrow(table)
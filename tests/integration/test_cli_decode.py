@@ -0,0 +1,69 @@
+"""Integration tests for the decode CLI command."""
+
+import json
+
+import pytest
+from click.testing import CliRunner
+
+from toonverter.cli.main import cli
+
+
+class TestCLIDecodeOutput:
+    """Test JSON output formatting and size capping of decode."""
+
+    @pytest.fixture
+    def runner(self):
+        return CliRunner()
+
+    @pytest.fixture
+    def toon_file(self, tmp_path):
+        path = tmp_path / "data.toon"
+        path.write_text("users[3]{id,name}:\n  1,Alice\n  2,Bob\n  3,Carol\n")
+        return path
+
+    def test_compact_smaller_than_pretty(self, runner, toon_file, tmp_path):
+        """Test compact and pretty output decode to the same data."""
+        pretty = tmp_path / "pretty.json"
+        compact = tmp_path / "compact.json"
+
+        result = runner.invoke(cli, ["decode", str(toon_file), "-o", str(pretty), "--indent", "4"])
+        assert result.exit_code == 0
+        result = runner.invoke(cli, ["decode", str(toon_file), "-o", str(compact), "--compact"])
+        assert result.exit_code == 0
+
+        assert compact.stat().st_size < pretty.stat().st_size
+        assert "\n" not in compact.read_text()
+        assert '\n    "users"' in pretty.read_text()
+        assert json.loads(compact.read_text()) == json.loads(pretty.read_text())
+
+    def test_max_output_bytes_exceeded(self, runner, toon_file, tmp_path):
+        """Test the cap fails cleanly without leaving a partial file."""
+        target = tmp_path / "out.json"
+
+        result = runner.invoke(
+            cli, ["decode", str(toon_file), "-o", str(target), "--max-output-bytes", "10"]
+        )
+
+        assert result.exit_code == 1
+        assert "exceeding limit of 10" in result.output
+        assert not target.exists()
+        assert sorted(p.name for p in tmp_path.iterdir()) == ["data.toon"]
+
+    def test_max_output_bytes_within_limit(self, runner, toon_file, tmp_path):
+        """Test output under the cap is written."""
+        target = tmp_path / "out.json"
+
+        result = runner.invoke(
+            cli,
+            ["decode", str(toon_file), "-o", str(target), "--compact", "--max-output-bytes", "1000"],
+        )
+
+        assert result.exit_code == 0
+        assert json.loads(target.read_text())["users"][2]["name"] == "Carol"
+
+    def test_max_output_bytes_stdout(self, runner, toon_file):
+        """Test the cap also applies when printing to stdout."""
+        result = runner.invoke(cli, ["decode", str(toon_file), "--max-output-bytes", "10"])
+
+        assert result.exit_code == 1
+        assert "exceeding limit of 10" in result.output
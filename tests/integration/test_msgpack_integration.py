@@ -0,0 +1,85 @@
+"""Tests for MessagePack integration."""
+
+import pytest
+
+from toonverter.integrations.msgpack_integration import (
+    MSGPACK_AVAILABLE,
+    from_msgpack_value,
+    msgpack_key,
+    msgpack_to_toon,
+    toon_to_msgpack,
+)
+
+
+DOCUMENT = {
+    "event": "order.created",
+    "meta": {"source": {"service": "shop", "region": "eu"}, "retries": 0},
+    "payload": b"\x00\x01\xfe\xff",
+    "counter": 2**64 - 1,
+    "items": [{"sku": "A1", "qty": 2}, {"sku": "B2", "qty": 1}],
+    7: "numeric key",
+}
+
+
+class TestMsgpackValueMapping:
+    """Test the mapping of MessagePack values to TOON-encodable data."""
+
+    def test_binary_becomes_base64(self):
+        """Test binary blobs are written as base64 strings."""
+        assert from_msgpack_value({"blob": b"\x00\x01\xfe\xff"}) == {"blob": "AAH+/w=="}
+
+    def test_keys_are_stringified(self):
+        """Test non-string map keys use their JSON spelling."""
+        assert from_msgpack_value({1: {True: None, None: 1.5}}) == {
+            "1": {"true": None, "null": 1.5}
+        }
+        assert msgpack_key(b"k") == "aw=="
+
+    def test_nested_arrays(self):
+        """Test arrays (and tuples) are converted recursively."""
+        assert from_msgpack_value((1, [b"a", {2: "x"}])) == [1, ["YQ==", {"2": "x"}]]
+
+    def test_missing_dependency(self):
+        """Test a clear error when msgpack is not installed."""
+        from unittest.mock import patch
+
+        with patch("toonverter.integrations.msgpack_integration.MSGPACK_AVAILABLE", False):
+            with pytest.raises(ImportError, match="msgpack is required"):
+                msgpack_to_toon(b"\x80")
+            with pytest.raises(ImportError, match="msgpack is required"):
+                toon_to_msgpack("a: 1")
+
+
+@pytest.mark.skipif(not MSGPACK_AVAILABLE, reason="msgpack not installed")
+class TestMsgpackRoundTrip:
+    """Test conversions against the msgpack package's view of the data."""
+
+    def test_msgpack_to_toon(self):
+        """Test a packed document decodes from TOON to the mapped data."""
+        import msgpack
+
+        from toonverter.decoders import decode
+
+        packed = msgpack.packb(DOCUMENT, use_bin_type=True)
+        result = msgpack_to_toon(packed)
+
+        assert decode(result) == from_msgpack_value(DOCUMENT)
+        assert "items[2]{sku,qty}:" in result
+
+    def test_round_trip(self):
+        """Test MessagePack -> TOON -> MessagePack keeps everything but binary type."""
+        import msgpack
+
+        packed = msgpack.packb(DOCUMENT, use_bin_type=True)
+        repacked = toon_to_msgpack(msgpack_to_toon(packed))
+
+        unpacked = msgpack.unpackb(repacked, raw=False)
+        assert unpacked == from_msgpack_value(DOCUMENT)
+        assert unpacked["counter"] == 18446744073709551615
+
+    def test_invalid_payload(self):
+        """Test truncated payloads raise ConversionError."""
+        from toonverter.core.exceptions import ConversionError
+
+        with pytest.raises(ConversionError, match="Failed to convert MessagePack to TOON"):
+            msgpack_to_toon(b"\x82\xa1a")
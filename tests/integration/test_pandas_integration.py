@@ -34,6 +34,13 @@ class TestPandasDataFrameConversion:
         assert df_result.shape == df_original.shape
         assert set(df_result.columns) == set(df_original.columns)
 
+    def test_tabular_toon_to_pandas_columns(self):
+        """Test tabular TOON builds the DataFrame from decoded columns."""
+        df = toon_to_pandas("[2]{a,b}:\n  1,x\n  2,y")
+
+        assert list(df.columns) == ["a", "b"]
+        assert df.to_dict("records") == [{"a": 1, "b": "x"}, {"a": 2, "b": "y"}]
+
     def test_dataframe_with_various_types(self):
         """Test DataFrame with different column types."""
         df = pd.DataFrame(
@@ -132,3 +139,127 @@ class TestPandasOptions:
 
         # Compressed should be smaller or equal
         assert len(toon_compressed) <= len(toon_normal)
+
+    def test_column_encoding_roundtrip(self):
+        """Test delta and rle columns round-trip through a DataFrame."""
+        from toonverter.core.types import EncodeOptions
+
+        df = pd.DataFrame({"ts": [1000, 1250, 1500], "status": ["ok", "ok", "ok"], "v": [1, 2, 3]})
+        options = EncodeOptions(column_encoding={"ts": "delta", "status": "rle"})
+
+        toon = pandas_to_toon(df, options)
+
+        assert toon.startswith("[3]{ts:+delta,status:*rle,v}:\n  1000,ok,1\n  250,,2")
+        pd.testing.assert_frame_equal(toon_to_pandas(toon, column_encoding=True), df)
+
+
+class TestPandasMissingValues:
+    """Test pandas missing-value markers and na_values encode as null."""
+
+    def test_pandas_sentinels_are_null(self):
+        """Test None, NaN, pd.NA and pd.NaT all encode as null."""
+        df = pd.DataFrame(
+            {
+                "id": [1, 2, 3, 4],
+                "v": pd.array([1, None, 3, None], dtype="Int64"),
+                "s": ["a", None, pd.NA, "d"],
+                "f": [1.5, float("nan"), 2.5, 3.5],
+                "t": pd.Series([pd.NaT] * 4, dtype="object"),
+            }
+        )
+
+        toon = pandas_to_toon(df)
+
+        assert toon == (
+            "[4]{id,v,s,f,t}:\n1,1,a,1.5,null\n2,null,null,null,null\n"
+            "3,3,null,2.5,null\n4,null,d,3.5,null"
+        )
+
+    def test_na_values(self):
+        """Test caller-declared sentinels encode as null, other cells untouched."""
+        df = pd.DataFrame({"code": ["x", "N/A", "-", "N/A!"], "n": [1, -999, 3, 4]})
+
+        toon = pandas_to_toon(df, na_values=["N/A", "-", -999])
+
+        assert toon == "[4]{code,n}:\nx,1\nnull,null\nnull,3\nN/A!,4"
+
+    def test_na_values_before_column_types(self):
+        """Test nulled cells pass column type checks."""
+        df = pd.DataFrame({"n": [1, "N/A", 3]})
+
+        toon = pandas_to_toon(df, column_types={"n": "int"}, na_values=["N/A"])
+
+        assert toon == "[3]{n}:\n1\nnull\n3"
+
+    def test_stream(self):
+        """Test the streaming export nulls the same cells."""
+        from toonverter.integrations.pandas_integration import pandas_to_toon_stream
+
+        df = pd.DataFrame({"a": [1, 2], "b": [pd.NA, "N/A"]})
+
+        toon = "".join(pandas_to_toon_stream(df, na_values=["N/A"]))
+
+        assert toon == "[2]:\n-\na: 1\nb: null\n-\na: 2\nb: null"
+
+
+class TestPandasColumnTypes:
+    """Test column type validation and coercion."""
+
+    def _mixed_df(self):
+        scores = list(range(1000))
+        scores[503] = "n/a"
+        return pd.DataFrame({"id": range(1000), "score": scores})
+
+    def test_violation_raises_with_column_and_row(self):
+        """Test a stray string in an int column raises ValidationError."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="'score' row 503"):
+            pandas_to_toon(self._mixed_df(), column_types={"id": "int", "score": "int"})
+
+    def test_violation_coerced_with_warning(self):
+        """Test coerce=True returns the encoded string and a warnings list."""
+        toon, warnings = pandas_to_toon(
+            self._mixed_df(), column_types={"id": "int", "score": "int"}, coerce=True
+        )
+
+        assert "[1000]{id,score}:" in toon
+        assert len(warnings) == 1
+        assert warnings[0].column == "score"
+        assert warnings[0].row == 503
+        assert warnings[0].value == "n/a"
+        assert warnings[0].coerced is None
+        assert "\n503,null\n" in toon
+
+    def test_coercible_string_is_converted(self):
+        """Test numeric strings are coerced to the declared type."""
+        df = pd.DataFrame({"n": [1, "2", 3]})
+        toon, warnings = pandas_to_toon(df, column_types={"n": "int"}, coerce=True)
+
+        assert [w.coerced for w in warnings] == [2]
+        assert "\n2\n" in toon
+
+    def test_matching_columns_return_plain_string(self):
+        """Test valid data without coerce returns a plain string."""
+        df = pd.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+        toon = pandas_to_toon(df, column_types={"a": "int", "b": "str"})
+
+        assert isinstance(toon, str)
+
+    def test_unknown_type_rejected(self):
+        """Test unknown type names raise ValueError."""
+        with pytest.raises(ValueError, match="Unknown column types"):
+            pandas_to_toon(pd.DataFrame({"a": [1]}), column_types={"a": "decimal"})
+
+    def test_typed_decode(self):
+        """Test toon_to_pandas decodes straight into the declared column types."""
+        from toonverter.core.exceptions import ConversionError
+
+        toon = "[3]{id,score,name}:\n  1,2,Ada\n  2,2.5,Bob\n  3,null,Cy"
+        df = toon_to_pandas(toon, column_types={"name": "str", "score": "float"})
+
+        assert list(df.columns) == ["name", "score"]
+        assert df["score"].dtype == "float64"
+        assert df["name"].tolist() == ["Ada", "Bob", "Cy"]
+        with pytest.raises(ConversionError, match="Row 1 .*column 'name'"):
+            toon_to_pandas(toon, column_types={"name": "int"})
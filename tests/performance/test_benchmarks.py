@@ -1,7 +1,11 @@
 """Performance benchmarks for TOON encoding/decoding."""
 
 import json
+import tracemalloc
 
+import pytest
+
+from toonverter.decoders.lexer import ToonLexer
 from toonverter.decoders.toon_decoder import ToonDecoder
 from toonverter.encoders.toon_encoder import ToonEncoder
 
@@ -89,6 +93,146 @@ class TestDecodingPerformance:
         assert len(result["users"]) == 1000
 
 
+@pytest.mark.slow
+class TestWideTableDecode:
+    """Benchmark decoding a wide table (100k rows x 50 columns)."""
+
+    ROWS = 100_000
+    COLUMNS = 50
+
+    def setup_method(self):
+        """Build the table with a mix of number and string columns."""
+        self.rows = [
+            {f"c{j}": (i * j) % 97 if j % 3 else f"v{i % 13}" for j in range(self.COLUMNS)}
+            for i in range(self.ROWS)
+        ]
+        self.text = ToonEncoder().encode({"rows": self.rows})
+
+    def test_decode_wide_table(self, benchmark):
+        """Benchmark decoding every row into a dict."""
+        result = benchmark.pedantic(ToonDecoder().decode, args=(self.text,), rounds=3)
+
+        assert result["rows"] == self.rows
+
+
+class TestLexerPerformance:
+    """Benchmark the lexer on a large tabular document."""
+
+    def setup_method(self):
+        """Build a ~10 MB tabular document."""
+        rows = [f'  {i},User{i},"user{i}@example.com",true' for i in range(200_000)]
+        self.text = "users[200000]{id,name,email,active}:\n" + "\n".join(rows)
+
+    def test_lex_large_document(self, benchmark):
+        """Benchmark tokenizing the whole document."""
+        tokens = benchmark(lambda: ToonLexer(self.text).tokenize())
+
+        assert len(tokens) > 200_000
+
+
+class TestTabularEncodeMemory:
+    """Check tabular encoding keeps finished lines, not a table of cells."""
+
+    ROWS = 50_000
+
+    def setup_method(self):
+        """Build a uniform table of rows."""
+        self.data = {
+            "rows": [{"id": i, "name": f"user{i}", "score": i * 0.5} for i in range(self.ROWS)]
+        }
+
+    def _peak(self, options=None):
+        """Encode under tracemalloc and return (output, peak bytes)."""
+        tracemalloc.start()
+        try:
+            output = ToonEncoder(options).encode(self.data)
+            _, peak = tracemalloc.get_traced_memory()
+        finally:
+            tracemalloc.stop()
+        return output, peak
+
+    def test_peak_memory_is_bounded(self):
+        """Test peak memory stays a small multiple of the output size."""
+        output, peak = self._peak()
+
+        assert output.count("\n") == self.ROWS
+        # Lines plus the joined output; holding every cell as well was ~12x
+        assert peak < 6 * len(output)
+
+    def test_aligned_peak_memory_is_bounded(self):
+        """Test the two-pass aligned encoding does not keep cells either."""
+        from toonverter.core.spec import ToonEncodeOptions
+
+        output, peak = self._peak(ToonEncodeOptions(align_columns=True))
+
+        assert output.count("\n") == self.ROWS
+        assert peak < 6 * len(output)
+
+
+@pytest.mark.slow
+class TestColumnEncodingRoundtrip:
+    """Round-trip a million-row table through delta and rle columns."""
+
+    ROWS = 1_000_000
+
+    def test_monotone_and_constant_columns(self):
+        """Test a monotone timestamp and a constant status survive the round trip."""
+        from toonverter.core.spec import ToonDecodeOptions, ToonEncodeOptions
+
+        start = 1_700_000_000_000
+        rows = [
+            {"ts": start + 250 * i, "status": "ok", "seq": self.ROWS - i}
+            for i in range(self.ROWS)
+        ]
+        column_encoding = {"ts": "delta", "status": "rle", "seq": "delta"}
+        options = ToonEncodeOptions(column_encoding=column_encoding)
+
+        toon_str = ToonEncoder(options).encode(rows)
+        plain = ToonEncoder().encode(rows)
+
+        # Every row after the first is "250,,-1"
+        assert toon_str.count("\n  250,,-1") == self.ROWS - 1
+        assert len(toon_str) < len(plain) / 2
+        decoder = ToonDecoder(ToonDecodeOptions(column_encoding=True))
+        assert decoder.decode(toon_str) == rows
+
+
+@pytest.mark.slow
+class TestTypedColumnDecode:
+    """Decode a 100k-row table straight into typed columns."""
+
+    ROWS = 100_000
+    SCHEMA = {"id": "int", "name": "str", "score": "float", "flag": "bool"}
+
+    def _table(self):
+        lines = [
+            f"  {i},name{i},{i * 0.25},{'true' if i % 3 else 'false'}" for i in range(self.ROWS)
+        ]
+        return f"[{self.ROWS}]{{id,name,score,flag}}:\n" + "\n".join(lines)
+
+    def test_matches_generic_decode(self):
+        """Test typed columns hold the same values as decode_columns."""
+        from toonverter.decoders import decode_columns, decode_typed_columns
+
+        toon_str = self._table()
+        typed = decode_typed_columns(toon_str, self.SCHEMA)
+
+        assert typed == decode_columns(toon_str)
+        assert len(typed["id"]) == self.ROWS
+        assert {type(value) for value in typed["score"]} == {float}
+
+    def test_mismatch_row_and_column(self):
+        """Test a bad cell deep in the table is reported by row and column."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.decoders import decode_typed_columns
+
+        lines = self._table().split("\n")
+        lines[57] = "  56,name56,oops,true"
+
+        with pytest.raises(ValidationError, match="Row 57 .*column 'score'"):
+            decode_typed_columns("\n".join(lines), self.SCHEMA)
+
+
 class TestRoundtripPerformance:
     """Benchmark roundtrip performance."""
 
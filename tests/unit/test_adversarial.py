@@ -0,0 +1,311 @@
+"""Regression corpus of hostile decoder inputs.
+
+Every case runs under a watchdog thread with a generous time bound and must
+end in a value or a toonverter error - never a hang, a RecursionError or any
+other stray exception.
+"""
+
+import random
+import threading
+from collections.abc import Callable
+from pathlib import Path
+from typing import Any
+
+import pytest
+
+from toonverter.core.exceptions import DecodingError, ToonConverterError, ValidationError
+from toonverter.core.spec import ToonDecodeOptions
+from toonverter.decoders import StreamDecoder, decode
+
+
+TIME_BOUND = 30.0
+
+
+def _bounded(func: Callable[[], Any]) -> Any:
+    """Run ``func`` under a watchdog and return its result or raised exception."""
+    outcome: dict[str, Any] = {}
+
+    def target() -> None:
+        try:
+            outcome["value"] = func()
+        except BaseException as e:  # reported to the test thread
+            outcome["error"] = e
+
+    worker = threading.Thread(target=target, daemon=True)
+    worker.start()
+    worker.join(TIME_BOUND)
+    if worker.is_alive():
+        pytest.fail(f"input took longer than {TIME_BOUND}s")
+    if "error" in outcome:
+        return outcome["error"]
+    return outcome["value"]
+
+
+def _indent_ramp(depth: int) -> str:
+    """Each line one level deeper than the last (quadratic in size, so depth is capped)."""
+    return "".join("  " * i + f"k{i}:\n" for i in range(depth))
+
+
+class TestAdversarialInputs:
+    """Test the decoder fails cleanly on pathological documents."""
+
+    def test_indentation_ramp(self):
+        """Test nesting deeper than the recursion limit is a DecodingError."""
+        result = _bounded(lambda: decode(_indent_ramp(3000)))
+
+        assert isinstance(result, DecodingError)
+
+    def test_indentation_ramp_with_depth_limit(self):
+        """Test max_indent_depth stops a ramp while tokenizing."""
+        options = ToonDecodeOptions(max_indent_depth=64)
+        result = _bounded(lambda: decode(_indent_ramp(3000), options))
+
+        assert isinstance(result, ValidationError)
+        assert "at line 66" in str(result)
+
+    def test_indentation_ramp_streamed(self):
+        """Test the streaming decoder also reports deep nesting as a DecodingError."""
+        doc = "[1]:\n  - " + _indent_ramp(3000).replace("\n", "\n    ", 2999)
+        lines = iter(doc.splitlines(keepends=True))
+
+        result = _bounded(lambda: list(StreamDecoder().decode_stream(lines)))
+
+        assert isinstance(result, DecodingError)
+
+    @pytest.mark.parametrize(
+        "doc",
+        [
+            "[" * 100_000,
+            "a: " + "[" * 100_000,
+            "a: " + "{" * 100_000,
+            "a: " + "{b: " * 5000 + "1" + "}" * 5000,
+        ],
+    )
+    def test_bracket_floods(self, doc):
+        """Test runs of open brackets and deep inline objects fail cleanly."""
+        result = _bounded(lambda: decode(doc))
+
+        assert isinstance(result, DecodingError)
+
+    def test_indent_dedent_thrash(self):
+        """Test alternating deep indents and full dedents decode in bounded time."""
+        block = "a:\n" + "".join("  " * j + f"b{j}:\n" for j in range(1, 200))
+        doc = block + "".join(block.replace("a:", f"a{i}:", 1) for i in range(200))
+
+        result = _bounded(lambda: decode(doc))
+
+        assert isinstance(result, dict)
+        assert len(result) == 201
+
+    def test_huge_unterminated_string(self):
+        """Test a 64 MB unterminated string fails with a short message."""
+        doc = 'a: "' + "x" * 64_000_000
+
+        result = _bounded(lambda: decode(doc))
+
+        assert isinstance(result, DecodingError)
+        assert "Unterminated quoted string at line 1, column 4" in str(result)
+        assert len(str(result)) < 300
+
+    def test_null_bytes_are_kept(self):
+        """Test NUL characters inside keys and values are ordinary characters."""
+        result = _bounded(lambda: decode("a\x00b: c\x00d"))
+
+        assert result == {"a\x00b": "c\x00d"}
+
+    def test_surrogate_escape_is_rejected(self):
+        """Test unsupported \\u escapes such as lone surrogates are errors."""
+        result = _bounded(lambda: decode('a: "\\ud800"'))
+
+        assert isinstance(result, DecodingError)
+        assert "Invalid escape sequence" in str(result)
+
+    @pytest.mark.parametrize(
+        "doc",
+        [
+            "[999999999999999999]: 1",
+            "[999999999999999999]{a}:\n  1",
+            "items[999999999999999999]:\n  - 1",
+        ],
+    )
+    def test_huge_declared_length(self, doc):
+        """Test a huge declared length is a length mismatch, not an allocation."""
+        result = _bounded(lambda: decode(doc))
+
+        assert isinstance(result, ValidationError)
+        assert "declared 999999999999999999" in str(result)
+
+    def test_errors_share_the_base_class(self):
+        """Test every failure above is catchable as ToonConverterError."""
+        for doc in ("[" * 100_000, _indent_ramp(3000), 'a: "\\q"'):
+            assert isinstance(_bounded(lambda doc=doc: decode(doc)), ToonConverterError)
+
+
+FIXTURES_DIR = Path(__file__).parents[1] / "fixture_corpus" / "fixtures"
+# Fragments spliced into the corpus documents: structure, layout and escapes
+MUTATION_PIECES = [*'[]{}:,|\t\n -"\\#', "  ", "\n  ", "[1]", "{a}", "- ", "null", "1e3"]
+
+
+def _mutants(count: int, seed: int = 1939) -> list[str]:
+    """Derive documents from the fixture corpus by deleting, inserting and truncating."""
+    docs = [path.read_text(encoding="utf-8") for path in sorted(FIXTURES_DIR.glob("*.toon"))]
+    docs += ["rows[2|]{a|b}:\n  1|2\n  3|4", "k[2]{t:+delta,s:*rle}:\n  1,a\n  2,"]
+    rng = random.Random(seed)
+    mutants = []
+    for _ in range(count):
+        chars = list(rng.choice(docs))
+        for _ in range(rng.randint(1, 4)):
+            index = rng.randint(0, len(chars))
+            roll = rng.random()
+            if roll < 0.4 and chars:
+                del chars[min(index, len(chars) - 1)]
+            elif roll < 0.8:
+                chars.insert(index, rng.choice(MUTATION_PIECES))
+            else:
+                del chars[index:]
+        mutants.append("".join(chars))
+    return mutants
+
+
+def _decode_apis(options: Any) -> dict[str, Callable[[str], Any]]:
+    """Every public entry point that parses TOON text, bound to one set of options."""
+    from toonverter.decoders import (
+        ChunkedDecoder,
+        decode_columns,
+        decode_fragment,
+        decode_rows,
+        decode_spans,
+        validate_toon,
+    )
+
+    def chunked(doc: str) -> Any:
+        decoder = ChunkedDecoder(options)
+        data = doc.encode("utf-8")
+        decoder.feed(data[:7])
+        decoder.feed(data[7:])
+        return decoder.finish()
+
+    return {
+        "decode": lambda doc: decode(doc, options),
+        "decode_columns": lambda doc: decode_columns(doc, options=options),
+        "decode_rows": lambda doc: decode_rows(doc, options=options),
+        "decode_fragment": lambda doc: decode_fragment(doc, options),
+        "decode_spans": lambda doc: decode_spans(doc, options),
+        "validate_toon": lambda doc: validate_toon(doc, options),
+        "decode_stream": lambda doc: list(
+            StreamDecoder(options).decode_stream(iter(doc.splitlines(keepends=True)))
+        ),
+        "chunked": chunked,
+    }
+
+
+@pytest.mark.filterwarnings("ignore:Inconsistent indentation")
+class TestNoStrayExceptions:
+    """Test malformed input only ever raises toonverter errors from the public APIs."""
+
+    # Documents take these in turn, so a regression repeated once per set meets them all
+    OPTION_SETS = (
+        ToonDecodeOptions(),
+        ToonDecodeOptions(strict=False, missing_cells="null"),
+        ToonDecodeOptions(row_errors="mark", max_errors=5),
+        ToonDecodeOptions(as_pairs=True, duplicate_keys="collect", indent_size=None),
+        ToonDecodeOptions(column_encoding=True, missing_values="null", expand_paths="safe"),
+    )
+
+    def _assert_clean(self, docs: list[str]) -> None:
+        """Run every document through every decode API under several option sets."""
+        stray = []
+        for index, doc in enumerate(docs):
+            options = self.OPTION_SETS[index % len(self.OPTION_SETS)]
+            for name, api in _decode_apis(options).items():
+                outcome = _bounded(lambda api=api, doc=doc: api(doc))
+                if isinstance(outcome, BaseException) and not isinstance(
+                    outcome, ToonConverterError
+                ):
+                    stray.append(f"{name}({doc!r}): {type(outcome).__name__}: {outcome}")
+        assert not stray, "\n".join(stray[:10])
+
+    def test_fixture_corpus(self):
+        """Test the valid fixtures decode through every API without stray errors."""
+        self._assert_clean([p.read_text(encoding="utf-8") for p in FIXTURES_DIR.glob("*.toon")])
+
+    def test_mutated_fixtures(self):
+        """Test a fixed corpus of damaged fixtures fails cleanly everywhere."""
+        self._assert_clean(_mutants(400))
+
+    @pytest.mark.parametrize(
+        "doc",
+        [
+            'a": "x\\t',
+            '[1]:\n  - "bad \\e escape"',
+            "[2]:\n  - 1\n \t - 2",
+            "#!toon indent=x\n[1]: 1",
+            "[1]{a{b}:\n  1",
+            "\ufeff[1]: 1",
+            "n[1e400]: 1",
+            "n[1e3]: 1",
+        ],
+    )
+    def test_regressions(self, doc):
+        """Test inputs that once escaped as ValueError, or hung, fail cleanly."""
+        self._assert_clean([doc] * len(self.OPTION_SETS))
+
+    def test_tokenize_toon_never_raises(self):
+        """Test the editor tokenizer reports problems as an error token instead of raising."""
+        from toonverter.decoders import tokenize_toon
+
+        for doc in [*_mutants(200, seed=7), "#!toon indent=x\na: 1", "a:\n  \t# note\n  b: 1"]:
+            outcome = _bounded(lambda doc=doc: tokenize_toon(doc))
+            assert isinstance(outcome, list), f"{doc!r}: {outcome!r}"
+            assert outcome[-1]["type"] in ("eof", "error")
+
+
+class TestBlankLineRuns:
+    """Test both lexers skip long runs of blank lines iteratively."""
+
+    BLANK_RUN = "\n" * 2_000_000
+
+    def _summary(self, tokens):
+        """Reduce tokens to comparable (type, value, line) tuples."""
+        return [(t.type.name, t.value, t.line) for t in tokens]
+
+    def test_lexer_blank_run_then_field(self):
+        """Test 2 million blank lines before one field lex to just that field."""
+        from toonverter.decoders.lexer import ToonLexer
+
+        tokens = _bounded(lambda: ToonLexer(self.BLANK_RUN + "key: value").tokenize())
+
+        assert self._summary(tokens) == [
+            ("IDENTIFIER", "key", 2_000_000),
+            ("COLON", ":", 2_000_000),
+            ("IDENTIFIER", "value", 2_000_000),
+            ("NEWLINE", None, 2_000_000),
+            ("EOF", None, 2_000_001),
+        ]
+
+    def test_stream_lexer_blank_run_then_field(self):
+        """Test the streaming lexer produces the same tokens."""
+        from toonverter.decoders import StreamLexer
+
+        lines = iter((self.BLANK_RUN + "key: value").splitlines(keepends=True))
+        tokens = _bounded(lambda: list(StreamLexer(lines).tokenize()))
+
+        assert [t.type.name for t in tokens] == [
+            "IDENTIFIER",
+            "COLON",
+            "IDENTIFIER",
+            "NEWLINE",
+            "EOF",
+        ]
+        assert tokens[0].line == 2_000_000
+
+    def test_pending_dedents_after_blank_run(self):
+        """Test dedents still close every open level when the input ends in blank lines."""
+        from toonverter.decoders.lexer import ToonLexer
+
+        doc = "a:\n  b:\n    c: 1" + self.BLANK_RUN
+        tokens = _bounded(lambda: ToonLexer(doc).tokenize())
+
+        assert [t.type.name for t in tokens[-3:]] == ["DEDENT", "DEDENT", "EOF"]
+        assert [t.indent_level for t in tokens[-3:-1]] == [1, 0]
+        assert _bounded(lambda: decode(doc)) == {"a": {"b": {"c": 1}}}
@@ -1,5 +1,7 @@
 """Unit tests for public API."""
 
+import pytest
+
 import toonverter as toon
 
 
@@ -118,6 +120,468 @@ class TestEncodeDecode:
         assert decoded == data
 
 
+class TestDecodeValue:
+    """Test decoding a single value that is not a whole document."""
+
+    @pytest.mark.parametrize(
+        ("text", "expected"),
+        [
+            ("[3]: 1,2,3", [1, 2, 3]),
+            ("{a: 1, b: x}", {"a": 1, "b": "x"}),
+            ("42", 42),
+            ('"quoted value"', "quoted value"),
+            ("null", None),
+            ("[1]{id,name}:\n  1,Ada", [{"id": 1, "name": "Ada"}]),
+        ],
+    )
+    def test_fragments(self, text, expected):
+        """Test bare arrays, inline objects and scalars decode on their own."""
+        assert toon.decode_value(text) == expected
+
+    def test_trailing_text_ignored(self):
+        """Test text after the value is not an error, unlike decode()."""
+        assert toon.decode_value("42 43") == 42
+        with pytest.raises(toon.DecodingError, match="Extra tokens"):
+            toon.decode("42 43")
+
+    def test_options_overrides(self):
+        """Test keyword arguments are passed as decode options."""
+        assert toon.decode_value("[3]: 1,2", strict=False) == [1, 2]
+        with pytest.raises(toon.DecodingError, match="declared 3, got 2"):
+            toon.decode_value("[3]: 1,2")
+
+    def test_empty_input(self):
+        """Test empty input is a DecodingError."""
+        with pytest.raises(toon.DecodingError, match="empty input"):
+            toon.decode_value("   ")
+
+
+class TestDecodeInto:
+    """Test decoding into an existing dict or list."""
+
+    def test_updates_prepopulated_dict(self):
+        """Test nested containers are updated in place and unlisted keys kept."""
+        hosts = ["a", "b", "c"]
+        db = {"host": "old", "port": 5432, "replicas": hosts}
+        config = {"db": db, "debug": False, "name": "app"}
+        text = "db:\n  host: new\n  replicas[2]: x,y\ndebug: true\nextra: 1"
+
+        result = toon.decode_into(text, config)
+
+        assert result is config
+        assert config == {
+            "db": {"host": "new", "port": 5432, "replicas": ["x", "y"]},
+            "debug": True,
+            "name": "app",
+            "extra": 1,
+        }
+        assert config["db"] is db
+        assert db["replicas"] is hosts
+
+    def test_updates_list(self):
+        """Test a list takes the document's length, reusing but clearing its containers."""
+        first = {"id": 1, "tag": "a"}
+        items = [first, 2]
+
+        toon.decode_into("[3]:\n  - id: 9\n  - [1]: x\n  - 3", items)
+
+        assert items == [{"id": 9}, ["x"], 3]
+        assert items[0] is first
+
+    def test_list_elements_match_document(self):
+        """Test dicts nested inside list elements drop keys the document lacks."""
+        inner = {"a": 1, "b": 2}
+        config = {"servers": [{"name": "x", "opts": inner, "old": True}], "keep": 1}
+
+        toon.decode_into("servers[1]:\n  - name: y\n    opts:\n      a: 5", config)
+
+        assert config == {"servers": [{"name": "y", "opts": {"a": 5}}], "keep": 1}
+        assert config["servers"][0]["opts"] is inner
+
+    def test_type_change_replaces_value(self):
+        """Test a value of a different type replaces the existing one."""
+        config = {"limits": {"max": 1}, "mode": "a"}
+
+        toon.decode_into("limits: 5\nmode:\n  kind: b", config)
+
+        assert config == {"limits": 5, "mode": {"kind": "b"}}
+
+    def test_root_mismatch_raises(self):
+        """Test a root of the other container type is a ValidationError."""
+        with pytest.raises(toon.ValidationError, match="Cannot decode a list root into a dict"):
+            toon.decode_into("[2]: 1,2", {})
+        with pytest.raises(toon.ValidationError, match="Cannot decode a dict root into a list"):
+            toon.decode_into("a: 1", [])
+
+    def test_options_overrides(self):
+        """Test keyword arguments are passed as decode options."""
+        target: list = []
+        toon.decode_into("[2]: 1", target, strict=False)
+
+        assert target == [1]
+
+
+class TestReindent:
+    """Test rewriting a TOON document with another indentation width."""
+
+    FOUR_SPACE = (
+        "server:\n"
+        "    host: example.org\n"
+        "    ports[2]: 80,443\n"
+        "users[2]{id,name}:\n"
+        "    1,Ada\n"
+        "    2,Bob\n"
+        "jobs[2]:\n"
+        "    - name: build\n"
+        "        steps[2]: lint,test\n"
+        "    - [2]: a,b"
+    )
+
+    def test_four_to_two_spaces(self):
+        """Test a 4-space document reflows to 2-space with the same data."""
+        result = toon.reindent_toon(self.FOUR_SPACE, 4, 2)
+
+        assert result == (
+            "server:\n"
+            "  host: example.org\n"
+            "  ports[2]: 80,443\n"
+            "users[2]{id,name}:\n"
+            "  1,Ada\n"
+            "  2,Bob\n"
+            "jobs[2]:\n"
+            "  - name: build\n"
+            "    steps[2]: lint,test\n"
+            "  - [2]: a,b"
+        )
+        assert toon.decode(result) == toon.decode(self.FOUR_SPACE, indent_size=4)
+        assert toon.reindent_toon(result, 2, 4) == self.FOUR_SPACE
+
+    def test_inferred_input_indent(self):
+        """Test from_indent=None infers the input's indentation."""
+        assert toon.reindent_toon(self.FOUR_SPACE, None, 2) == toon.reindent_toon(
+            self.FOUR_SPACE, 4, 2
+        )
+
+    def test_encode_overrides(self):
+        """Test keyword arguments are passed as encode options."""
+        from toonverter.core.spec import Delimiter
+
+        result = toon.reindent_toon("a:\n    b[2]: 1,2", 4, 2, delimiter=Delimiter.PIPE)
+        assert result == "a:\n  b[2|]: 1|2"
+
+    def test_wrong_from_indent(self):
+        """Test input indented differently from from_indent is rejected."""
+        with pytest.raises(toon.ValidationError, match="multiple of 4 spaces"):
+            toon.reindent_toon("a:\n   b: 1", 4, 2)
+
+
+class TestPrettyMinified:
+    """Test the encode_pretty and encode_minified helpers."""
+
+    def test_pretty_separates_root_fields(self):
+        """Test pretty output puts a blank line between root-level fields."""
+        data = {"a": 1, "b": {"c": 2}, "d": [1, 2]}
+        assert toon.encode_pretty(data) == "a: 1\n\nb:\n  c: 2\n\nd[2]: 1,2"
+
+    def test_pretty_separates_list_items(self):
+        """Test pretty output puts a blank line between root list items."""
+        data = [{"a": 1, "b": 2}, 3]
+        assert toon.encode_pretty(data) == "[2]:\n  - a: 1\n    b: 2\n\n  - 3"
+
+    def test_pretty_roundtrip(self):
+        """Test pretty output decodes to the original data."""
+        data = {"rows": [{"x": 1, "y": 2}, {"x": 3, "y": 4}], "tags": ["a"], "n": {"m": 1}}
+        assert toon.decode(toon.encode_pretty(data)) == data
+
+    def test_minified_has_no_indentation(self):
+        """Test minified output uses compact mode."""
+        data = {"rows": [{"x": 1, "y": 2}, {"x": 3, "y": 4}]}
+        result = toon.encode_minified(data)
+
+        assert result == "rows[2]{x,y}:\n1,2\n3,4"
+        assert toon.decode(result) == data
+
+    def test_minified_nested_roundtrip(self):
+        """Test nested blocks keep a one-space indent announced by a directive."""
+        data = {"a": {"b": 1}, "c": 2, "items": [{"id": 1, "tags": ["x"]}, [1, {"d": 2}]]}
+        result = toon.encode_minified(data)
+
+        assert result.startswith("#!toon indent=1\na:\n b: 1\nc: 2\n")
+        assert toon.decode(result) == data
+
+    def test_blank_lines_requires_indentation(self):
+        """Test blank_lines cannot be combined with compact mode."""
+        from toonverter.core.spec import ToonEncodeOptions
+
+        with pytest.raises(ValueError, match="blank_lines"):
+            ToonEncodeOptions(indent_size=0, blank_lines=True)
+
+
+class TestLineEnding:
+    """Test the line_ending encode option."""
+
+    DATA = {"name": "a\nb", "rows": [{"x": 1, "y": 2}, {"x": 3, "y": 4}], "n": {"m": [1]}}
+
+    def test_lf_by_default(self):
+        """Test output uses bare newlines unless asked otherwise."""
+        result = toon.encode(self.DATA)
+
+        assert "\r" not in result
+        assert result == toon.encode(self.DATA, line_ending="lf")
+
+    def test_crlf(self):
+        """Test every separator becomes CRLF, escaped newlines in values stay escaped."""
+        lf = toon.encode(self.DATA)
+        crlf = toon.encode(self.DATA, line_ending="crlf")
+
+        assert crlf == lf.replace("\n", "\r\n")
+        assert crlf.startswith('name: "a\\nb"\r\nrows[2]{x,y}:\r\n  1,2\r\n')
+        assert toon.decode(crlf) == self.DATA
+
+    def test_crlf_with_options_object(self):
+        """Test EncodeOptions carries line_ending through to the encoder."""
+        options = toon.EncodeOptions(line_ending="crlf", blank_lines=True)
+
+        assert toon.encode({"a": 1, "b": 2}, options=options) == "a: 1\r\n\r\nb: 2"
+
+    def test_crlf_streaming(self):
+        """Test the streaming encoder writes the same CRLF output."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.encoders import ToonStreamEncoder
+
+        encoder = ToonStreamEncoder(ToonEncodeOptions(line_ending="crlf"))
+        data = {"a": {"b": 1}, "c": [1, 2]}
+        rows = encoder.iterencode_rows(iter([{"id": 1}, {"id": 2}]), 2, key="t")
+
+        assert "".join(encoder.iterencode(data)) == "a:\r\n  b: 1\r\nc[2]:\r\n  - 1\r\n  - 2"
+        assert "".join(rows) == "t[2]{id}:\r\n  1\r\n  2"
+
+    def test_invalid_line_ending_rejected(self):
+        """Test unknown line endings are rejected."""
+        with pytest.raises(ValueError, match="line_ending"):
+            toon.encode({"a": 1}, line_ending="cr")
+
+
+class TestFingerprint:
+    """Test the fingerprint encode option and verify_toon_fingerprint."""
+
+    DATA = {"name": "Ada", "rows": [{"x": 1, "y": 2}, {"x": 3, "y": 4}]}
+
+    def test_trailer_records_options(self):
+        """Test the trailer names version, delimiter and indent, and verifies."""
+        text = toon.encode(self.DATA, fingerprint=True, delimiter="|", indent=4)
+        report = toon.verify_toon_fingerprint(text)
+
+        assert text.splitlines()[-1].startswith(
+            f"# toonverter: v={toon.__version__} delimiter=| indent=4 sha256="
+        )
+        assert report["fingerprinted"] is True
+        assert report["valid"] is True
+        assert (report["version"], report["delimiter"], report["indent"]) == (
+            toon.__version__,
+            "|",
+            4,
+        )
+
+    def test_decoding_unaffected(self):
+        """Test the trailer is an ordinary comment for the decoder."""
+        for data in (self.DATA, [1, 2], "x", {}):
+            assert toon.decode(toon.encode(data, fingerprint=True)) == data
+
+    def test_tampered_content_detected(self):
+        """Test any edit above the trailer invalidates it."""
+        text = toon.encode(self.DATA, fingerprint=True)
+        tampered = text.replace("3,4", "3,5")
+
+        assert toon.verify_toon_fingerprint(tampered)["valid"] is False
+        assert toon.verify_toon_fingerprint(tampered)["fingerprinted"] is True
+        assert toon.verify_toon_fingerprint(text + "\n")["valid"] is True
+
+    def test_missing_trailer(self):
+        """Test documents without a trailer are reported as not fingerprinted."""
+        report = toon.verify_toon_fingerprint(toon.encode(self.DATA))
+
+        assert report == {
+            "fingerprinted": False,
+            "valid": False,
+            "version": None,
+            "delimiter": None,
+            "indent": None,
+            "sha256": None,
+        }
+
+    def test_crlf(self):
+        """Test the hash covers the CRLF output as written."""
+        text = toon.encode(self.DATA, fingerprint=True, line_ending="crlf", delimiter="\t")
+
+        assert toon.verify_toon_fingerprint(text + "\r\n")["valid"] is True
+        assert toon.verify_toon_fingerprint(text)["delimiter"] == "\t"
+
+    def test_reindent_refreshes_fingerprint(self):
+        """Test re-encoding a fingerprinted file writes a new, valid trailer."""
+        text = toon.encode(self.DATA, fingerprint=True)
+        reindented = toon.reindent_toon(text, None, 4, fingerprint=True)
+        report = toon.verify_toon_fingerprint(reindented)
+
+        assert reindented.count("# toonverter:") == 1
+        assert report["valid"] is True
+        assert report["indent"] == 4
+        assert toon.decode(reindented, indent_size=4) == self.DATA
+
+
+class TestTrailingNewline:
+    """Test the trailing_newline encode option across string, file and stream output."""
+
+    # Shapes both encoders write the same way (the stream encoder has no
+    # inline or tabular arrays)
+    SAME_SHAPE = [
+        {"a": 1, "b": {"c": None, "d": {"e": "x"}}},
+        {"a": {}, "b": []},
+        [],
+        42,
+        "text",
+    ]
+
+    def test_off_by_default(self):
+        """Test output ends at the last line unless asked otherwise."""
+        assert toon.encode({"a": 1}) == "a: 1"
+
+    @pytest.mark.parametrize(("line_ending", "end"), [("lf", "\n"), ("crlf", "\r\n")])
+    def test_ends_with_line_ending(self, line_ending, end):
+        """Test the terminator follows the configured line ending."""
+        result = toon.encode({"a": 1, "b": 2}, trailing_newline=True, line_ending=line_ending)
+
+        assert result == f"a: 1{end}b: 2{end}"
+        assert toon.decode(result) == {"a": 1, "b": 2}
+
+    def test_empty_document_stays_empty(self):
+        """Test an empty object is still written as no bytes at all."""
+        assert toon.encode({}, trailing_newline=True) == ""
+
+    def test_after_fingerprint(self):
+        """Test the terminator follows the fingerprint trailer, which still verifies."""
+        result = toon.encode({"a": 1}, fingerprint=True, trailing_newline=True)
+
+        assert result.endswith("\n")
+        assert result.splitlines()[-1].startswith("# toonverter:")
+        assert toon.verify_toon_fingerprint(result)["valid"] is True
+
+    def test_save_writes_encoded_bytes(self, tmp_path):
+        """Test a saved file holds exactly the bytes of the encoded string."""
+        path = tmp_path / "out.toon"
+        data = {"rows": [{"x": 1}, {"x": 2}], "name": "Ada"}
+
+        toon.save(data, str(path), format="toon", trailing_newline=True)
+
+        expected = toon.encode(data, trailing_newline=True).encode("utf-8")
+        assert path.read_bytes() == expected
+
+    @pytest.mark.parametrize("trailing_newline", [False, True])
+    @pytest.mark.parametrize("line_ending", ["lf", "crlf"])
+    def test_stream_matches_string_encoder(self, trailing_newline, line_ending):
+        """Test the streaming encoder ends in the same bytes as the string encoder."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.encoders import ToonStreamEncoder
+
+        options = ToonEncodeOptions(trailing_newline=trailing_newline, line_ending=line_ending)
+        for data in [*self.SAME_SHAPE, {}]:
+            streamed = "".join(ToonStreamEncoder(options).iterencode(data))
+            assert streamed == toon.ToonEncoder(options).encode(data), data
+
+    def test_stream_rows(self):
+        """Test iterencode_rows is terminated too."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.encoders import ToonStreamEncoder
+
+        encoder = ToonStreamEncoder(ToonEncodeOptions(trailing_newline=True))
+        rows = encoder.iterencode_rows(iter([{"id": 1}, {"id": 2}]), 2, key="t")
+
+        assert "".join(rows) == "t[2]{id}:\n  1\n  2\n"
+
+
+class TestOptionsObjects:
+    """Test passing option objects to the facade functions."""
+
+    def test_encode_options_to_dict_roundtrip(self):
+        """Test EncodeOptions survives a to_dict() round-trip."""
+        options = toon.EncodeOptions(indent=4, delimiter="|", nonfinite="error")
+        assert toon.EncodeOptions(**options.to_dict()) == options
+
+    def test_decode_options_to_dict_roundtrip(self):
+        """Test DecodeOptions survives a to_dict() round-trip."""
+        options = toon.DecodeOptions(strict=False, intern_strings=True)
+        assert toon.DecodeOptions(**options.to_dict()) == options
+
+    def test_encode_float_precision(self):
+        """Test float_precision is honored by the facade and tabular rows."""
+        data = {"total": 0.1 + 0.2, "rows": [{"x": 1 / 3}, {"x": 2 / 3}]}
+        result = toon.encode(data, float_precision=2)
+        assert result == "total: 0.3\nrows[2]{x}:\n  0.33\n  0.67"
+
+    def test_float_precision_must_be_positive(self):
+        """Test float_precision below 1 is rejected."""
+        with pytest.raises(ValueError, match="float_precision"):
+            toon.encode({"a": 1.5}, float_precision=0)
+
+    def test_encode_with_options_object(self):
+        """Test encode accepts an options object."""
+        options = toon.EncodeOptions(delimiter="|")
+        assert toon.encode({"a": [1, 2]}, options=options) == "a[2|]: 1|2"
+
+    def test_encode_keywords_override_object(self):
+        """Test explicit keywords take precedence over the options object."""
+        options = toon.EncodeOptions(delimiter="|", indent=4)
+        result = toon.encode({"a": {"b": [1, 2]}}, options=options, delimiter=",")
+
+        assert result == "a:\n    b[2]: 1,2"
+        assert options.delimiter == "|"  # object is not mutated
+
+    def test_decode_keywords_override_object(self):
+        """Test decode keywords take precedence over the options object."""
+        options = toon.DecodeOptions(strict=True)
+        with pytest.raises(toon.ValidationError):
+            toon.decode("[3]: 1,2", options=options)
+        assert toon.decode("[3]: 1,2", options=options, strict=False) == [1, 2]
+
+    def test_toon_specific_options_object(self):
+        """Test spec-level option objects are accepted for TOON."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(intern_strings=True)
+        result = toon.decode("[2]: " + "q" * 40 + "," + "q" * 40, options=options)
+        assert result[0] is result[1]
+
+    def test_toon_options_object_with_overrides(self):
+        """Test keywords on a ToonEncodeOptions use its own field names."""
+        from toonverter.core.spec import ToonEncodeOptions
+
+        options = ToonEncodeOptions(indent_size=4)
+        data = {"a": {"b": [1, 2]}}
+
+        assert toon.encode(data, options=options, delimiter="|") == "a:\n    b[2|]: 1|2"
+        assert toon.encode(data, options=options, indent_size=1) == "a:\n b[2]: 1,2"
+
+    def test_unknown_override_for_options_class(self):
+        """Test a keyword the options class lacks raises a clear TypeError."""
+        from toonverter.core.spec import ToonDecodeOptions, ToonEncodeOptions
+
+        with pytest.raises(TypeError, match="ToonEncodeOptions has no option"):
+            toon.encode({"a": 1}, options=ToonEncodeOptions(), indent=3)
+        with pytest.raises(TypeError, match="ToonDecodeOptions has no option"):
+            toon.decode("a: 1", options=ToonDecodeOptions(), delimiter="|")
+        with pytest.raises(TypeError, match="EncodeOptions has no option"):
+            toon.encode({"a": 1}, indent_size=3)
+
+    def test_toon_options_for_other_format(self):
+        """Test TOON spec options given for another format raise TypeError."""
+        from toonverter.core.spec import ToonDecodeOptions, ToonEncodeOptions
+
+        with pytest.raises(TypeError, match="use EncodeOptions for 'json'"):
+            toon.encode({"a": 1}, to_format="json", options=ToonEncodeOptions())
+        with pytest.raises(TypeError, match="use DecodeOptions for 'json'"):
+            toon.decode('{"a": 1}', from_format="json", options=ToonDecodeOptions())
+
+
 class TestLoadSave:
     """Test load/save functions."""
 
@@ -172,6 +636,48 @@ class TestConvert:
         assert result.source_tokens > 0
         assert result.target_tokens > 0
 
+    def test_convert_preserves_big_integers(self, tmp_path):
+        """Test JSON -> TOON -> JSON keeps integers beyond 64 bits exactly."""
+        source = tmp_path / "source.json"
+        middle = tmp_path / "data.toon"
+        target = tmp_path / "target.json"
+        digits = "123456789012345678901234567890"
+        source.write_text(f'{{"id": {digits}, "rows": [{{"v": 18446744073709551617}}]}}')
+
+        assert toon.convert(str(source), str(middle), "json", "toon").success
+        assert toon.convert(str(middle), str(target), "toon", "json").success
+
+        assert digits in middle.read_text()
+        assert digits in target.read_text()
+        assert "18446744073709551617" in target.read_text()
+
+    def test_convert_append(self, tmp_path):
+        """Test two conversions appended to one file are both present."""
+        first = tmp_path / "first.json"
+        second = tmp_path / "second.json"
+        target = tmp_path / "log.toon"
+        first.write_text('[{"id": 1, "msg": "start"}, {"id": 2, "msg": "ok"}]')
+        second.write_text('[{"id": 3, "msg": "stop"}]')
+
+        assert toon.convert(str(first), str(target), "json", "toon", append=True).success
+        assert toon.convert(str(second), str(target), "json", "toon", append=True).success
+
+        content = target.read_text()
+        assert content == "[2]{id,msg}:\n  1,start\n  2,ok\n[1]{id,msg}:\n  3,stop"
+        first_doc, second_doc = content.split("\n[1]")
+        assert toon.decode(first_doc) == [{"id": 1, "msg": "start"}, {"id": 2, "msg": "ok"}]
+        assert toon.decode("[1]" + second_doc) == [{"id": 3, "msg": "stop"}]
+
+    def test_convert_without_append_overwrites(self, tmp_path):
+        """Test the default still replaces an existing target."""
+        source = tmp_path / "source.json"
+        target = tmp_path / "out.toon"
+        source.write_text('{"a": 1}')
+        target.write_text("old: content")
+
+        assert toon.convert(str(source), str(target), "json", "toon").success
+        assert target.read_text() == "a: 1"
+
     def test_convert_failure(self):
         """Test convert handles errors."""
         result = toon.convert("nonexistent.json", "out.json", "json", "json")
@@ -179,6 +685,52 @@ class TestConvert:
         assert result.success is False
         assert result.error is not None
 
+    def test_convert_stream_json_to_toon_bytes(self):
+        """Test converting between binary in-memory buffers."""
+        import io
+
+        writer = io.BytesIO()
+        result = toon.convert_stream(
+            io.BytesIO(b'{"name": "J\xc3\xbcrgen", "ids": [1, 2]}'), writer, "json", "toon"
+        )
+
+        assert result.success is True
+        assert result.data == {"name": "Jürgen", "ids": [1, 2]}
+        assert result.source_tokens > 0
+        assert writer.getvalue().decode("utf-8") == "name: Jürgen\nids[2]: 1,2"
+
+    def test_convert_stream_toon_to_json_text(self):
+        """Test converting between text in-memory buffers."""
+        import io
+        import json
+
+        writer = io.StringIO()
+        result = toon.convert_stream(io.StringIO("rows[2]{a}:\n  1\n  2"), writer, "toon", "json")
+
+        assert result.success is True
+        assert json.loads(writer.getvalue()) == {"rows": [{"a": 1}, {"a": 2}]}
+
+    def test_convert_stream_reader_error(self):
+        """Test a reader failing midway yields a failed result, not an exception."""
+        import io
+
+        class FlakyReader:
+            def __init__(self):
+                self.calls = 0
+
+            def read(self, size):
+                self.calls += 1
+                if self.calls > 1:
+                    raise OSError("connection reset")
+                return '{"a": '
+
+        writer = io.StringIO()
+        result = toon.convert_stream(FlakyReader(), writer, "json", "toon")
+
+        assert result.success is False
+        assert "Failed to read stream: connection reset" in result.error
+        assert writer.getvalue() == ""
+
 
 class TestAnalyze:
     """Test analyze function."""
@@ -234,3 +786,48 @@ class TestEdgeCases:
         # Note: This depends on implementation
         assert toon.is_supported("json") is True
         assert toon.is_supported("TOON") is True or toon.is_supported("toon") is True
+
+
+class TestRootValues:
+    """Test the round-trip contract for scalar and empty roots."""
+
+    @pytest.mark.parametrize(
+        ("value", "expected"),
+        [
+            (42, "42"),
+            (-7, "-7"),
+            (1.5, "1.5"),
+            (True, "true"),
+            (False, "false"),
+            (None, "null"),
+            ("hello", "hello"),
+            ("", '""'),
+            ("42", '"42"'),
+            ("null", '"null"'),
+            ([], "[0]:"),
+            ({}, ""),
+        ],
+    )
+    def test_round_trip(self, value, expected):
+        """Test each root encodes to its own form and decodes to the same type."""
+        encoded = toon.encode(value)
+        decoded = toon.decode(encoded)
+
+        assert encoded == expected
+        assert decoded == value
+        assert type(decoded) is type(value)
+
+    def test_integral_float_decodes_as_int(self):
+        """Test a float with no fraction comes back as an int."""
+        assert toon.encode(2.0) == "2"
+        assert toon.decode("2") == 2
+
+    @pytest.mark.parametrize("text", ["[0]:", "[0]:\n", "\n[0]:", "# list\n[0]:"])
+    def test_empty_list_is_not_empty_document(self, text):
+        """Test "[0]:" decodes to an empty list wherever it sits in the input."""
+        assert toon.decode(text) == []
+
+    @pytest.mark.parametrize("text", ["", "  ", "\n\n", "# only a comment"])
+    def test_empty_document_is_empty_dict(self, text):
+        """Test input with no content decodes to an empty object."""
+        assert toon.decode(text) == {}
@@ -231,6 +231,46 @@ class TestEncodeTabular:
         assert result[0] == "data[1|]{a|b}:"
         assert result[1] == "  1|2"
 
+    def test_encode_tabular_quotes_field_containing_delimiter(self):
+        """Test field names containing the delimiter are quoted in the header."""
+        arr = [{"a,b": 1, "c": 2}, {"a,b": 3, "c": 4}]
+        result = self.encoder.encode_tabular("data", arr, 0)
+
+        assert result[0] == 'data[2]{"a,b",c}:'
+        assert result[1] == "  1,2"
+
+    def test_encode_tabular_quotes_field_containing_pipe_delimiter(self):
+        """Test only the active delimiter forces quoting of field names."""
+        encoder = ArrayEncoder(StringEncoder(Delimiter.PIPE), NumberEncoder(), IndentationManager())
+        arr = [{"a,b": 1, "c|d": 2}]
+        result = encoder.encode_tabular("data", arr, 0)
+
+        assert result[0] == 'data[1|]{a,b|"c|d"}:'
+
+
+class TestEncodeList:
+    """Test list array encoding of object items."""
+
+    def setup_method(self):
+        """Set up test fixtures."""
+        from toonverter.encoders import ToonEncoder
+
+        self.value_encoder = ToonEncoder()
+        self.encoder = self.value_encoder.array_enc
+
+    def test_object_item_first_field_on_dash_line(self):
+        """Test object items put the first field on the dash line."""
+        arr = [{"name": "a", "age": 1}, 3]
+        result = self.encoder.encode_list("items", arr, 0, self.value_encoder)
+
+        assert result == ["items[2]:", "  - name: a", "    age: 1", "  - 3"]
+
+    def test_empty_object_item(self):
+        """Test empty object items encode as a bare dash."""
+        result = self.encoder.encode_list("items", [{}, 1], 0, self.value_encoder)
+
+        assert result == ["items[2]:", "  -", "  - 1"]
+
 
 class TestEncodeValue:
     """Test value encoding."""
@@ -313,5 +353,185 @@ class TestEdgeCases:
         """Test detecting array of empty dicts."""
         arr = [{}, {}]
         result = self.encoder.detect_array_form(arr)
-        # Empty dicts have same keys (none), so should be tabular
-        assert result == ArrayForm.TABULAR
+        # Empty dicts have no fields for a tabular header, so use list form
+        assert result == ArrayForm.LIST
+
+
+class TestAlignColumns:
+    """Test padding tabular cells so columns line up."""
+
+    def setup_method(self):
+        """Set up test fixtures."""
+        self.encoder = ArrayEncoder(
+            StringEncoder(Delimiter.COMMA), NumberEncoder(), IndentationManager(), True
+        )
+
+    def test_cells_padded_to_column_width(self):
+        """Test every cell but the last is padded to its column's widest cell."""
+        arr = [
+            {"id": 1, "name": "Alice", "role": "admin"},
+            {"id": 100, "name": "Bob", "role": "x"},
+        ]
+        result = self.encoder.encode_tabular("users", arr, 0)
+
+        assert result == ["users[2]{id,name,role}:", "  1  ,Alice,admin", "  100,Bob  ,x"]
+
+    def test_quoted_cells_padded_outside_quotes(self):
+        """Test padding goes after the closing quote of a quoted cell."""
+        result = self.encoder.encode_root_array_tabular([{"a": "x y", "b": 1}, {"a": "z", "b": 2}])
+        assert result == ["[2]{a,b}:", '  "x y",1', "  z    ,2"]
+
+    def test_round_trip(self):
+        """Test aligned output decodes back to the original values."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.decoders import decode
+        from toonverter.encoders import encode
+
+        data = {
+            "rows": [
+                {"id": 1, "name": "Ada Lovelace", "score": 9.5, "ok": True, "note": None},
+                {"id": 22, "name": "", "score": -1, "ok": False, "note": "a,b"},
+            ]
+        }
+        for delimiter in (Delimiter.COMMA, Delimiter.PIPE, Delimiter.TAB):
+            toon_str = encode(data, ToonEncodeOptions(align_columns=True, delimiter=delimiter))
+            assert decode(toon_str) == data
+
+    def test_facade_option(self):
+        """Test align_columns is accepted by the facade encode function."""
+        import toonverter as toon
+
+        result = toon.encode([{"k": "long", "v": 1}, {"k": "s", "v": 2}], align_columns=True)
+        assert result == "[2]{k,v}:\n  long,1\n  s   ,2"
+
+
+class TestColumnEncoding:
+    """Test delta and run-length encoded tabular columns."""
+
+    def setup_method(self):
+        """Set up test fixtures."""
+        self.encoder = ArrayEncoder(
+            StringEncoder(Delimiter.COMMA),
+            NumberEncoder(),
+            IndentationManager(),
+            column_encoding={"ts": "delta", "status": "rle"},
+        )
+
+    def test_header_annotations_and_cells(self):
+        """Test annotated fields, deltas and elided repeats."""
+        arr = [
+            {"ts": 100, "status": "ok", "v": 1},
+            {"ts": 110, "status": "ok", "v": 2},
+            {"ts": 105, "status": "down", "v": 3},
+        ]
+        result = self.encoder.encode_tabular("rows", arr, 0)
+
+        assert result == [
+            "rows[3]{ts:+delta,status:*rle,v}:",
+            "  100,ok,1",
+            "  10,,2",
+            "  -5,down,3",
+        ]
+
+    def test_nulls(self):
+        """Test nulls keep the delta base and repeat like any value in rle columns."""
+        arr = [
+            {"status": None, "ts": 5},
+            {"status": None, "ts": None},
+            {"status": "a", "ts": 7},
+        ]
+        result = self.encoder.encode_root_array_tabular(arr)
+
+        assert result == ["[3]{status:*rle,ts:+delta}:", "  null,5", "  ,null", "  a,2"]
+
+    def test_repeats_written_where_empty_cell_would_be_lost(self):
+        """Test a lone column and the edges of tab rows never elide a repeat."""
+        result = self.encoder.encode_root_array_tabular([{"status": "a"}, {"status": "a"}])
+        assert result == ["[2]{status:*rle}:", "  a", "  a"]
+
+        encoder = ArrayEncoder(
+            StringEncoder(Delimiter.TAB),
+            NumberEncoder(),
+            IndentationManager(),
+            column_encoding={"a": "rle", "b": "rle", "c": "rle"},
+        )
+        result = encoder.encode_root_array_tabular([{"a": 0, "b": 0, "c": 0}] * 2)
+        assert result[1:] == ["  0\t0\t0", "  0\t\t0"]
+
+    def test_rle_distinguishes_types(self):
+        """Test 1, 1.0 and true are not repeats of each other."""
+        arr = [{"status": 1, "v": 0}, {"status": 1.0, "v": 0}, {"status": True, "v": 0}]
+        result = self.encoder.encode_root_array_tabular(arr)
+        assert result[1:] == ["  1,0", "  1,0", "  true,0"]
+
+    def test_delta_rejects_non_integers(self):
+        """Test a delta column holding a float or bool is rejected."""
+        import pytest
+
+        from toonverter.core.exceptions import ValidationError
+
+        for bad in (1.5, True, "x"):
+            with pytest.raises(ValidationError, match="row 2 holds"):
+                self.encoder.encode_root_array_tabular([{"ts": 1}, {"ts": bad}])
+
+    def test_aligned(self):
+        """Test align_columns pads the encoded cells."""
+        encoder = ArrayEncoder(
+            StringEncoder(Delimiter.COMMA),
+            NumberEncoder(),
+            IndentationManager(),
+            True,
+            {"ts": "delta"},
+        )
+        result = encoder.encode_root_array_tabular([{"ts": 1000, "v": 1}, {"ts": 1001, "v": 2}])
+        assert result == ["[2]{ts:+delta,v}:", "  1000,1", "  1   ,2"]
+
+        encoder.column_encoding = {"s": "rle", "t": "rle"}
+        result = encoder.encode_root_array_tabular([{"s": "ab", "t": 1, "v": 0}] * 2)
+        # A padded empty first cell would read as indentation
+        assert result == ["[2]{s:*rle,t:*rle,v}:", "  ab,1,0", "  ab, ,0"]
+
+    def test_round_trip(self):
+        """Test encoded columns decode back to the original values."""
+        from toonverter.core.spec import ToonDecodeOptions, ToonEncodeOptions
+        from toonverter.decoders import decode
+        from toonverter.encoders import encode
+
+        data = {
+            "rows": [
+                {"status": "ok", "ts": -3, "n": None},
+                {"status": "ok", "ts": -10, "n": 1},
+                {"status": None, "ts": None, "n": 1},
+                {"status": None, "ts": 0, "n": 1},
+                {"status": "ok", "ts": 2**70, "n": 1},
+            ]
+        }
+        column_encoding = {"ts": "delta", "status": "rle", "n": "rle"}
+        for delimiter in (Delimiter.COMMA, Delimiter.PIPE, Delimiter.TAB):
+            for align in (False, True):
+                options = ToonEncodeOptions(
+                    column_encoding=column_encoding, delimiter=delimiter, align_columns=align
+                )
+                toon_str = encode(data, options)
+                assert decode(toon_str, ToonDecodeOptions(column_encoding=True)) == data
+
+    def test_unencoded_fields_untouched(self):
+        """Test tables without an encoded field keep the plain header."""
+        result = self.encoder.encode_root_array_tabular([{"a": 1}, {"a": 1}])
+        assert result == ["[2]{a}:", "  1", "  1"]
+
+    def test_facade_option(self):
+        """Test column_encoding is accepted by the facade encode function."""
+        import toonverter as toon
+
+        result = toon.encode([{"t": 1, "k": 0}, {"t": 3, "k": 0}], column_encoding={"t": "delta"})
+        assert result == "[2]{t:+delta,k}:\n  1,0\n  2,0"
+
+    def test_invalid_encoding_rejected(self):
+        """Test unknown encodings are rejected when the options are built."""
+        import pytest
+
+        from toonverter.core.spec import ToonEncodeOptions
+
+        with pytest.raises(ValueError, match="column_encoding values must be"):
+            ToonEncodeOptions(column_encoding={"t": "zigzag"})
@@ -0,0 +1,120 @@
+"""Tests for contract version and capability reporting."""
+
+from dataclasses import fields
+
+import toonverter as toon
+from toonverter.core import capabilities
+from toonverter.core.spec import ToonDecodeOptions, ToonEncodeOptions
+
+
+# Options that predate capability reporting and are always available
+BASELINE_OPTIONS = {"indent_size", "delimiter", "strict", "optimization_policy"}
+# Features advertised at the current contract version. A change here must come
+# with a CONTRACT_VERSION bump and an entry in the contract history.
+CONTRACT_FEATURES = (
+    "2.0.0",
+    [
+        "align_columns",
+        "as_pairs",
+        "blank_lines",
+        "coerce_string_booleans",
+        "coerce_types",
+        "column_encoding",
+        "comments",
+        "decode_column_encoding",
+        "decode_indent_size",
+        "delimiter_pipe",
+        "delimiter_tab",
+        "duplicate_keys",
+        "empty_list_items",
+        "empty_strings",
+        "enum_mode",
+        "expand_paths",
+        "fingerprint",
+        "float_precision",
+        "int_as_float",
+        "integer_keyed_dict_as_list",
+        "intern_strings",
+        "key_folding",
+        "line_ending",
+        "max_dict_entries",
+        "max_errors",
+        "max_indent_depth",
+        "max_line_length",
+        "max_list_elements",
+        "max_tabular_fields",
+        "missing_cells",
+        "missing_values",
+        "nonfinite_error",
+        "nonfinite_tokens",
+        "normalize_numbers",
+        "on_unsupported",
+        "preserve_negative_zero",
+        "redact_paths",
+        "redact_with",
+        "reserved_literals",
+        "row_errors",
+        "streaming_encode",
+        "streaming_rows",
+        "streaming_rows_encode",
+        "strict_lengths",
+        "string_booleans_case_sensitive",
+        "token_budget",
+        "trailing_newline",
+        "transform",
+        "type_inference",
+    ],
+)
+
+
+class TestCapabilities:
+    """Test get_capabilities() and supports()."""
+
+    def test_capabilities_shape(self):
+        """Test the capability dictionary contents."""
+        caps = toon.get_capabilities()
+
+        assert caps["contract"] == toon.CONTRACT_VERSION
+        assert caps["version"] == toon.__version__
+        assert caps["features"] == sorted(caps["features"])
+        assert set(caps["delimiters"]) == {",", "\t", "|"}
+
+    def test_supports(self):
+        """Test feature detection for known and unknown features."""
+        assert toon.supports("streaming_rows")
+        assert toon.supports("intern_strings")
+        assert not toon.supports("no_such_feature")
+
+    def test_every_feature_is_backed(self):
+        """Test every advertised feature resolves to real code."""
+        for name, (owner, attribute) in capabilities.FEATURES.items():
+            assert hasattr(owner, attribute), f"{name} -> {owner!r}.{attribute} missing"
+
+    def test_every_option_is_advertised(self):
+        """Test new encode/decode options are registered as features."""
+        backed = {
+            attribute
+            for owner, attribute in capabilities.FEATURES.values()
+            if owner in (ToonDecodeOptions, ToonEncodeOptions)
+        }
+        option_names = {f.name for f in fields(ToonDecodeOptions)} | {
+            f.name for f in fields(ToonEncodeOptions)
+        }
+
+        assert option_names - BASELINE_OPTIONS <= backed
+
+    def test_contract_version_tracks_features(self):
+        """Test the feature list only changes together with the contract version."""
+        caps = toon.get_capabilities()
+
+        assert (caps["contract"], caps["features"]) == CONTRACT_FEATURES, (
+            "Features changed: bump CONTRACT_VERSION, add it to the contract "
+            "history and update CONTRACT_FEATURES"
+        )
+
+    def test_contract_history_is_current(self):
+        """Test the module's contract history ends at CONTRACT_VERSION."""
+        history = capabilities.__doc__.split("Contract history:")[1]
+        versions = [line[2:].split(":")[0] for line in history.splitlines() if line[:2] == "- "]
+
+        assert versions[-1] == toon.CONTRACT_VERSION
@@ -201,3 +201,52 @@ class TestCompareFunction:
 
         report = compare(data, ["json"], encode_options=options)
         assert report is not None
+
+
+class TestCompareSizes:
+    """Test compare_sizes() byte-size analytics."""
+
+    def test_tabular_input_saves_half(self):
+        """Test a compact tabular-heavy JSON document shrinks by about half."""
+        import json
+
+        from toonverter.analysis.comparator import compare_sizes
+
+        rows = [
+            {"id": i, "name": f"user{i}", "email": f"user{i}@example.com", "active": i % 2 == 0}
+            for i in range(100)
+        ]
+        result = compare_sizes(json.dumps({"users": rows}))
+
+        assert result["json_bytes"] == 7731
+        assert result["toon_bytes"] == 3653
+        assert result["ratio"] == pytest.approx(3653 / 7731)
+        assert result["savings_percent"] == pytest.approx(52.75, abs=0.01)
+
+    def test_options_change_toon_size(self):
+        """Test indent_size and delimiter are applied to the TOON side."""
+        from toonverter.analysis.comparator import compare_sizes
+
+        text = '{"a": {"b": [1, 2, 3]}}'
+        default = compare_sizes(text)
+        wide = compare_sizes(text, indent_size=4, delimiter="|")
+
+        assert wide["json_bytes"] == default["json_bytes"] == len(text)
+        assert wide["toon_bytes"] == default["toon_bytes"] + 3
+
+    def test_counts_utf8_bytes(self):
+        """Test sizes are UTF-8 byte counts, not characters."""
+        from toonverter.analysis.comparator import compare_sizes
+
+        result = compare_sizes('{"city": "Zürich"}')
+
+        assert result["json_bytes"] == 19
+        assert result["toon_bytes"] == len("city: Zürich".encode())
+
+    def test_invalid_json(self):
+        """Test invalid JSON raises DecodingError."""
+        from toonverter.analysis.comparator import compare_sizes
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match="Invalid JSON"):
+            compare_sizes("{not json")
@@ -88,3 +88,2497 @@ class TestToonDecoder:
         encoded = encode(sample_dict)
         decoded = decode(encoded)
         assert decoded == sample_dict
+
+
+class TestDelimiterRoundtrip:
+    """Round-trip tests for non-comma delimiters and quoted field names."""
+
+    @pytest.mark.parametrize("delimiter", ["comma", "pipe", "tab"])
+    def test_roundtrip_inline_and_tabular(self, delimiter):
+        """Test inline and tabular arrays round-trip under every delimiter."""
+        from toonverter.core.spec import Delimiter, ToonEncodeOptions
+        from toonverter.encoders import encode
+
+        data = {
+            "tags": ["a", "b,c", "d|e"],
+            "rows": [{"id": 1, "name": "x y"}, {"id": 2, "name": "z"}],
+        }
+        options = ToonEncodeOptions(delimiter=Delimiter[delimiter.upper()])
+        assert decode(encode(data, options)) == data
+
+    @pytest.mark.parametrize("delimiter", ["comma", "pipe", "tab"])
+    def test_roundtrip_field_names_containing_delimiter(self, delimiter):
+        """Test tabular field names containing delimiter characters round-trip."""
+        from toonverter.core.spec import Delimiter, ToonEncodeOptions
+        from toonverter.encoders import encode
+
+        data = [{"a,b": 1, "c|d": 2, "e\tf": 3}, {"a,b": 4, "c|d": 5, "e\tf": 6}]
+        options = ToonEncodeOptions(delimiter=Delimiter[delimiter.upper()])
+        assert decode(encode(data, options)) == data
+        assert decode(encode({"rows": data}, options)) == {"rows": data}
+
+    def test_unquoted_delimiter_field_name_is_rejected(self):
+        """Test the ambiguous pre-quoting output fails instead of misaligning."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="Row width mismatch"):
+            decode("[2]{a,b,c}:\n  1,2\n  3,4")
+
+
+LOOKALIKES = [
+    pytest.param("[3]: 1,2,3", id="inline-array-header"),
+    pytest.param("[2]{a,b}:", id="tabular-header"),
+    pytest.param("[1|]: x", id="pipe-array-header"),
+    pytest.param("[2]", id="bracketed-length"),
+    pytest.param("key[2]", id="key-with-length"),
+    pytest.param("- item", id="dash-item"),
+    pytest.param("- [2]: 1,2", id="dash-array-item"),
+    pytest.param("-", id="bare-dash"),
+    pytest.param("{a: 1}", id="inline-object"),
+    pytest.param("{", id="open-brace"),
+    pytest.param("# comment", id="comment"),
+    pytest.param("#!toon indent=4", id="directive"),
+    pytest.param("a: b", id="key-value"),
+    pytest.param("1,2", id="tabular-row"),
+    pytest.param("x|y", id="pipe-row"),
+    pytest.param("a\tb", id="tab-row"),
+    pytest.param("null", id="null"),
+    pytest.param("true", id="true"),
+    pytest.param("-1", id="negative-number"),
+    pytest.param("1e3", id="exponent"),
+]
+
+LOOKALIKE_POSITIONS = {
+    "root-value": lambda s: s,
+    "field-value": lambda s: {"v": s},
+    "field-key": lambda s: {s: 1},
+    "nested-value": lambda s: {"o": {"p": s}},
+    "nested-key": lambda s: {"o": {s: {"x": 1}}},
+    "key-of-array": lambda s: {s: [1, 2]},
+    "key-of-table": lambda s: {s: [{"a": 1}, {"a": 2}]},
+    "key-of-empty-object": lambda s: {s: {}},
+    "list-item": lambda s: {"l": [s, "x"]},
+    "nested-list-item": lambda s: {"l": [[s, s], [s]]},
+    "list-object-key": lambda s: {"l": [{s: 1, "b": [1, 2]}, 3]},
+    "list-object-value": lambda s: {"l": [{"a": s, "b": {"c": 1}}, 3]},
+    "tabular-cell": lambda s: {"t": [{"a": s, "b": 1}, {"a": "z", "b": 2}]},
+    "tabular-field": lambda s: {"t": [{s: 1, "b": 1}, {s: 2, "b": 2}]},
+}
+
+
+class TestStructuralLookalikeRoundtrip:
+    """Round-trip tests for strings that mimic TOON syntax, as keys and values."""
+
+    @pytest.mark.parametrize("delimiter", ["comma", "pipe", "tab"])
+    @pytest.mark.parametrize("position", list(LOOKALIKE_POSITIONS))
+    @pytest.mark.parametrize("text", LOOKALIKES)
+    def test_roundtrip(self, text, position, delimiter):
+        """Test every lookalike survives encoding in every position and delimiter."""
+        from toonverter.core.spec import Delimiter, ToonEncodeOptions
+        from toonverter.encoders import ToonStreamEncoder, encode
+
+        data = LOOKALIKE_POSITIONS[position](text)
+        options = ToonEncodeOptions(delimiter=Delimiter[delimiter.upper()])
+        assert decode(encode(data, options)) == data
+        assert decode("".join(ToonStreamEncoder(options).iterencode(data))) == data
+
+    def test_comma_value_under_pipe_delimiter(self):
+        """Test a comma in a field value is quoted, since fields always split on commas."""
+        from toonverter.core.spec import Delimiter, ToonEncodeOptions
+        from toonverter.encoders import encode
+
+        options = ToonEncodeOptions(delimiter=Delimiter.PIPE)
+        assert encode({"v": "1,2", "l": ["a,b"]}, options) == 'v: "1,2"\nl[1|]: a,b'
+        assert encode({"l": [{"a": "1,2"}, "x,y"]}, options) == (
+            'l[2]:\n  - a: "1,2"\n  - "x,y"'
+        )
+
+    def test_literal_keys_stay_keys(self):
+        """Test reserved-word and numeric keys before a colon or array header are keys."""
+        assert decode("null: 1\ntrue:\n  x: 2") == {"null": 1, "true": {"x": 2}}
+        assert decode("-1[2]: 1,2\n1e3[1]{a}:\n  x") == {"-1": [1, 2], "1e3": [{"a": "x"}]}
+        assert decode("l[1]:\n  - false: 1") == {"l": [{"false": 1}]}
+
+    def test_literal_tabular_fields_are_quoted(self):
+        """Test tabular field names that read as literals are quoted in the header."""
+        from toonverter.encoders import encode
+
+        data = {"t": [{"null": 1, "-1": 2}]}
+        assert encode(data) == 't[1]{"null","-1"}:\n  1,2'
+        assert decode(encode(data)) == data
+
+    def test_literal_values_keep_their_type(self):
+        """Test a bare literal followed by a delimiter is still a value, not a key."""
+        assert decode("v: true\nl[3]: null,1e3,-1") == {"v": True, "l": [None, 1000.0, -1]}
+
+
+class TestBigIntegers:
+    """Round-trip tests for integers beyond the 64-bit range."""
+
+    BIG = 2**70
+
+    @pytest.mark.parametrize("delimiter", ["comma", "pipe", "tab"])
+    def test_tabular_inline_and_field_values(self, delimiter):
+        """Test big integers keep every digit in cells, inline lists and fields."""
+        from toonverter.core.spec import Delimiter, ToonEncodeOptions
+        from toonverter.encoders import encode
+
+        data = {
+            "rows": [{"id": self.BIG + i, "n": i} for i in range(3)],
+            "mixed": [1, -(2**80), self.BIG, 2**63],
+            "value": 2**63,
+        }
+        toon_str = encode(data, ToonEncodeOptions(delimiter=Delimiter[delimiter.upper()]))
+
+        assert str(self.BIG) in toon_str
+        result = decode(toon_str)
+        assert result == data
+        assert all(type(row["id"]) is int for row in result["rows"])
+
+    def test_literals_past_int64_decode_exactly(self):
+        """Test literals just past the int64 range stay exact ints."""
+        assert decode("x: 9223372036854775808") == {"x": 9223372036854775808}
+        assert decode("[2]: -1208925819614629174706176,18446744073709551617") == [
+            -(2**80),
+            2**64 + 1,
+        ]
+
+    def test_exponent_literals_decode_as_float(self):
+        """Test exponent literals are floats while plain digits stay exact ints."""
+        result = decode("a: 1e20\nb: -2.5E-3\nc: 1e+2\nd: 100000000000000000000")
+        assert result == {"a": 1e20, "b": -0.0025, "c": 100.0, "d": 10**20}
+        assert type(result["a"]) is float
+        assert type(result["d"]) is int
+        assert decode("[3]: 1e3,2e,e5") == [1000.0, "2e", "e5"]
+
+    def test_exponent_like_strings_and_keys_round_trip(self):
+        """Test quoted exponent strings and numeric-looking keys keep their text."""
+        from toonverter.encoders import encode
+
+        data = {"code": "1e20", "1e5": 1, "7": "x", "n": 1e20}
+        assert decode(encode(data)) == data
+
+    @pytest.mark.parametrize("length", ["1e400", "1e3", "2.0"])
+    def test_exponent_array_length_rejected(self, length):
+        """Test an array length must be plain digits, in the decoder and the stream decoder."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.decoders import StreamDecoder
+
+        with pytest.raises(DecodingError, match="Expected array length"):
+            decode(f"n[{length}]: 1")
+        with pytest.raises(DecodingError, match="Expected array length"):
+            list(StreamDecoder().decode_stream(iter([f"[{length}]: 1\n"])))
+
+    def test_stream_decoder(self):
+        """Test the streaming decoder keeps big integers exact."""
+        from toonverter.decoders import StreamDecoder
+
+        lines = iter(["[2]:\n", f"  - id: {self.BIG}\n", f"  - {-self.BIG}\n"])
+        assert list(StreamDecoder().decode_stream(lines)) == [{"id": self.BIG}, -self.BIG]
+
+
+class TestCoerceStringBooleans:
+    """Test the coerce_string_booleans decode option."""
+
+    def test_quoted_booleans_coerced(self):
+        """Test quoted "true"/"false" fields, cells and list items become booleans."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        toon_str = 'a: "true"\nrows[2]{id,ok}:\n  1,"false"\n  2,"TRUE"\ntags[2]: "False",x'
+        result = decode(toon_str, ToonDecodeOptions(coerce_string_booleans=True))
+
+        assert result == {
+            "a": True,
+            "rows": [{"id": 1, "ok": False}, {"id": 2, "ok": True}],
+            "tags": [False, "x"],
+        }
+
+    def test_case_sensitive(self):
+        """Test only lowercase spellings are coerced when case-sensitive."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(
+            coerce_string_booleans=True, string_booleans_case_sensitive=True
+        )
+        assert decode('[3]: "true",TRUE,"False"', options) == [True, "TRUE", "False"]
+
+    def test_other_strings_and_keys_untouched(self):
+        """Test near-miss values and keys named true are left alone."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        result = decode('"true": " true"\nb: yes', ToonDecodeOptions(coerce_string_booleans=True))
+        assert result == {"true": " true", "b": "yes"}
+
+    def test_default_keeps_strings(self):
+        """Test quoted booleans stay strings by default."""
+        assert decode('a: "true"') == {"a": "true"}
+
+    def test_facade_and_stream_decoder(self):
+        """Test the option through the facade and the streaming decoder."""
+        import toonverter as toon
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import StreamDecoder
+
+        assert toon.decode('k: "false"', coerce_string_booleans=True) == {"k": False}
+        stream = StreamDecoder(ToonDecodeOptions(coerce_string_booleans=True))
+        assert list(stream.decode_stream(iter(["[2]:\n", '  - "true"\n', "  - n\n"]))) == [
+            True,
+            "n",
+        ]
+
+
+class TestNonfiniteTokens:
+    """Test unquoted nan/inf values."""
+
+    @pytest.mark.parametrize("text", ["inf", "nan", "NaN", "-inf", "+Infinity"])
+    def test_kept_as_strings_by_default(self, text):
+        """Test bare non-finite spellings decode as strings, never floats."""
+        assert decode(f"x: {text}") == {"x": text}
+
+    def test_round_trip_does_not_lose_data(self):
+        """Test "nan" survives decode and encode unchanged."""
+        from toonverter.encoders import encode
+
+        data = decode("x: nan\ny[2]: inf,1")
+        assert data == {"x": "nan", "y": ["inf", 1]}
+        assert decode(encode(data)) == data
+
+    @pytest.mark.parametrize("text", ["x: inf", "x: nan", "x[2]: 1,NaN", "r[1]{a}:\n  -Infinity"])
+    def test_error_mode_rejects(self, text):
+        """Test nonfinite_tokens="error" rejects bare non-finite values."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        decoder = ToonDecoder(ToonDecodeOptions(nonfinite_tokens="error"))
+        with pytest.raises(DecodingError, match="Unquoted non-finite number"):
+            decoder.decode(text)
+
+    def test_error_mode_allows_quoted_keys_and_words(self):
+        """Test quoted values, keys and longer words are unaffected."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        decoder = ToonDecoder(ToonDecodeOptions(nonfinite_tokens="error"))
+        assert decoder.decode('x: "nan"\nnan: infinite') == {"x": "nan", "nan": "infinite"}
+
+    def test_invalid_option(self):
+        """Test unknown modes are rejected."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="nonfinite_tokens"):
+            ToonDecodeOptions(nonfinite_tokens="float")  # type: ignore[arg-type]
+
+
+class TestCoerceTypes:
+    """Test coercion of stringly typed values."""
+
+    SPREADSHEET = (
+        'name: "Ada"\n'
+        'age: "42"\n'
+        'score: "-3.5"\n'
+        'big: "1e5"\n'
+        'active: "true"\n'
+        'manager: "null"\n'
+        'zip: "007"\n'
+        "rows[2]{id,ok,code}:\n"
+        '  "1","false","0"\n'
+        '  "2","true","0042"'
+    )
+
+    def _decoder(self, *types):
+        from toonverter.core.spec import ToonDecodeOptions
+
+        return ToonDecoder(ToonDecodeOptions(coerce_types=types))
+
+    def test_quoted_everything(self):
+        """Test every coercible literal becomes a typed value."""
+        result = self._decoder("int", "float", "bool", "null").decode(self.SPREADSHEET)
+
+        assert result == {
+            "name": "Ada",
+            "age": 42,
+            "score": -3.5,
+            "big": 100000.0,
+            "active": True,
+            "manager": None,
+            "zip": "007",
+            "rows": [{"id": 1, "ok": False, "code": 0}, {"id": 2, "ok": True, "code": "0042"}],
+        }
+
+    def test_off_by_default(self):
+        """Test quoted literals stay strings without the option."""
+        result = decode(self.SPREADSHEET)
+        assert result["age"] == "42"
+        assert result["active"] == "true"
+
+    def test_only_selected_types(self):
+        """Test only the listed types are coerced."""
+        result = self._decoder("int").decode(self.SPREADSHEET)
+
+        assert result["age"] == 42
+        assert result["score"] == "-3.5"
+        assert result["big"] == "1e5"
+        assert result["active"] == "true"
+        assert result["manager"] == "null"
+
+    @pytest.mark.parametrize(
+        "text", ["007", "00", "-01", "01.5", "1.", ".5", "+1", "1_000", " 1", "1e", "TRUE", "Null"]
+    )
+    def test_non_canonical_forms_stay_strings(self, text):
+        """Test leading zeros and other non-canonical spellings are not coerced."""
+        decoder = self._decoder("int", "float", "bool", "null")
+        assert decoder.decode(f'x: "{text}"') == {"x": text}
+
+    def test_exponent_rules(self):
+        """Test exponent forms coerce to float only when "float" is enabled."""
+        decoder = self._decoder("int", "float")
+        assert decoder.decode('a: "1e5"\nb: "2.5E-3"\nc: "0e0"') == {
+            "a": 100000.0,
+            "b": 0.0025,
+            "c": 0.0,
+        }
+        assert self._decoder("int").decode('a: "1e5"') == {"a": "1e5"}
+
+    def test_keys_are_not_coerced(self):
+        """Test only values are coerced, never keys."""
+        assert self._decoder("int", "bool").decode('"1": "2"\n"true": x') == {
+            "1": 2,
+            "true": "x",
+        }
+
+    def test_facade_and_invalid_type(self):
+        """Test the option through the facade and validation of unknown types."""
+        import toonverter as toon
+        from toonverter.core.spec import ToonDecodeOptions
+
+        assert toon.decode('n: "5"', coerce_types=("int",)) == {"n": 5}
+        with pytest.raises(ValueError, match="coerce_types must only contain"):
+            ToonDecodeOptions(coerce_types=("date",))
+
+
+class TestTrailingCommas:
+    """Test a single trailing delimiter is accepted and a double one rejected."""
+
+    def test_inline_object(self):
+        """Test inline objects ignore one trailing comma, at the root and nested."""
+        assert decode("{a: 1, b: 2,}") == {"a": 1, "b": 2}
+        assert decode("x: {a: {b: 1,},}") == {"x": {"a": {"b": 1}}}
+
+    def test_inline_object_double_comma_rejected(self):
+        """Test doubled or leading commas in inline objects are errors."""
+        from toonverter.core.exceptions import DecodingError
+
+        for toon_str in ("{a: 1, b: 2,,}", "x: {,a: 1}"):
+            with pytest.raises(DecodingError, match="Unexpected ','"):
+                decode(toon_str)
+
+    @pytest.mark.parametrize("toon_str", ["[3]: 1,2,3,", "[3|]: 1|2|3|", "[3\t]: 1\t2\t3\t"])
+    def test_inline_array(self, toon_str):
+        """Test inline arrays ignore one trailing delimiter under every delimiter."""
+        assert decode(toon_str) == [1, 2, 3]
+
+    @pytest.mark.parametrize("toon_str", ["[3]: 1,2,3,,", "[2]: 1,,", "[3|]: 1|2|3||"])
+    def test_inline_array_double_comma_rejected(self, toon_str):
+        """Test a second trailing delimiter is an error, not an extra empty element."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match="Unexpected trailing"):
+            decode(toon_str)
+
+    def test_inline_array_trailing_comma_is_not_a_cell(self):
+        """Test a dropped trailing delimiter leaves a short array short."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="declared 2, got 1"):
+            decode("[2]: 1,")
+
+    def test_inline_array_empty_cell_is_null(self):
+        """Test an empty cell between delimiters is a null element, as in tabular rows."""
+        assert decode("[3]: 1,,3") == [1, None, 3]
+
+    def test_tabular_rows(self):
+        """Test tabular rows ignore one trailing delimiter after a full row."""
+        toon_str = "rows[2]{a,b}:\n  1,2,\n  3,null,"
+        assert decode(toon_str) == {"rows": [{"a": 1, "b": 2}, {"a": 3, "b": None}]}
+        assert decode("[1]{a}:\n  x,") == [{"a": "x"}]
+
+    def test_tabular_trailing_comma_is_not_a_cell(self):
+        """Test a dropped trailing delimiter leaves a short row short."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="expected 2, got 1"):
+            decode("[1]{a,b}:\n  3,")
+
+    def test_tabular_double_comma_rejected(self):
+        """Test a second trailing delimiter is an error, also in non-strict mode."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        for toon_str in ("[1]{a,b}:\n  1,2,,", "[1]{a,b,c}:\n  1,,"):
+            with pytest.raises(ValidationError, match="Unexpected trailing ','"):
+                decode(toon_str)
+        with pytest.raises(ValidationError, match="Unexpected trailing ','"):
+            decode("[1]{a,b}:\n  1,2,,", ToonDecodeOptions(strict=False))
+
+    def test_tabular_double_comma_marked(self):
+        """Test a row with a second trailing delimiter can be marked as malformed."""
+        from toonverter.core.spec import PARSE_ERROR_KEY, ToonDecodeOptions
+
+        result = decode("[2]{a,b}:\n  1,2,,\n  3,4", ToonDecodeOptions(row_errors="mark"))
+        assert result[0] == {PARSE_ERROR_KEY: "Unexpected trailing ',' at row 1 (line 2)"}
+        assert result[1] == {"a": 3, "b": 4}
+
+    def test_encoder_never_emits_trailing_commas(self):
+        """Test encoded inline and tabular arrays end without a delimiter."""
+        from toonverter.encoders import encode
+
+        toon_str = encode({"t": [1, None], "rows": [{"a": 1, "b": None}]})
+        assert not any(line.endswith(",") for line in toon_str.splitlines())
+
+
+class TestWrappedInlineArrays:
+    """Test inline arrays continued on deeper lines."""
+
+    @pytest.mark.parametrize(
+        "toon_str",
+        [
+            "ids[6]: 1,2,3,\n  4,5,6",
+            "ids[6]: 1,2,3\n  4,5\n  6",
+            "ids[6]: 1, 2,\n  3, 4,\n  5, 6",
+            "ids[6|]: 1|2|3|\n  4|5|6",
+            "ids[6\t]: 1\t2\t3\n  4\t5\t6",
+        ],
+    )
+    def test_wrapped(self, toon_str):
+        """Test line breaks separate values like a delimiter, under every delimiter."""
+        assert decode(toon_str) == {"ids": [1, 2, 3, 4, 5, 6]}
+
+    def test_fields_after_wrapped_array(self):
+        """Test parsing resumes at the next field once the array is complete."""
+        toon_str = "a:\n  ids[3]: x,\n    y,z\n  b: 1\nc[2]: 1,2"
+        assert decode(toon_str) == {"a": {"ids": ["x", "y", "z"], "b": 1}, "c": [1, 2]}
+
+    def test_list_items(self):
+        """Test continuations are indented deeper than the item's key, or its hyphen."""
+        toon_str = "x[2]:\n  - tags[3|]: a|b\n      c\n    note: p|q\n  - [2|]: 1|\n    2"
+        assert decode(toon_str) == {"x": [{"tags": ["a", "b", "c"], "note": "p|q"}, [1, 2]]}
+
+    def test_sibling_lines_are_not_continuations(self):
+        """Test fields, list items and lines at the key's level never continue an array."""
+        from toonverter.core.exceptions import ValidationError
+
+        for toon_str in ("a[3]: 1,2\n  b: 3", "a[3]: 1,2\nb: 3", "x[1]:\n  - t[3]: 1,2\n    3"):
+            with pytest.raises(ValidationError, match="declared 3, got 2"):
+                decode(toon_str)
+
+    def test_complete_array_is_not_continued(self):
+        """Test a deeper line after a complete array is not read into it."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        assert decode("a[2]: 1,2\n  3", ToonDecodeOptions(strict=False)) == {"a": [1, 2]}
+
+
+class TestInternStrings:
+    """Test the intern_strings decode option."""
+
+    def test_equal_values_share_one_object(self):
+        """Test equal string values in a tabular column are the same object."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        toon_str = "rows[3]{id,category}:\n  1," + "x" * 40 + "\n  2,other\n  3," + "x" * 40
+        result = decode(toon_str, ToonDecodeOptions(intern_strings=True))
+
+        a = result["rows"][0]["category"]
+        b = result["rows"][2]["category"]
+        assert a == b
+        assert a is b
+
+    def test_quoted_values_are_interned(self):
+        """Test quoted string values are interned as well."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        result = decode('[3]: "a b",c,"a b"', ToonDecodeOptions(intern_strings=True))
+        assert result[0] is result[2]
+
+    def test_disabled_by_default(self):
+        """Test interning is off by default and output is unchanged."""
+        toon_str = "[2]: " + "y" * 40 + "," + "y" * 40
+        result = decode(toon_str)
+        assert result == ["y" * 40, "y" * 40]
+        assert result[0] is not result[1]
+
+    def test_facade_option(self):
+        """Test intern_strings is accepted by the top-level decode function."""
+        import toonverter as toon
+
+        result = toon.decode("[2]: " + "z" * 40 + "," + "z" * 40, intern_strings=True)
+        assert result[0] is result[1]
+
+
+class TestDashListObjects:
+    """Test list items that are key-value blocks."""
+
+    def test_two_field_items(self):
+        """Test fields continuing below the dash stay with their item."""
+        toon_str = "items[2]:\n  - name: a\n    age: 1\n  - name: b\n    age: 2"
+        assert decode(toon_str) == {
+            "items": [{"name": "a", "age": 1}, {"name": "b", "age": 2}]
+        }
+
+    def test_sibling_key_after_list(self):
+        """Test a key after the list is not merged into the last item."""
+        toon_str = "items[2]:\n  - name: a\n    age: 1\n  - name: b\nnext: 3"
+        assert decode(toon_str) == {
+            "items": [{"name": "a", "age": 1}, {"name": "b"}],
+            "next": 3,
+        }
+
+    def test_nested_list_inside_item(self):
+        """Test a list field inside an item with a field after it."""
+        toon_str = (
+            "[2]:\n"
+            "  - name: a\n"
+            "    tags[2]:\n"
+            "      - x\n"
+            "      - k: v\n"
+            "        w: 1\n"
+            "    age: 1\n"
+            "  - name: b"
+        )
+        assert decode(toon_str) == [
+            {"name": "a", "tags": ["x", {"k": "v", "w": 1}], "age": 1},
+            {"name": "b"},
+        ]
+
+    def test_nested_object_as_first_field(self):
+        """Test a nested object on the dash line keeps later fields on the item."""
+        toon_str = "[1]:\n  - a:\n      b: 1\n    c: 2"
+        assert decode(toon_str) == [{"a": {"b": 1}, "c": 2}]
+
+    def test_trailing_scalar_after_object_items(self):
+        """Test a scalar item following object items."""
+        toon_str = "[3]:\n  - a: 1\n    b: 2\n  - a: 3\n  - 5"
+        assert decode(toon_str) == [{"a": 1, "b": 2}, {"a": 3}, 5]
+
+    def test_empty_object_item(self):
+        """Test a bare dash decodes as an empty object."""
+        assert decode("[2]:\n  -\n  - a: 1") == [{}, {"a": 1}]
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            {"items": [{"a": 1, "b": [1, {"c": 2}]}, {"a": 2}, 3]},
+            [{"a": {"b": 1}, "c": 2}, {"d": 3}],
+            {"l": [{"a": 1, "b": 2}, {"a": 1}], "z": 1},
+            [[1, [2, 3]], {"a": [{"x": 1, "y": 2}, {"x": 3, "y": 4}], "b": 1}],
+            {"a": [{"b": [{"c": [1, {"d": 1, "e": {"f": 2}}]}], "g": 3}], "h": 4},
+            [{}, {"a": 1}],
+        ],
+    )
+    def test_roundtrip_encoder_block_lists(self, data):
+        """Test the encoder's block list output decodes to the original data."""
+        from toonverter.encoders import encode
+
+        assert decode(encode(data)) == data
+
+
+class TestMaxTabularFields:
+    """Test the max_tabular_fields decode option."""
+
+    def test_oversized_header_rejected(self):
+        """Test a header with too many fields raises ValidationError."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        fields = ",".join(f"f{i}" for i in range(10_000))
+        toon_str = f"[1]{{{fields}}}:\n  " + ",".join("1" for _ in range(10_000))
+        with pytest.raises(ValidationError, match="more than 100 fields"):
+            decode(toon_str, ToonDecodeOptions(max_tabular_fields=100))
+
+    def test_header_at_limit_accepted(self):
+        """Test a header with exactly the limit decodes."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        result = decode("[1]{a,b}:\n  1,2", ToonDecodeOptions(max_tabular_fields=2))
+        assert result == [{"a": 1, "b": 2}]
+
+    def test_invalid_limit_rejected(self):
+        """Test non-positive limits are rejected at option construction."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="max_tabular_fields"):
+            ToonDecodeOptions(max_tabular_fields=0)
+
+
+class TestMaxCollectionWidth:
+    """Test the max_dict_entries and max_list_elements decode options."""
+
+    def test_wide_object_rejected(self):
+        """Test an object with too many keys raises ValidationError."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        toon_str = "outer:\n" + "\n".join(f"  k{i}: {i}" for i in range(10_000))
+        with pytest.raises(ValidationError, match="max_dict_entries 100 keys at line 102"):
+            decode(toon_str, ToonDecodeOptions(max_dict_entries=100))
+
+    def test_wide_inline_and_root_objects_rejected(self):
+        """Test the limit applies to inline objects and the root object too."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(max_dict_entries=2)
+        with pytest.raises(ValidationError, match="max_dict_entries"):
+            decode("a: {x: 1, y: 2, z: 3}", options)
+        with pytest.raises(ValidationError, match="max_dict_entries"):
+            decode("a: 1\nb: 2\nc: 3", options)
+        assert decode("a: {x: 1, y: 2}\nb: 2", options) == {"a": {"x": 1, "y": 2}, "b": 2}
+
+    def test_wide_tabular_header_rejected(self):
+        """Test a tabular header with more fields than the limit fails before its rows."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(max_dict_entries=3)
+        with pytest.raises(ValidationError, match="max_dict_entries 3 keys at line 1"):
+            decode("[1]{a,b,c,d}:\n  1,2,3,4", options)
+        assert decode("[1]{a,b,c}:\n  1,2,3", options) == [{"a": 1, "b": 2, "c": 3}]
+
+    def test_declared_length_rejected_before_body(self):
+        """Test a huge declared length fails without reading the rows."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(max_list_elements=1000)
+        for toon_str in ("[99999999]{a}:\n  1", "[99999999]: 1", "items[99999999]:\n  - 1"):
+            with pytest.raises(ValidationError, match="declares 99999999 elements"):
+                decode(toon_str, options)
+
+    def test_undeclared_inline_elements_rejected(self):
+        """Test inline values past the limit are caught when lengths are not enforced."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        toon_str = "[1]: " + ",".join(str(i) for i in range(10_000))
+        options = ToonDecodeOptions(strict=False, max_list_elements=50)
+        with pytest.raises(ValidationError, match="max_list_elements 50 elements at line 1"):
+            decode(toon_str, options)
+
+    def test_at_limit_accepted(self):
+        """Test collections exactly at the limits decode."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(max_dict_entries=2, max_list_elements=2)
+        toon_str = "rows[2]{a,b}:\n  1,2\n  3,4\ntags[2]: x,y"
+        assert decode(toon_str, options) == {
+            "rows": [{"a": 1, "b": 2}, {"a": 3, "b": 4}],
+            "tags": ["x", "y"],
+        }
+
+    def test_facade_override(self):
+        """Test both limits are accepted as facade overrides."""
+        import toonverter as toon
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="max_list_elements"):
+            toon.decode("[3]: 1,2,3", max_list_elements=2)
+        assert toon.decode("a: 1", max_dict_entries=1) == {"a": 1}
+
+    def test_invalid_limit_rejected(self):
+        """Test negative limits are rejected at option construction."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="max_dict_entries"):
+            ToonDecodeOptions(max_dict_entries=-1)
+        with pytest.raises(ValueError, match="max_list_elements"):
+            ToonDecodeOptions(max_list_elements=-1)
+
+
+class TestMaxIndentDepth:
+    """Test the max_indent_depth decode option."""
+
+    @staticmethod
+    def _deep_document(depth):
+        return "".join(f"{'  ' * i}k{i}:\n" for i in range(depth)) + f"{'  ' * depth}v: 1"
+
+    def test_pathologically_deep_input_rejected_by_lexer(self):
+        """Test runaway indentation fails during tokenization."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.decoders.lexer import ToonLexer
+
+        lexer = ToonLexer(self._deep_document(5000), max_indent_depth=64)
+        with pytest.raises(ValidationError, match="depth 64 exceeded at line 66"):
+            lexer.tokenize()
+
+    def test_decode_and_stream_honor_option(self):
+        """Test the decoder and stream decoder pass the limit to their lexers."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import StreamDecoder
+
+        options = ToonDecodeOptions(max_indent_depth=2)
+        with pytest.raises(ValidationError, match="depth 2 exceeded at line 4"):
+            decode(self._deep_document(3), options)
+
+        lines = ["[1]:\n", "  - a:\n", "      b:\n", "        c: 1\n"]
+        with pytest.raises(ValidationError, match="depth 2 exceeded at line 3"):
+            list(StreamDecoder(options).decode_stream(iter(lines)))
+
+    def test_depth_at_limit_accepted(self):
+        """Test input exactly at the limit decodes."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        result = decode(self._deep_document(2), ToonDecodeOptions(max_indent_depth=2))
+        assert result == {"k0": {"k1": {"v": 1}}}
+
+    def test_negative_limit_rejected(self):
+        """Test negative limits are rejected at option construction."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="max_indent_depth"):
+            ToonDecodeOptions(max_indent_depth=-1)
+
+
+class TestComments:
+    """Test comment handling in the lexer and decoder."""
+
+    def test_hash_inside_unquoted_value_preserved(self):
+        """Test '#' without preceding whitespace stays part of the value."""
+        from toonverter.decoders.lexer import TokenType, ToonLexer
+
+        tokens = ToonLexer("key: val#note").tokenize()
+
+        assert decode("key: val#note") == {"key": "val#note"}
+        assert decode("n: 1#2") == {"n": "1#2"}
+        assert TokenType.COMMENT not in [t.type for t in tokens]
+
+    def test_inline_comment_with_space(self):
+        """Test a trailing comment after whitespace is ignored."""
+        from toonverter.decoders.lexer import TokenType, ToonLexer
+
+        tokens = ToonLexer("key: val # note").tokenize()
+        types = [t.type for t in tokens]
+
+        assert decode("a: 1 # one\nb: 2") == {"a": 1, "b": 2}
+        assert tokens[types.index(TokenType.COMMENT)].value == "note"
+
+    def test_comment_after_inline_list(self):
+        """Test a trailing comment after an inline array."""
+        assert decode("tags[2]: a,b # two tags\nz: 1") == {"tags": ["a", "b"], "z": 1}
+
+    def test_comment_after_tabular_rows(self):
+        """Test trailing comments inside tabular rows do not become cells."""
+        toon_str = "rows[2]{a,b}:\n  1,x#y # first\n  3,4\t# second"
+        assert decode(toon_str) == {"rows": [{"a": 1, "b": "x#y"}, {"a": 3, "b": 4}]}
+
+    def test_comment_only_lines_ignored(self):
+        """Test full-line comments do not affect structure or indentation."""
+        toon_str = "# header\nitems[2]:\n  # first\n  - a: 1\n# stray\n  - a: 2\nz: 1"
+        assert decode(toon_str) == {"items": [{"a": 1}, {"a": 2}], "z": 1}
+
+    def test_comment_after_tabular_header(self):
+        """Test a comment after a tabular header keeps the row delimiter scope."""
+        toon_str = "rows[2|]{a|b}: # pipes\n  1|2\n  3|4 # last"
+        assert decode(toon_str) == {"rows": [{"a": 1, "b": 2}, {"a": 3, "b": 4}]}
+
+    def test_hash_inside_quotes_preserved(self):
+        """Test '#' inside quoted strings is not a comment."""
+        assert decode('a: "x # y"') == {"a": "x # y"}
+
+    def test_roundtrip_strings_with_hash(self):
+        """Test the encoder quotes '#' so values round-trip."""
+        from toonverter.encoders import encode
+
+        data = {"a": "x#y", "b": "x # y", "tags": ["#a", "b#"], "rows": [{"#k": "#v"}]}
+        assert decode(encode(data)) == data
+
+
+class TestDecodeFileobj:
+    """Test decoding from file-like objects and line iterables."""
+
+    TOON = "name: Ünïcode\nrows[2]{id,tag}:\n  1,a\n  2,b\nitems[2]:\n  - x: 1\n  - 5\n"
+
+    def test_temp_file_text_and_binary(self, tmp_path):
+        """Test text and binary mode files decode like the full string."""
+        from toonverter.decoders import decode_fileobj
+
+        path = tmp_path / "data.toon"
+        path.write_text(self.TOON, encoding="utf-8")
+
+        with path.open(encoding="utf-8") as f:
+            assert decode_fileobj(f, chunk_size=7) == decode(self.TOON)
+        with path.open("rb") as f:
+            assert decode_fileobj(f, chunk_size=7) == decode(self.TOON)
+
+    def test_string_io(self):
+        """Test io.StringIO sources."""
+        import io
+
+        from toonverter.decoders import decode_fileobj
+
+        assert decode_fileobj(io.StringIO(self.TOON)) == decode(self.TOON)
+
+    def test_gzip_file(self, tmp_path):
+        """Test gzip.GzipFile sources, with multibyte characters split across reads."""
+        import gzip
+
+        from toonverter.decoders import decode_fileobj
+
+        path = tmp_path / "data.toon.gz"
+        with gzip.open(path, "wt", encoding="utf-8") as f:
+            f.write(self.TOON)
+
+        with gzip.GzipFile(path) as f:
+            assert decode_fileobj(f, chunk_size=1) == decode(self.TOON)
+
+    def test_iterable_of_lines(self):
+        """Test plain line iterables."""
+        from toonverter.decoders import decode_fileobj
+
+        lines = self.TOON.splitlines(keepends=True)
+        assert decode_fileobj(iter(lines)) == decode(self.TOON)
+
+    def test_iterable_items_are_lines(self):
+        """Test items without line breaks, and empty items, each form one line."""
+        from toonverter.decoders import decode_fileobj
+
+        lines = self.TOON.splitlines()
+        assert decode_fileobj(iter(lines)) == decode(self.TOON)
+        assert decode_fileobj(["a: 1", "", "b: 2"]) == {"a": 1, "b": 2}
+        assert decode_fileobj([b"a: 1", b"", b"b: 2\n"]) == {"a": 1, "b": 2}
+
+    def test_read_errors_propagate(self):
+        """Test exceptions raised by read() are not wrapped."""
+        from toonverter.decoders import decode_fileobj
+
+        class Broken:
+            def read(self, size):
+                raise OSError("connection reset")
+
+        with pytest.raises(OSError, match="connection reset"):
+            decode_fileobj(Broken())
+
+    def test_invalid_utf8_rejected(self):
+        """Test invalid UTF-8 bytes raise DecodingError."""
+        import io
+
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.decoders import decode_fileobj
+
+        with pytest.raises(DecodingError, match="UTF-8"):
+            decode_fileobj(io.BytesIO(b"a: \xff"))
+
+
+class TestChunkedDecoder:
+    """Test feeding a document to ChunkedDecoder in pieces."""
+
+    TOON = "name: Ünïcode\nrows[2]{id,tag}:\n  1,a\n  2,b\nitems[2]:\n  - x: 1\n  - 5\n"
+
+    def test_two_chunks_split_mid_line(self):
+        """Test a split inside a line matches a single-shot decode."""
+        from toonverter.decoders import ChunkedDecoder
+
+        split = self.TOON.index("2,b") + 1
+        decoder = ChunkedDecoder()
+        decoder.feed(self.TOON[:split])
+        decoder.feed(self.TOON[split:])
+        assert decoder.finish() == decode(self.TOON)
+
+    def test_bytes_split_inside_multibyte_character(self):
+        """Test UTF-8 bytes fed one at a time."""
+        from toonverter.decoders import ChunkedDecoder
+
+        decoder = ChunkedDecoder()
+        for byte in self.TOON.encode("utf-8"):
+            decoder.feed(bytes([byte]))
+        assert decoder.finish() == decode(self.TOON)
+
+    def test_truncated_utf8_at_finish(self):
+        """Test input ending inside a multibyte character is rejected."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.decoders import ChunkedDecoder
+
+        decoder = ChunkedDecoder()
+        decoder.feed("a: ".encode() + "é".encode()[:1])
+        with pytest.raises(DecodingError, match="UTF-8"):
+            decoder.finish()
+
+    def test_feed_after_finish(self):
+        """Test the decoder cannot be reused after finish()."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.decoders import ChunkedDecoder
+
+        decoder = ChunkedDecoder()
+        decoder.feed("a: 1")
+        assert decoder.finish() == {"a": 1}
+        with pytest.raises(DecodingError, match="after finish"):
+            decoder.feed("b: 2")
+        with pytest.raises(DecodingError, match="already called"):
+            decoder.finish()
+
+
+class TestByteOrderMark:
+    """Test input carrying a byte order mark."""
+
+    TOON = "name: Ada\nrows[2]{id,tag}:\n  1,a\n  2,b"
+
+    def test_utf8_bom_ignored(self):
+        """Test a leading BOM does not become part of the first key."""
+        assert decode("\ufeff" + self.TOON) == decode(self.TOON)
+        assert decode("\ufeff[2]: 1,2") == [1, 2]
+
+    def test_utf8_bom_file(self, tmp_path):
+        """Test BOM'd files decode like BOM-less ones through every file path."""
+        import toonverter as toon
+        from toonverter.decoders import decode_fileobj
+
+        path = tmp_path / "bom.toon"
+        path.write_text(self.TOON, encoding="utf-8-sig")
+
+        with path.open("rb") as f:
+            assert decode_fileobj(f, chunk_size=2) == decode(self.TOON)
+        assert toon.load(str(path), format="toon") == decode(self.TOON)
+
+    def test_utf16_rejected(self, tmp_path):
+        """Test UTF-16 input gets a targeted message instead of a UTF-8 error."""
+        import io
+
+        import toonverter as toon
+        from toonverter.core.exceptions import DecodingError, FileOperationError
+        from toonverter.decoders import decode_fileobj
+
+        for encoding in ("utf-16-le", "utf-16-be"):
+            data = "\ufeff".encode(encoding) + self.TOON.encode(encoding)
+            with pytest.raises(DecodingError, match="appears to be UTF-16; re-encode as UTF-8"):
+                decode_fileobj(io.BytesIO(data))
+            with pytest.raises(DecodingError, match="appears to be UTF-16"):
+                decode_fileobj(io.BytesIO(data), chunk_size=1)
+
+            path = tmp_path / f"{encoding}.toon"
+            path.write_bytes(data)
+            with pytest.raises(FileOperationError, match="appears to be UTF-16"):
+                toon.load(str(path), format="toon")
+
+
+class TestDirective:
+    """Test the leading #!toon directive."""
+
+    def test_directive_changes_indent_interpretation(self):
+        """Test indent=4 makes two-space lines part of the root level."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        body = "a:\n  b: 1"
+        assert decode(body) == {"a": {"b": 1}}
+        lenient = ToonDecodeOptions(strict=False)
+        with pytest.warns(UserWarning, match="expected a multiple of 4 spaces"):
+            assert decode("#!toon indent=4\n" + body, lenient) == {"a": {}, "b": 1}
+        with pytest.raises(ValidationError, match="expected a multiple of 4 spaces"):
+            decode("#!toon indent=4\n" + body)
+
+    def test_directive_indent_four_document(self):
+        """Test a four-space document with list items and continuation fields."""
+        toon_str = "#!toon indent=4\nitems[2]:\n    - x: 1\n        y: 2\n    - 3"
+        assert decode(toon_str) == {"items": [{"x": 1, "y": 2}, 3]}
+
+    def test_directive_sets_document_delimiter(self):
+        """Test delimiter=| applies to headers without a marker."""
+        toon_str = "#!toon delimiter=pipe\ntags[2]: a,b|c"
+        assert decode(toon_str) == {"tags": ["a,b", "c"]}
+
+    def test_directive_in_stream(self):
+        """Test the stream decoder honors the directive."""
+        from toonverter.decoders import StreamDecoder
+
+        lines = ["#!toon indent=4\n", "[2]:\n", "    - a: 1\n", "        b: 2\n", "    - 3\n"]
+        assert list(StreamDecoder().decode_stream(iter(lines))) == [{"a": 1, "b": 2}, 3]
+
+    def test_invalid_directive_value(self):
+        """Test invalid directive values raise DecodingError."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match="#!toon"):
+            decode("#!toon indent=0\na: 1")
+
+
+class TestTabularBlankAndEmptyRows:
+    """Tests for blank lines and empty cells inside tabular blocks."""
+
+    def test_blank_lines_between_rows_are_skipped(self):
+        """Test blank lines never count as rows."""
+        toon_str = "rows[2]{a,b}:\n  1,2\n\n\n  3,4\nx: 1"
+        assert decode(toon_str) == {"rows": [{"a": 1, "b": 2}, {"a": 3, "b": 4}], "x": 1}
+
+    def test_whitespace_only_line_is_skipped(self):
+        """Test an indented blank line inside a single-field table is skipped."""
+        assert decode("rows[2]{a}:\n  1\n  \n  3\n") == {"rows": [{"a": 1}, {"a": 3}]}
+
+    def test_explicit_nulls_make_null_row(self):
+        """Test a row of nulls is written out, since a blank line is skipped."""
+        toon_str = "rows[2]{a,b}:\n  1,2\n\n  null,null\n"
+        assert decode(toon_str) == {"rows": [{"a": 1, "b": 2}, {"a": None, "b": None}]}
+
+    def test_empty_cells_between_values(self):
+        """Test leading and middle empty cells decode to None."""
+        toon_str = "rows[2]{a,b,c}:\n  1,,3\n  ,2,null\n"
+        assert decode(toon_str) == {
+            "rows": [{"a": 1, "b": None, "c": 3}, {"a": None, "b": 2, "c": None}]
+        }
+
+    def test_empty_cells_with_pipe_delimiter(self):
+        """Test empty cells are recognized for non-comma delimiters."""
+        assert decode("rows[1|]{a|b}:\n  |x\n") == {"rows": [{"a": None, "b": "x"}]}
+
+    def test_short_row_omits_missing_cells_by_default(self):
+        """Test non-strict short rows leave missing keys out of the row."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(strict=False)
+        result = decode("rows[2]{a,b,c}:\n  1,,3\n  1\n", options)
+        assert result == {"rows": [{"a": 1, "b": None, "c": 3}, {"a": 1}]}
+
+    def test_short_row_missing_cells_as_null(self):
+        """Test missing_cells='null' fills short rows with None."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(strict=False, missing_cells="null")
+        result = decode("rows[2]{a,b,c}:\n  1,,3\n  1\n", options)
+        assert result == {"rows": [{"a": 1, "b": None, "c": 3}, {"a": 1, "b": None, "c": None}]}
+
+    def test_missing_cells_through_facade(self):
+        """Test the option is available on DecodeOptions."""
+        import toonverter
+
+        result = toonverter.decode("[1]{a,b}:\n  1", strict=False, missing_cells="null")
+        assert result == [{"a": 1, "b": None}]
+
+    def test_invalid_missing_cells_rejected(self):
+        """Test unknown missing_cells values are rejected."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="missing_cells"):
+            ToonDecodeOptions(missing_cells="skip")
+
+    def test_rows_are_independent_dicts(self):
+        """Test rows built from the shared key template do not share state."""
+        rows = decode("[2]{a,b}:\n  1,2\n  3,4")
+        rows[0]["a"] = 9
+        rows[0]["extra"] = True
+        assert rows[1] == {"a": 3, "b": 4}
+        assert list(rows[1]) == ["a", "b"]
+
+    def test_long_row_and_repeated_field_keep_header_keys(self):
+        """Test extra cells are dropped and a repeated field keeps its last cell."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(strict=False)
+        assert decode("[1]{a,b}:\n  1,2,3", options) == [{"a": 1, "b": 2}]
+        assert decode("[1]{a,b,a}:\n  1,2,3", options) == [{"a": 3, "b": 2}]
+
+    def test_blank_line_does_not_fill_declared_length(self):
+        """Test a blank line cannot stand in for a missing row in strict mode."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="declared 3, got 2"):
+            decode("rows[3]{a,b}:\n  1,2\n\n  3,4\n")
+
+
+class TestIndentationValidation:
+    """Test strict-mode checks on the indentation structure."""
+
+    def test_indented_root_rejected(self):
+        """Test a document starting above level 0 fails in strict mode."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="Line 1: document must start at indent level 0"):
+            decode("  a: 1\n  b: 2")
+
+    def test_skipped_level_rejected(self):
+        """Test jumping two levels below a field fails in strict mode."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="Line 3: indent level 3 skips levels"):
+            decode("a:\n  b:\n      c: 1")
+
+    def test_list_item_object_fields_allowed(self):
+        """Test fields of an object opened on a hyphen line may sit two levels deeper."""
+        toon_str = "[1]:\n  - a:\n      b: 1\n    c: 2"
+        assert decode(toon_str) == [{"a": {"b": 1}, "c": 2}]
+
+    def test_dedent_by_several_levels_allowed(self):
+        """Test closing several blocks at once is fine."""
+        assert decode("a:\n  b:\n    c: 1\nd: 2") == {"a": {"b": {"c": 1}}, "d": 2}
+
+    def test_lenient_mode_accepts_loose_indentation(self):
+        """Test non-strict mode keeps the permissive behavior."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(strict=False)
+        assert decode("  a: 1\n  b: 2", options) == {"a": 1, "b": 2}
+        assert decode("a:\n    b: 1", options) == {"a": {"b": 1}}
+
+    def test_switch_to_four_spaces_points_at_first_line(self):
+        """Test a second half pasted with 4-space indentation fails at its first line."""
+        from toonverter.core.exceptions import ValidationError
+
+        toon_str = (
+            "server:\n"
+            "  host: a\n"
+            "  tls:\n"
+            "    enabled: true\n"
+            "client:\n"
+            "    retries: 3\n"
+            "    timeout:\n"
+            "        connect: 5"
+        )
+        with pytest.raises(ValidationError) as exc_info:
+            decode(toon_str)
+
+        assert str(exc_info.value).startswith("Line 6: indent level 2 skips levels")
+        assert "found 4 spaces, expected at most 2 with 2-space indentation" in str(
+            exc_info.value
+        )
+
+    def test_partial_unit_rejected(self):
+        """Test a line that is not a whole number of levels is reported."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(
+            ValidationError,
+            match="Inconsistent indentation at line 4: expected a multiple of 2 spaces, found 3",
+        ):
+            decode("a:\n  b: 1\nc:\n   d: 1\ne: 2")
+
+    def test_partial_unit_warns_when_lenient(self):
+        """Test non-strict decoding rounds the line down but warns about it."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        lenient = ToonDecodeOptions(strict=False)
+        with pytest.warns(UserWarning, match="line 4: expected a multiple of 2 spaces, found 3"):
+            assert decode("a:\n  b: 1\nc:\n   d: 1\ne: 2", lenient) == {
+                "a": {"b": 1},
+                "c": {"d": 1},
+                "e": 2,
+            }
+
+    def test_partial_unit_rejected_by_stream_decoder(self):
+        """Test the streaming decoder applies the same check."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.decoders import StreamDecoder
+
+        lines = iter(["[2]:\n", "  - a: 1\n", "     b: 2\n", "  - 3\n"])
+        with pytest.raises(ValidationError, match="line 3: expected a multiple of 2"):
+            list(StreamDecoder().decode_stream(lines))
+
+
+class TestIndentSizeInference:
+    """Test decoding with indent_size=None, which infers the indent unit."""
+
+    @pytest.mark.parametrize("unit", [2, 4])
+    def test_infers_unit(self, unit):
+        """Test 2- and 4-space documents decode without naming the size."""
+        from toonverter.core.spec import ToonDecodeOptions, ToonEncodeOptions
+        from toonverter.encoders import encode
+
+        data = {"a": {"b": {"c": 1}}, "items": [{"x": 1, "y": [1, 2]}, 3], "z": None}
+        toon_str = encode(data, ToonEncodeOptions(indent_size=unit))
+        assert decode(toon_str, ToonDecodeOptions(indent_size=None)) == data
+
+    def test_skips_comments_and_blank_lines(self):
+        """Test comment-only and blank lines do not count as the first indent."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        toon_str = "# header\n\na:\n      # note\n    b: 1\n    c:\n        d: 2"
+        result = decode(toon_str, ToonDecodeOptions(indent_size=None))
+        assert result == {"a": {"b": 1, "c": {"d": 2}}}
+
+    def test_inconsistent_document_still_rejected(self):
+        """Test strict mode checks later lines against the inferred unit."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValidationError, match="expected a multiple of 4 spaces, found 6"):
+            decode("a:\n    b: 1\nc:\n      d: 1", ToonDecodeOptions(indent_size=None))
+
+    def test_default_and_explicit_size(self):
+        """Test the default stays 2 and an explicit size is honored."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        toon_str = "a:\n    b:\n        c: 1"
+        with pytest.raises(ValidationError):
+            decode(toon_str)
+        assert decode(toon_str, ToonDecodeOptions(indent_size=4)) == {"a": {"b": {"c": 1}}}
+
+    def test_stream_decoder_and_facade(self):
+        """Test the streaming decoder and the facade infer the unit too."""
+        import toonverter as toon
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import StreamDecoder
+
+        lines = ["[2]:\n", "    - a: 1\n", "        b: 2\n", "    - 3\n"]
+        stream = StreamDecoder(ToonDecodeOptions(indent_size=None))
+        assert list(stream.decode_stream(iter(lines))) == [{"a": 1, "b": 2}, 3]
+        assert toon.decode("a:\n    b: 1", indent_size=None) == {"a": {"b": 1}}
+
+    def test_invalid_size_rejected(self):
+        """Test sizes below 1 are rejected at option construction."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="indent_size"):
+            ToonDecodeOptions(indent_size=0)
+
+
+class TestJsonInputHint:
+    """Test the hint raised when JSON is passed to the TOON decoder."""
+
+    @pytest.mark.parametrize(
+        "json_str",
+        [
+            "[1, 2, 3]",
+            '{"users": [{"id": 1, "name": "Alice"}]}',
+            '{\n  "a": {\n    "b": [1, 2]\n  }\n}',
+            '{"a": 1}',
+        ],
+    )
+    def test_json_input_gets_hint(self, json_str):
+        """Test failures on JSON documents raise JsonInputError with a hint."""
+        from toonverter.core.exceptions import DecodingError, JsonInputError
+
+        with pytest.raises(JsonInputError, match="input appears to be JSON") as exc_info:
+            decode(json_str)
+        assert isinstance(exc_info.value, DecodingError)
+
+    @pytest.mark.parametrize(
+        ("toon_str", "expected"),
+        [
+            ("{a:1}", {"a": 1}),
+            ("{name:Alice}", {"name": "Alice"}),
+            ('{"a": 1, b: 2}', {"a": 1, "b": 2}),
+        ],
+    )
+    def test_valid_toon_starting_with_brace_unchanged(self, toon_str, expected):
+        """Test TOON documents starting with '{' still decode normally."""
+        assert decode(toon_str) == expected
+
+    def test_invalid_non_json_input_has_no_hint(self):
+        """Test ordinary TOON errors are not relabeled."""
+        from toonverter.core.exceptions import DecodingError, JsonInputError
+
+        with pytest.raises(DecodingError) as exc_info:
+            decode("[1, 2")
+        assert not isinstance(exc_info.value, JsonInputError)
+        assert "JSON" not in str(exc_info.value)
+
+    def test_extra_tokens_after_root_primitive(self):
+        """Test trailing content after a root primitive is rejected in strict mode."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match="Extra tokens found after root primitive"):
+            decode("42\nextra")
+
+
+class TestRunScanning:
+    """Test the lexer scans plain runs of ASCII and non-ASCII text alike."""
+
+    @pytest.mark.parametrize(
+        ("text", "expected"),
+        [
+            (
+                'city: Zürich\nquote: "naïve ☕"\nkey: "caf\u00e9,bar"',
+                {"city": "Zürich", "quote": "naïve ☕", "key": "café,bar"},
+            ),
+            ('text: "say \\"hi\\"\\n"\nempty: ""', {"text": 'say "hi"\n', "empty": ""}),
+            (
+                "rows[2|]{a|b}:\n  x|ÿ-z,1\n  ü|-2.5e3",
+                {"rows": [{"a": "x", "b": "ÿ-z,1"}, {"a": "ü", "b": -2500.0}]},
+            ),
+        ],
+    )
+    def test_plain_runs_and_escapes(self, text, expected):
+        """Test values are read whole around escapes and multi-byte characters."""
+        assert decode(text) == expected
+
+    def test_unterminated_string_still_rejected(self):
+        """Test a missing closing quote is reported."""
+        from toonverter.decoders.lexer import ToonLexer
+
+        with pytest.raises(ValueError, match="Unterminated quoted string"):
+            ToonLexer('a: "open é').tokenize()
+
+
+class TestTokenizeToon:
+    """Test the tokenizer exposed for editor tooling."""
+
+    @staticmethod
+    def _tokens(text, **kwargs):
+        from toonverter.decoders import tokenize_toon
+
+        return [
+            (t["type"], t["value"], t["line"], t["column"], t["indent_level"])
+            for t in tokenize_toon(text, **kwargs)
+        ]
+
+    def test_representative_document(self):
+        """Test the full token stream, with absolute columns and comments."""
+        text = '# config\nname: "Ada" # who\nrows[2]{id,ok}:\n  1,true\n  2,null\ntags[2|]: a|b'
+
+        assert self._tokens(text) == [
+            ("comment", "config", 0, 0, 0),
+            ("identifier", "name", 1, 0, 0),
+            ("colon", ":", 1, 4, 0),
+            ("quoted_string", "Ada", 1, 6, 0),
+            ("comment", "who", 1, 12, 0),
+            ("newline", None, 1, 17, 0),
+            ("identifier", "rows", 2, 0, 0),
+            ("array_start", "[", 2, 4, 0),
+            ("number", 2, 2, 5, 0),
+            ("array_end", "]", 2, 6, 0),
+            ("brace_start", "{", 2, 7, 0),
+            ("identifier", "id", 2, 8, 0),
+            ("comma", ",", 2, 10, 0),
+            ("identifier", "ok", 2, 11, 0),
+            ("brace_end", "}", 2, 13, 0),
+            ("colon", ":", 2, 14, 0),
+            ("newline", None, 2, 15, 0),
+            ("indent", None, 3, 0, 1),
+            ("number", 1, 3, 2, 1),
+            ("comma", ",", 3, 3, 1),
+            ("boolean", True, 3, 4, 1),
+            ("newline", None, 3, 8, 1),
+            ("number", 2, 4, 2, 1),
+            ("comma", ",", 4, 3, 1),
+            ("null", None, 4, 4, 1),
+            ("newline", None, 4, 8, 1),
+            ("dedent", None, 5, 0, 0),
+            ("identifier", "tags", 5, 0, 0),
+            ("array_start", "[", 5, 4, 0),
+            ("number", 2, 5, 5, 0),
+            ("identifier", "|", 5, 6, 0),
+            ("array_end", "]", 5, 7, 0),
+            ("colon", ":", 5, 8, 0),
+            ("identifier", "a", 5, 10, 0),
+            ("comma", "|", 5, 11, 0),
+            ("identifier", "b", 5, 12, 0),
+            ("newline", None, 5, 13, 0),
+            ("eof", None, 6, 0, 0),
+        ]
+
+    def test_error_returns_partial_stream(self):
+        """Test an error ends the stream instead of raising."""
+        tokens = self._tokens('a: 1\n# note\nb: "open\nc: 2')
+
+        assert tokens[:5] == [
+            ("identifier", "a", 0, 0, 0),
+            ("colon", ":", 0, 1, 0),
+            ("number", 1, 0, 3, 0),
+            ("newline", None, 0, 4, 0),
+            ("comment", "note", 1, 0, 0),
+        ]
+        assert len(tokens) == 6
+        kind, message, line, _, _ = tokens[-1]
+        assert (kind, line) == ("error", 2)
+        assert message.startswith("Unterminated quoted string at line 3")
+
+    def test_indent_size(self):
+        """Test indent levels follow the given indent size."""
+        tokens = self._tokens("a:\n    b: 1", indent_size=4)
+
+        assert ("identifier", "b", 1, 4, 1) in tokens
+
+    def test_type_names_are_token_type_values(self):
+        """Test type names come from TokenType, the single source of the mapping."""
+        from toonverter.decoders.lexer import TokenType
+
+        names = {kind for kind, *_ in self._tokens("a[1]: -x # c\nb: {k: 1}")}
+        assert names <= {member.value for member in TokenType}
+
+
+class TestMultiLevelDedent:
+    """Regression tests for closing several blocks on one line."""
+
+    DOC = {
+        "outer": [
+            {"inner": [[1, [2, {"deep": [3]}]]], "sib": {"k": 1}},
+            {"a": 2},
+        ],
+        "top": 1,
+    }
+
+    def test_layout_tokens_carry_level_after_each_step(self):
+        """Test INDENT/DEDENT tokens step one level at a time."""
+        from toonverter.decoders.lexer import TokenType, ToonLexer
+
+        tokens = ToonLexer("a:\n  b:\n    c:\n      d: 1\ne: 2").tokenize()
+        indents = [t.indent_level for t in tokens if t.type == TokenType.INDENT]
+        dedents = [t.indent_level for t in tokens if t.type == TokenType.DEDENT]
+
+        assert indents == [1, 2, 3]
+        assert dedents == [2, 1, 0]
+
+    def test_final_dedents_step_to_zero(self):
+        """Test dedents emitted at end of input also step down one level each."""
+        from toonverter.decoders.lexer import TokenType, ToonLexer
+
+        tokens = ToonLexer("a:\n  b:\n    c: 1").tokenize()
+        assert [t.indent_level for t in tokens if t.type == TokenType.DEDENT] == [1, 0]
+
+    def test_nested_lists_then_sibling_and_top_level_key(self):
+        """Test keys after a deeply nested list attach to the right parent."""
+        from toonverter.encoders import encode
+
+        toon_str = encode(self.DOC)
+        assert "\n    sib:\n" in toon_str
+        assert decode(toon_str) == self.DOC
+
+    def test_handwritten_three_level_dedent(self):
+        """Test a hand-written document that dedents three levels at once."""
+        toon_str = "a:\n  b:\n    c:\n      d[2]: 1,2\n  e: 3\nf: 4"
+        assert decode(toon_str) == {"a": {"b": {"c": {"d": [1, 2]}}, "e": 3}, "f": 4}
+
+
+class TestEmptyContainers:
+    """Round-trip tests for empty dicts and lists at every position."""
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            {},
+            [],
+            {"a": {}, "b": []},
+            {"a": [], "x": 1},
+            {"x": 1, "a": {}},
+            {"n": {"e": [], "o": {}}, "z": 2},
+            {"a": [{}], "z": 1},
+            [{}],
+            [{}, {}],
+            [{}, {"a": 1}],
+            [[], {}],
+            [{"a": []}, {"b": {}}],
+            {"a": [[]], "b": []},
+        ],
+    )
+    def test_roundtrip(self, data):
+        """Test empty containers survive encode then decode."""
+        from toonverter.encoders import encode
+
+        assert decode(encode(data)) == data
+
+    def test_brace_empty_object_value(self):
+        """Test a `{}` value followed by a sibling key."""
+        assert decode("a: {}\nb[0]:\nc: 1") == {"a": {}, "b": [], "c": 1}
+
+    def test_empty_array_as_only_content(self):
+        """Test an empty keyed array as the whole document."""
+        assert decode("b[0]:") == {"b": []}
+
+
+class TestEmptyStringsAndNulls:
+    """Round-trip tests for empty strings and nulls in every syntactic position."""
+
+    POSITIONS = {
+        "dict value": lambda v: {"k": v, "z": 1},
+        "inline array element": lambda v: {"k": [v, 1, v]},
+        "list item": lambda v: {"k": [{"a": 1, "b": [1]}, v]},
+        "list item field": lambda v: {"k": [{"a": v, "c": [1]}, {"a": 2}]},
+        "tabular cell": lambda v: {"k": [{"a": v, "b": 1}, {"a": 2, "b": v}]},
+        "root list item": lambda v: [v, {"a": 1}],
+    }
+
+    @pytest.mark.parametrize("value", ["", None])
+    @pytest.mark.parametrize("position", list(POSITIONS))
+    def test_roundtrip(self, position, value):
+        """Test the value survives the standard and streaming encoders."""
+        from toonverter.encoders import ToonStreamEncoder, encode
+
+        data = self.POSITIONS[position](value)
+
+        assert decode(encode(data)) == data
+        assert decode("".join(ToonStreamEncoder().iterencode(data))) == data
+
+    @pytest.mark.parametrize("position", list(POSITIONS))
+    def test_empty_string_is_explicit(self, position):
+        """Test an empty string is written as "" wherever another string would go."""
+        from toonverter.encoders import encode
+
+        expected = encode(self.POSITIONS[position]("x")).replace("x", '""')
+        assert encode(self.POSITIONS[position]("")) == expected
+
+    @pytest.mark.parametrize(
+        ("text", "expected"),
+        [
+            ('a: ""', {"a": ""}),
+            ("a: null", {"a": None}),
+            ("a:", {"a": {}}),
+            ("a:\nb: 1", {"a": {}, "b": 1}),
+            ('a[2]: "",null', {"a": ["", None]}),
+            ("a[3]: x,,y", {"a": ["x", None, "y"]}),
+            ('r[1]{x,y}:\n  "",null', {"r": [{"x": "", "y": None}]}),
+            ('a[2]:\n  - ""\n  - null', {"a": ["", None]}),
+            ('[1]:\n  - k: ""', [{"k": ""}]),
+        ],
+    )
+    def test_canonical_semantics(self, text, expected):
+        """Test "" is an empty string, null is None and a bare key is {}."""
+        assert decode(text) == expected
+
+
+class TestDecodeFragment:
+    """Test decoding a single value with leftover reporting."""
+
+    def test_inline_object_with_trailing_text(self):
+        """Test an inline object followed by unrelated text."""
+        from toonverter.decoders import decode_fragment
+
+        assert decode_fragment("{a: 1} trailing") == ({"a": 1}, "trailing")
+        assert decode_fragment("{a: {b: x}, c: 1}") == ({"a": {"b": "x"}, "c": 1}, "")
+
+    def test_inline_array_with_rest(self):
+        """Test an inline array stops after its last delimited value."""
+        from toonverter.decoders import decode_fragment
+
+        assert decode_fragment("[2]: 1,2 rest") == ([1, 2], "rest")
+
+    def test_block_object_followed_by_garbage(self):
+        """Test a block object consumes its fields, including nested blocks."""
+        from toonverter.decoders import decode_fragment
+
+        value, rest = decode_fragment("a: 1\nb:\n  c: 2\n!!! garbage\nx: 1")
+        assert value == {"a": 1, "b": {"c": 2}}
+        assert rest == "!!! garbage\nx: 1"
+
+    def test_block_arrays_consume_through_dedent(self):
+        """Test tabular and list arrays end where their rows end."""
+        from toonverter.decoders import decode_fragment
+
+        assert decode_fragment("[2]{a}:\n  1\n  2\nzzz") == ([{"a": 1}, {"a": 2}], "zzz")
+        assert decode_fragment("[2]:\n  - 1\n  - x: 1\n    y: 2\nrest") == (
+            [1, {"x": 1, "y": 2}],
+            "rest",
+        )
+
+    def test_leading_whitespace_and_comments_consumed(self):
+        """Test blank lines and comments before the value are skipped."""
+        from toonverter.decoders import decode_fragment
+
+        assert decode_fragment("  # note\n\n  42 x") == (42, "x")
+        assert decode_fragment('"q s", more') == ("q s", ", more")
+
+    def test_fragment_may_start_at_any_column(self):
+        """Test the first line's indentation need not be a whole indent level."""
+        from toonverter.decoders import decode_fragment
+
+        assert decode_fragment("   [2]: 1,2 rest") == ([1, 2], "rest")
+
+    def test_rest_on_its_own_line_keeps_indentation(self):
+        """Test a remainder starting a new line is returned from that line's start."""
+        from toonverter.decoders import decode_fragment
+
+        assert decode_fragment("k: v\n  bad: 1") == ({"k": "v"}, "  bad: 1")
+
+    def test_validation_errors_are_decoding_errors(self):
+        """Test a malformed fragment raises DecodingError, not ValidationError."""
+        from toonverter.core.exceptions import DecodingError, ValidationError
+        from toonverter.decoders import decode_fragment
+
+        with pytest.raises(DecodingError, match="declared 3, got 2") as exc_info:
+            decode_fragment("[3]: 1,2")
+        assert not isinstance(exc_info.value, ValidationError)
+
+    def test_consumed_count(self):
+        """Test ToonDecoder.decode_fragment reports characters consumed."""
+        text = "[2]: 1,2 rest"
+        value, consumed = ToonDecoder().decode_fragment(text)
+        assert value == [1, 2]
+        assert text[consumed:] == "rest"
+
+    @pytest.mark.parametrize("text", ["", "   \n", "# only a comment"])
+    def test_empty_input_errors(self, text):
+        """Test empty input raises a clean DecodingError."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.decoders import decode_fragment
+
+        with pytest.raises(DecodingError, match="empty input"):
+            decode_fragment(text)
+
+    def test_unterminated_inline_object(self):
+        """Test a missing closing brace is reported."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.decoders import decode_fragment
+
+        with pytest.raises(DecodingError, match="Unterminated inline object"):
+            decode_fragment("{a: 1")
+
+
+class TestDecodeColumns:
+    """Test column-major decoding of tabular arrays."""
+
+    @staticmethod
+    def _table(rows):
+        lines = [f"  {i},name{i},{i * 0.5},{'true' if i % 2 else 'false'}" for i in range(rows)]
+        return f"[{rows}]{{id,name,score,flag}}:\n" + "\n".join(lines)
+
+    def test_matches_transposed_rows(self):
+        """Test columns equal the transposed row decode for a 10k-row table."""
+        from toonverter.decoders import decode_columns
+
+        toon_str = self._table(10_000)
+        rows = decode(toon_str)
+        columns = decode_columns(toon_str)
+
+        assert list(columns) == ["id", "name", "score", "flag"]
+        assert columns == {name: [row[name] for row in rows] for name in columns}
+
+    def test_projection_skips_unrequested_columns(self):
+        """Test only the requested cells are converted."""
+        from unittest.mock import patch
+
+        from toonverter.decoders import decode_columns
+
+        toon_str = self._table(100)
+        with patch.object(ToonDecoder, "_token_to_value", autospec=True) as convert:
+            convert.side_effect = lambda _self, token: token.value
+            result = decode_columns(toon_str, columns=["score", "id"])
+
+        assert list(result) == ["score", "id"]
+        assert result["id"] == list(range(100))
+        assert convert.call_count == 200
+
+    def test_named_root_field(self):
+        """Test decoding a table stored under a root key."""
+        from toonverter.decoders import decode_columns
+
+        toon_str = "meta:\n  v: 1\nrows[2]{a,b}:\n  1,x\n  2,y"
+        assert decode_columns(toon_str, key="rows") == {"a": [1, 2], "b": ["x", "y"]}
+
+    def test_short_rows_fill_with_none(self):
+        """Test missing cells in non-strict mode keep columns aligned."""
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import decode_columns
+
+        options = ToonDecodeOptions(strict=False)
+        assert decode_columns("[2]{a,b}:\n  1\n  2,3", options=options) == {
+            "a": [1, 2],
+            "b": [None, 3],
+        }
+
+    @pytest.mark.parametrize(
+        ("toon_str", "key"),
+        [("a: 1", None), ("[2]: 1,2", None), ("[1]:\n  - 1", None), ("rows[2]: 1,2", "rows")],
+    )
+    def test_non_tabular_target_rejected(self, toon_str, key):
+        """Test non-tabular roots and fields raise FormatNotSupportedError."""
+        from toonverter.core.exceptions import FormatNotSupportedError
+        from toonverter.decoders import decode_columns
+
+        with pytest.raises(FormatNotSupportedError, match="tabular array"):
+            decode_columns(toon_str, key=key)
+
+    def test_unknown_column_rejected(self):
+        """Test requesting a column missing from the header fails."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.decoders import decode_columns
+
+        with pytest.raises(ValidationError, match="Unknown columns"):
+            decode_columns("[1]{a}:\n  1", columns=["z"])
+
+
+class TestDecodeTypedColumns:
+    """Test schema-directed decoding of tabular arrays into typed columns."""
+
+    SCHEMA = {"id": "int", "name": "str", "score": "float", "flag": "bool"}
+
+    def test_matches_generic_decode(self):
+        """Test typed columns equal decode_columns for a 10k-row table."""
+        from toonverter.decoders import decode_columns, decode_typed_columns
+
+        toon_str = TestDecodeColumns._table(10_000)
+        columns = decode_typed_columns(toon_str, self.SCHEMA)
+
+        assert columns == decode_columns(toon_str)
+        assert all(type(value) is float for value in columns["score"])
+
+    def test_mismatch_names_row_and_column(self):
+        """Test a cell of the wrong type raises with its row, line and column."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.decoders import decode_typed_columns
+
+        lines = TestDecodeColumns._table(100).split("\n")
+        lines[57] = "  56,name56,n/a,true"
+
+        with pytest.raises(ValidationError, match=r"Row 57 \(line 58\), column 'score'"):
+            decode_typed_columns("\n".join(lines), self.SCHEMA)
+
+    def test_mismatch_null(self):
+        """Test mismatch="null" stores None for cells of the wrong type."""
+        from toonverter.decoders import decode_typed_columns
+
+        toon_str = 'rows[3]{id,name}:\n  1,Ada\n  "2",7\n  x,null'
+        result = decode_typed_columns(
+            toon_str, {"name": "str", "id": "int"}, key="rows", mismatch="null"
+        )
+
+        assert result == {"name": ["Ada", None, None], "id": [1, None, None]}
+
+    def test_any_column_and_empty_cells(self):
+        """Test "any" converts like decode_columns and empty cells are None."""
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import decode_typed_columns
+
+        options = ToonDecodeOptions(coerce_types=("int",))
+        result = decode_typed_columns(
+            '[2]{a,b}:\n  "5",1\n  ,2', {"a": "any", "b": "int"}, options=options
+        )
+
+        assert result == {"a": [5, None], "b": [1, 2]}
+
+    def test_unknown_type_and_column(self):
+        """Test unknown type names and columns missing from the header are rejected."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.decoders import decode_typed_columns
+
+        with pytest.raises(ValueError, match="Unknown column types"):
+            decode_typed_columns("[1]{a}:\n  1", {"a": "date"})
+        with pytest.raises(ValidationError, match="Unknown columns"):
+            decode_typed_columns("[1]{a}:\n  1", {"z": "int"})
+
+
+class TestDecodeRows:
+    """Test decoding tabular arrays into tuples or typed rows."""
+
+    def test_tuples_with_header(self):
+        """Test rows come back as tuples alongside the field names."""
+        from toonverter.decoders import decode_rows
+
+        fields, rows = decode_rows("[3]{id,name}:\n  1,Ada\n  2,Bob\n  3,Cy")
+
+        assert fields == ["id", "name"]
+        assert rows == [(1, "Ada"), (2, "Bob"), (3, "Cy")]
+        assert [dict(zip(fields, row, strict=True)) for row in rows] == decode(
+            "[3]{id,name}:\n  1,Ada\n  2,Bob\n  3,Cy"
+        )
+
+    def test_row_factory_builds_instances(self):
+        """Test a namedtuple class receives the fields as keyword arguments."""
+        from collections import namedtuple
+
+        from toonverter.decoders import decode_rows
+
+        Row = namedtuple("Row", "name id")
+        fields, rows = decode_rows(
+            "meta: 1\nrows[2]{id,name}:\n  1,Ada\n  2,Bob", key="rows", row_factory=Row
+        )
+
+        assert fields == ["id", "name"]
+        assert rows == [Row(name="Ada", id=1), Row(name="Bob", id=2)]
+
+    def test_short_rows(self):
+        """Test missing cells pad tuples and are omitted from factory kwargs."""
+        from dataclasses import dataclass
+
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import decode_rows
+
+        @dataclass
+        class Row:
+            a: int
+            b: str = "default"
+
+        toon_str = "[2]{a,b}:\n  1,x\n  2"
+        options = ToonDecodeOptions(strict=False)
+
+        assert decode_rows(toon_str, options=options)[1] == [(1, "x"), (2, None)]
+        assert decode_rows(toon_str, row_factory=Row, options=options)[1] == [
+            Row(1, "x"),
+            Row(2, "default"),
+        ]
+        null_options = ToonDecodeOptions(strict=False, missing_cells="null")
+        assert decode_rows(toon_str, row_factory=Row, options=null_options)[1][1] == Row(2, None)
+
+    def test_row_factory_errors_propagate(self):
+        """Test a ValueError raised by row_factory is not relabeled as a decoding error."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.decoders import decode_rows
+
+        def row_factory(id, name):
+            raise ValueError(f"bad row {id}")
+
+        with pytest.raises(ValueError, match="bad row 1") as exc_info:
+            decode_rows("[1]{id,name}:\n  1,Ada", row_factory=row_factory)
+        assert not isinstance(exc_info.value, DecodingError)
+
+    def test_non_tabular_target_rejected(self):
+        """Test non-tabular targets raise FormatNotSupportedError."""
+        from toonverter.core.exceptions import FormatNotSupportedError
+        from toonverter.decoders import decode_rows
+
+        with pytest.raises(FormatNotSupportedError, match="Row decoding requires"):
+            decode_rows("[2]: 1,2")
+
+
+class TestRowErrors:
+    """Test row/column context and multi-error collection for tabular rows."""
+
+    @staticmethod
+    def _table():
+        rows = [f"  {i},item{i},{i}.5" for i in range(1, 1001)]
+        rows[2] = "  3,short"
+        rows[499] = "  500,item500,{"
+        rows[998] = "  999,a,b,c"
+        return "[1000]{id,name,price}:\n" + "\n".join(rows)
+
+    def test_error_names_row_line_and_column(self):
+        """Test the first error says which row and column failed."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError) as exc_info:
+            decode(self._table())
+
+        assert str(exc_info.value) == (
+            "Row width mismatch at row 3 (line 4): expected 3, got 2; column 'price' is missing"
+        )
+
+    def test_invalid_cell_names_column(self):
+        """Test a structural token in a cell reports the column it landed in."""
+        from toonverter.core.exceptions import ValidationError
+
+        with pytest.raises(ValidationError, match="row 2 \\(line 3\\), column 'price'"):
+            decode("[2]{id,price}:\n  1,2\n  2,{")
+
+    def test_collects_errors_in_one_pass(self):
+        """Test errors in rows 3, 500 and 999 are all reported."""
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import validate_toon
+
+        errors = validate_toon(self._table(), ToonDecodeOptions(max_errors=10))
+
+        assert len(errors) == 3
+        assert errors[0].startswith("Row width mismatch at row 3 ")
+        assert errors[1].startswith("Invalid cell at row 500 ")
+        assert errors[2].startswith("Row width mismatch at row 999 ")
+
+    def test_stops_at_max_errors(self):
+        """Test decoding fails with every collected error once the limit is hit."""
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValidationError, match="^2 errors:") as exc_info:
+            decode(self._table(), ToonDecodeOptions(max_errors=2))
+
+        assert len(exc_info.value.errors) == 2
+
+    def test_valid_document_has_no_errors(self):
+        """Test validate_toon returns an empty list for valid input."""
+        from toonverter.decoders import validate_toon
+
+        assert validate_toon("[2]{a,b}:\n  1,2\n  3,4") == []
+
+    def test_max_errors_must_be_positive(self):
+        """Test max_errors below 1 is rejected."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="max_errors"):
+            ToonDecodeOptions(max_errors=0)
+
+
+class TestRowErrorMarkers:
+    """Test row_errors="mark" replaces malformed tabular rows and keeps going."""
+
+    TABLE = (
+        "rows[5]{id,name}:\n"
+        "  1,Ada\n"
+        '  2,"Bob\n'
+        "  3,Cy,extra\n"
+        "  4,{\n"
+        "  5,Eve\n"
+        "total: 5"
+    )
+
+    def _mark(self, **kwargs):
+        from toonverter.core.spec import ToonDecodeOptions
+
+        return ToonDecodeOptions(row_errors="mark", **kwargs)
+
+    def test_malformed_rows_become_markers(self):
+        """Test each bad row is a marker while valid rows and later fields decode."""
+        result = decode(self.TABLE, self._mark())
+
+        rows = result["rows"]
+        assert rows[0] == {"id": 1, "name": "Ada"}
+        assert rows[1] == {
+            "__parse_error__": "Malformed row 2 (line 3): Unterminated quoted string "
+            "at line 3, column 5 near '2,\"Bob'"
+        }
+        assert rows[2] == {
+            "__parse_error__": "Row width mismatch at row 3 (line 4): expected 2, got 3"
+        }
+        assert rows[3] == {
+            "__parse_error__": "Invalid cell at row 4 (line 5), column 'name': "
+            "expected value, found '{'"
+        }
+        assert rows[4] == {"id": 5, "name": "Eve"}
+        assert result["total"] == 5
+
+    def test_raises_by_default(self):
+        """Test the first bad row still fails the decode without the option."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match="Unterminated quoted string"):
+            decode(self.TABLE)
+
+    def test_cell_conversion_errors_are_marked(self):
+        """Test errors raised while converting a cell mark only that row."""
+        options = self._mark(nonfinite_tokens="error")
+
+        result = decode("[2]{a,b}:\n  nan,1\n  2,3", options)
+
+        assert result[0] == {
+            "__parse_error__": "Unquoted non-finite number 'nan' at line 2; "
+            "quote it to keep it as a string"
+        }
+        assert result[1] == {"a": 2, "b": 3}
+
+    def test_errors_outside_rows_still_raise(self):
+        """Test only tabular rows are recovered."""
+        from toonverter.core.exceptions import DecodingError, ValidationError
+
+        with pytest.raises(DecodingError, match="Unterminated quoted string"):
+            decode('name: "Ada', self._mark())
+        with pytest.raises(ValidationError, match="Array length mismatch"):
+            decode("[3]{a}:\n  1\n  2", self._mark())
+
+    def test_through_facade(self):
+        """Test the option is accepted as a decode keyword."""
+        import toonverter
+
+        result = toonverter.decode("[2]{a,b}:\n  1,2,3\n  4,5", row_errors="mark")
+
+        assert result == [
+            {"__parse_error__": "Row width mismatch at row 1 (line 2): expected 2, got 3"},
+            {"a": 4, "b": 5},
+        ]
+
+    def test_invalid_row_errors_rejected(self):
+        """Test unknown row_errors values are rejected."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="row_errors"):
+            ToonDecodeOptions(row_errors="skip")  # type: ignore[arg-type]
+
+
+class TestAsPairs:
+    """Test as_pairs output and collection of repeated keys."""
+
+    CONFIG = (
+        "include: base.toon\n"
+        "name: app\n"
+        "include: db.toon\n"
+        "server:\n"
+        "  port: 80\n"
+        "  port: 8080\n"
+        "include: cache.toon"
+    )
+
+    def _decoder(self, **kwargs):
+        from toonverter.core.spec import ToonDecodeOptions
+
+        return ToonDecoder(ToonDecodeOptions(as_pairs=True, **kwargs))
+
+    def test_collect_keeps_every_include_in_order(self):
+        """Test all three include lines survive, interleaved with other keys."""
+        result = self._decoder(duplicate_keys="collect").decode(self.CONFIG)
+
+        assert result == [
+            ("include", "base.toon"),
+            ("name", "app"),
+            ("include", "db.toon"),
+            ("server", [("port", 80), ("port", 8080)]),
+            ("include", "cache.toon"),
+        ]
+
+    def test_pairs_without_collect_keep_last_value(self):
+        """Test repeated keys keep the last value at the first position."""
+        result = self._decoder().decode(self.CONFIG)
+
+        assert result == [
+            ("include", "cache.toon"),
+            ("name", "app"),
+            ("server", [("port", 8080)]),
+        ]
+
+    def test_default_keeps_dicts(self):
+        """Test as_pairs=False keeps today's last-wins dicts."""
+        assert decode(self.CONFIG) == {
+            "include": "cache.toon",
+            "name": "app",
+            "server": {"port": 8080},
+        }
+
+    def test_nested_arrays_and_rows(self):
+        """Test objects inside lists, tabular rows and inline objects become pairs."""
+        text = "rows[1]{id,ok}:\n  1,true\nitems[1]:\n  - k: 1\n    k: 2\ninline: {a: 1, a: 2}"
+
+        result = self._decoder(duplicate_keys="collect").decode(text)
+
+        assert result == [
+            ("rows", [[("id", 1), ("ok", True)]]),
+            ("items", [[("k", 1), ("k", 2)]]),
+            ("inline", [("a", 1), ("a", 2)]),
+        ]
+
+    def test_non_object_roots_and_empty_input(self):
+        """Test arrays of primitives are untouched and empty input is an empty pair list."""
+        decoder = self._decoder()
+        assert decoder.decode("[3]: 1,2,3") == [1, 2, 3]
+        assert decoder.decode("") == []
+        assert decoder.decode("a: {}") == [("a", [])]
+
+    def test_through_facade(self):
+        """Test the options are accepted as decode keywords."""
+        import toonverter
+
+        result = toonverter.decode("x: 1\nx: 2", as_pairs=True, duplicate_keys="collect")
+        assert result == [("x", 1), ("x", 2)]
+
+    def test_collect_requires_pairs(self):
+        """Test collect mode without as_pairs is rejected, since a dict cannot hold repeats."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="requires as_pairs"):
+            ToonDecodeOptions(duplicate_keys="collect")
+        with pytest.raises(ValueError, match="duplicate_keys"):
+            ToonDecodeOptions(as_pairs=True, duplicate_keys="first")  # type: ignore[arg-type]
+
+
+class TestMissingValues:
+    """Test keys whose colon is followed by nothing."""
+
+    def _decode(self, text, policy):
+        from toonverter.core.spec import ToonDecodeOptions
+
+        return decode(text, ToonDecodeOptions(missing_values=policy))
+
+    @pytest.mark.parametrize(("policy", "expected"), [("object", {}), ("null", None)])
+    def test_key_at_eof(self, policy, expected):
+        """Test a bare key on the last line."""
+        assert self._decode("a: 1\nkey:", policy) == {"a": 1, "key": expected}
+
+    @pytest.mark.parametrize(("policy", "expected"), [("object", {}), ("null", None)])
+    def test_key_mid_document(self, policy, expected):
+        """Test a bare key followed by a sibling and by a dedent does not take their lines."""
+        text = "a: 1\nkey:\nb: 2\nouter:\n  inner:\nc: 3"
+
+        assert self._decode(text, policy) == {
+            "a": 1,
+            "key": expected,
+            "b": 2,
+            "outer": {"inner": expected},
+            "c": 3,
+        }
+
+    @pytest.mark.parametrize(
+        ("text", "message"),
+        [
+            ("key:", "Missing value for key 'key' at line 1"),
+            ("a: 1\nkey:\nb: 2", "Missing value for key 'key' at line 2"),
+            ("outer:\n  inner:\nc: 3", "Missing value for key 'inner' at line 2"),
+            ("items[1]:\n  - id:", "Missing value for key 'id' at line 2"),
+            ("k: {a:, b: 1}", "Missing value for key 'a' at line 1"),
+        ],
+    )
+    def test_error_names_key_and_line(self, text, message):
+        """Test the error policy reports the key and the line of its colon."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match=f"^{message}$"):
+            self._decode(text, "error")
+
+    def test_nested_block_is_not_missing(self):
+        """Test a key with deeper lines is an object even under the error policy."""
+        assert self._decode("key:\n  a: 1", "error") == {"key": {"a": 1}}
+
+    def test_inline_object_missing_value(self):
+        """Test an empty inline value is not read as the delimiter or closing brace."""
+        assert decode("k: {a:, b: 1}") == {"k": {"a": {}, "b": 1}}
+        assert self._decode("k: {a: }", "null") == {"k": {"a": None}}
+
+    def test_invalid_policy_rejected(self):
+        """Test unknown missing_values values are rejected."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="missing_values"):
+            ToonDecodeOptions(missing_values="empty")  # type: ignore[arg-type]
+
+
+class TestEmptyListItems:
+    """Test list markers with nothing after them."""
+
+    def _decode(self, text, policy):
+        from toonverter.core.spec import ToonDecodeOptions
+
+        return decode(text, ToonDecodeOptions(empty_list_items=policy))
+
+    @pytest.mark.parametrize(("policy", "expected"), [("object", {}), ("null", None)])
+    def test_bare_marker_is_its_own_item(self, policy, expected):
+        """Test the next item is not taken as the bare marker's value."""
+        text = "[3]:\n  -\n  - item\n  -"
+
+        assert self._decode(text, policy) == [expected, "item", expected]
+
+    def test_sparse_nested_list(self):
+        """Test null items inside a keyed list followed by a sibling key."""
+        text = "a[3]:\n  - x\n  - \n  - y\nb: 1"
+
+        assert self._decode(text, "null") == {"a": ["x", None, "y"], "b": 1}
+
+    def test_deeper_fields_still_form_object(self):
+        """Test a bare marker followed by deeper lines is an object either way."""
+        text = "[2]:\n  -\n    id: 1\n  -"
+
+        assert self._decode(text, "null") == [{"id": 1}, None]
+
+    def test_default_round_trips_empty_object(self):
+        """Test the default keeps the encoder's form for [{}]."""
+        from toonverter.encoders import ToonEncoder
+
+        assert decode(ToonEncoder().encode([{}, 1])) == [{}, 1]
+
+    def test_invalid_policy_rejected(self):
+        """Test unknown empty_list_items values are rejected."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="empty_list_items"):
+            ToonDecodeOptions(empty_list_items="error")  # type: ignore[arg-type]
+
+
+class TestDecodeSpans:
+    """Test source spans recorded for editor tooling."""
+
+    DOC = (
+        "users[2]{id,name}:\n"
+        '  1,"A\\"l"\n'
+        "  ,B\n"
+        "items[2]:\n"
+        "  - k: v\n"
+        "    z: true\n"
+        "  - null\n"
+        "io: {a: 1}"
+    )
+
+    def test_value_matches_decode(self):
+        """Test decode_with_spans returns the same value as decode."""
+        from toonverter.decoders import ToonDecoder
+
+        value, _ = ToonDecoder().decode_with_spans(self.DOC)
+
+        assert value == decode(self.DOC)
+
+    def test_tabular_rows_and_cells(self):
+        """Test rows and cells get spans and empty cells get none."""
+        from toonverter.decoders import Span, ToonDecoder
+
+        _, spans = ToonDecoder().decode_with_spans(self.DOC)
+
+        assert spans["/users"] == Span(0, 0, 2, 4)
+        assert spans["/users/0"] == Span(1, 2, 1, 10)
+        assert spans["/users/0/name"] == Span(1, 4, 1, 10)
+        assert spans["/users/1/name"] == Span(2, 3, 2, 4)
+        assert "/users/1/id" not in spans
+
+    def test_list_items_and_fields(self):
+        """Test list items start at their dash and fields at their key."""
+        from toonverter.decoders import Span, ToonDecoder
+
+        _, spans = ToonDecoder().decode_with_spans(self.DOC)
+
+        assert spans["/items/0"] == Span(4, 2, 5, 11)
+        assert spans["/items/0/z"] == Span(5, 4, 5, 11)
+        assert spans["/items/1"] == Span(6, 2, 6, 8)
+        assert spans["/io/a"] == Span(7, 5, 7, 9)
+
+    def test_document_order_with_root_first(self):
+        """Test decode_spans lists parents before children in source order."""
+        from toonverter.decoders import decode_spans
+
+        paths = [path for path, _ in decode_spans(self.DOC)]
+
+        assert paths[:3] == ["", "/users", "/users/0"]
+        assert paths.index("/items") < paths.index("/items/0") < paths.index("/io")
+
+    def test_keys_are_escaped_pointers(self):
+        """Test keys with slashes use JSON Pointer escaping."""
+        from toonverter.decoders import Span, decode_spans
+
+        assert dict(decode_spans('"a/b": 1'))["/a~1b"] == Span(0, 0, 0, 8)
+
+    def test_plain_decode_records_nothing(self):
+        """Test spans are only tracked inside decode_with_spans."""
+        from toonverter.decoders import ToonDecoder
+
+        decoder = ToonDecoder()
+        decoder.decode_with_spans("a: 1")
+        decoder.decode("b: 2")
+
+        assert decoder._spans is None
+
+
+class TestDecodeComments:
+    """Test leading comments kept for the root and top-level keys."""
+
+    CONFIG = (
+        "# Service configuration\n"
+        "#   maintained by ops\n"
+        "\n"
+        "# Network\n"
+        "server:\n"
+        "  host: example.org\n"
+        "  port: 8080\n"
+        "# Accounts allowed to log in\n"
+        "users[2]{id,name}:\n"
+        "  1,Ada\n"
+        "  2,Bob\n"
+        "debug: false\n"
+        "#\n"
+        "# Feature switches\n"
+        "features[2]: search,export"
+    )
+
+    def test_section_comments(self):
+        """Test the root block and each section comment are collected by pointer."""
+        from toonverter.decoders import ToonDecoder
+
+        value, comments = ToonDecoder().decode_with_comments(self.CONFIG)
+
+        assert value == decode(self.CONFIG)
+        assert comments == {
+            "": ["Service configuration", "  maintained by ops"],
+            "/server": ["Network"],
+            "/users": ["Accounts allowed to log in"],
+            "/features": ["", "Feature switches"],
+        }
+
+    def test_round_trip(self):
+        """Test a commented config re-encodes to the same text."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.decoders import ToonDecoder
+        from toonverter.encoders import encode
+
+        value, comments = ToonDecoder().decode_with_comments(self.CONFIG)
+
+        assert encode(value, ToonEncodeOptions(comments=comments)) == self.CONFIG
+
+    def test_block_without_blank_line_belongs_to_first_key(self):
+        """Test a leading block directly above the first key is that key's."""
+        from toonverter.decoders import ToonDecoder
+
+        _, comments = ToonDecoder().decode_with_comments("#!toon indent=4\n# Port\nport: 80")
+
+        assert comments == {"/port": ["Port"]}
+
+    def test_other_comments_are_dropped(self):
+        """Test indented, trailing and detached comments are not collected."""
+        from toonverter.decoders import ToonDecoder
+
+        doc = "a:\n  # nested\n  b: 1  # trailing\n\n# detached\n\nc: 2"
+        _, comments = ToonDecoder().decode_with_comments(doc)
+
+        assert comments == {}
+
+    def test_non_object_root(self):
+        """Test leading comments of an array or comment-only document go to the root."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.decoders import ToonDecoder
+        from toonverter.encoders import encode
+
+        value, comments = ToonDecoder().decode_with_comments("# ids\n[2]: 1,2")
+        assert (value, comments) == ([1, 2], {"": ["ids"]})
+        assert encode(value, ToonEncodeOptions(comments=comments)) == "# ids\n\n[2]: 1,2"
+        assert ToonDecoder().decode_with_comments("# only")[1] == {"": ["only"]}
+
+    def test_encode_with_blank_lines(self):
+        """Test key comments sit between the separating blank line and the key."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.encoders import encode
+
+        options = ToonEncodeOptions(blank_lines=True, comments={"/b": ["second"]})
+        result = encode({"a": 1, "b": 2}, options)
+        assert result == "a: 1\n\n# second\nb: 2"
+
+    @pytest.mark.parametrize(
+        ("kwargs", "match"),
+        [
+            ({"comments": {"/a/b": ["x"]}}, "top-level pointer"),
+            ({"comments": {"a": ["x"]}}, "top-level pointer"),
+            ({"comments": {"": ["x\ny"]}}, "newlines"),
+            ({"comments": {"": ["x"]}, "indent_size": 0}, "indent_size > 0"),
+        ],
+    )
+    def test_invalid_options(self, kwargs, match):
+        """Test nested pointers, multi-line comments and compact mode are rejected."""
+        from toonverter.core.spec import ToonEncodeOptions
+
+        with pytest.raises(ValueError, match=match):
+            ToonEncodeOptions(**kwargs)
+
+
+class TestLongLines:
+    """Test max_line_length and bounded error messages for huge lines."""
+
+    def test_error_in_huge_line_is_short_and_names_column(self):
+        """Test an error at column 9,999,999 of a 10 MB line yields a short message."""
+        from toonverter.core.exceptions import DecodingError
+
+        padding = "a" * (9_999_998 - len('data: "'))
+        doc = 'data: "' + padding + '\\q' + "b" * 10 + '"'
+
+        with pytest.raises(DecodingError) as exc_info:
+            decode(doc)
+
+        message = str(exc_info.value)
+        assert len(message) < 300
+        assert "line 1, column 9999999" in message
+        assert "...aaa" in message
+
+    def test_columns_include_indentation(self):
+        """Test error columns count the indentation stripped before scanning."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match="line 2, column 8 near 'x: \"q'"):
+            decode('a:\n    x: "q')
+
+    def test_max_line_length_rejects_long_lines(self):
+        """Test lines over max_line_length raise LineTooLongError."""
+        from toonverter.core.exceptions import LineTooLongError, ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(max_line_length=10)
+
+        with pytest.raises(LineTooLongError, match="Line 2 is 14 characters long") as exc_info:
+            decode("a: 1\nb: abcdefghijk", options)
+
+        assert isinstance(exc_info.value, ValidationError)
+        assert decode("a: 1\nb: abcdefg", options) == {"a": 1, "b": "abcdefg"}
+
+    def test_stream_decoder_honors_max_line_length(self):
+        """Test the streaming decoder checks line length too."""
+        from toonverter.core.exceptions import LineTooLongError
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import StreamDecoder
+
+        decoder = StreamDecoder(ToonDecodeOptions(max_line_length=8))
+
+        with pytest.raises(LineTooLongError, match="Line 3"):
+            list(decoder.decode_stream(iter(["[2]:\n", "  - a\n", "  - abcdefgh\n"])))
+
+    def test_max_line_length_must_be_positive(self):
+        """Test max_line_length below 1 is rejected."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(ValueError, match="max_line_length"):
+            ToonDecodeOptions(max_line_length=0)
+
+
+class TestHexEscapes:
+    """Test \\xNN byte escapes in quoted strings."""
+
+    def test_hex_escape_decodes(self):
+        """Test \\x41 decodes to A, in values and keys."""
+        assert decode('a: "\\x41"') == {"a": "A"}
+        assert decode('"k\\x31": "\\xe9t\\xE9"') == {"k1": "été"}
+
+    def test_hex_escape_in_tabular_cells(self):
+        """Test hex escapes work in tabular rows and the streaming decoder."""
+        from toonverter.decoders import StreamDecoder
+
+        assert decode('[1]{a,b}:\n  "\\x2c","x\\x22"') == [{"a": ",", "b": 'x"'}]
+        assert list(StreamDecoder().decode_stream(iter(["[1]:\n", '  - "\\x41"\n']))) == ["A"]
+
+    def test_invalid_hex_digits_raise(self):
+        """Test non-hex digits are rejected with the escape position."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match=r"\\x4g needs two hex digits at line 1, column 5"):
+            decode('a: "\\x4g"')
+
+    def test_truncated_hex_escape_raises(self):
+        """Test an escape cut off at the end of the input is rejected."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match="needs two hex digits"):
+            decode('a: "\\x4')
+        with pytest.raises(DecodingError, match="needs two hex digits"):
+            decode('a: "\\x')
+
+
+class TestColumnEncoding:
+    """Test restoring delta and rle encoded tabular columns."""
+
+    TEXT = "rows[4]{ts:+delta,status:*rle,v}:\n  100,ok,1\n  -5,,2\n  null,null,3\n  7,,4"
+    ROWS = [
+        {"ts": 100, "status": "ok", "v": 1},
+        {"ts": 95, "status": "ok", "v": 2},
+        {"ts": None, "status": None, "v": 3},
+        {"ts": 102, "status": None, "v": 4},
+    ]
+
+    def test_decode(self):
+        """Test deltas accumulate past nulls and empty rle cells repeat."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        result = decode(self.TEXT, ToonDecodeOptions(column_encoding=True))
+        assert result == {"rows": self.ROWS}
+
+    def test_decode_columns_and_rows(self):
+        """Test column and row decoding restore the values too."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        decoder = ToonDecoder(ToonDecodeOptions(column_encoding=True))
+
+        columns = decoder.decode_columns(self.TEXT, key="rows", columns=["status", "v"])
+        assert columns == {"status": ["ok", "ok", None, None], "v": [1, 2, 3, 4]}
+        fields, rows = decoder.decode_rows(self.TEXT, key="rows")
+        assert fields == ["ts", "status", "v"]
+        assert rows == [tuple(row.values()) for row in self.ROWS]
+
+    def test_rejected_without_option(self):
+        """Test encoded headers need the option, so they are never misread."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match="'ts' is delta-encoded; decode with"):
+            decode(self.TEXT)
+
+    def test_unknown_annotation(self):
+        """Test a field annotation other than +delta or *rle is rejected."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        with pytest.raises(DecodingError, match="Unknown encoding 'zz' for tabular field 'a'"):
+            decode("[1]{a:zz}:\n  1", ToonDecodeOptions(column_encoding=True))
+
+    def test_unexpected_header_token(self):
+        """Test a stray token in a tabular header is an error, not a hang."""
+        from toonverter.core.exceptions import DecodingError
+
+        with pytest.raises(DecodingError, match=r"Unexpected '\[' in tabular header on line 1"):
+            decode("[1]{a[b}:\n  1")
+
+    def test_bad_cells(self):
+        """Test a non-integer delta and a leading empty rle cell are errors."""
+        from toonverter.core.exceptions import DecodingError
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(column_encoding=True)
+        with pytest.raises(DecodingError, match="Delta column 'ts' row 2 holds 1.5"):
+            decode("[2]{ts:+delta}:\n  1\n  1.5", options)
+        with pytest.raises(DecodingError, match="'s' row 1 is empty with no value to repeat"):
+            decode("[1]{s:*rle,v}:\n  ,1", options)
+
+    def test_bad_cells_marked(self):
+        """Test row_errors='mark' marks a bad encoded cell and keeps decoding."""
+        from toonverter.core.spec import PARSE_ERROR_KEY, ToonDecodeOptions
+
+        options = ToonDecodeOptions(column_encoding=True, row_errors="mark")
+        result = decode("[3]{ts:+delta}:\n  1\n  x\n  2", options)
+
+        assert result[0] == {"ts": 1}
+        assert PARSE_ERROR_KEY in result[1]
+        assert result[2] == {"ts": 3}
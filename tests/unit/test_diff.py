@@ -111,6 +111,35 @@ class TestToonDiffer:
         assert change.old_value == "int"
         assert change.new_value == "str"
 
+    def test_diff_int_float_strict_by_default(self):
+        result = ToonDiffer().diff({"a": 1.0}, {"a": 1})
+        assert result.changes[0].type == ChangeType.TYPE_CHANGE
+
+    def test_diff_loose_numbers(self):
+        differ = ToonDiffer(loose_numbers=True)
+        assert differ.diff({"a": 1.0, "b": [2, 3.5]}, {"b": [2.0, 3.5], "a": 1}).match
+
+        result = differ.diff({"a": 1.0}, {"a": 2})
+        assert result.changes[0].type == ChangeType.CHANGE
+        assert result.changes[0].new_value == 2
+
+    def test_diff_loose_numbers_keeps_bool_distinct(self):
+        result = ToonDiffer(loose_numbers=True).diff({"a": True}, {"a": 1})
+        assert result.changes[0].type == ChangeType.TYPE_CHANGE
+
+    def test_loosely_equal_round_trip(self):
+        import toonverter as toon
+        from toonverter.differ import loosely_equal
+
+        data = {"rows": [{"x": 1.0, "y": 2.5}], "n": 3.0}
+        decoded = toon.decode(toon.encode(data))
+        assert decoded["n"] == 3
+        assert type(decoded["n"]) is int
+        assert not toon.diff(data, decoded).match
+        assert toon.diff(data, decoded, loose_numbers=True).match
+        assert loosely_equal(data, decoded)
+        assert not loosely_equal(data, {**decoded, "n": 4})
+
 
 class TestDiffFormatter:
     @pytest.fixture
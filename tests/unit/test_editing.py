@@ -0,0 +1,120 @@
+"""Tests for splicing edits into TOON source text."""
+
+import pytest
+
+from toonverter import decode
+from toonverter.editing import ToonDocument, patch_toon
+
+
+SOURCE = """# service config
+server:
+  host: example.com   # production
+  port: 80
+
+users[2]{id,name}:
+  1,Ada
+  2,Bob
+items[2]:
+  - k: v
+    z: true
+  - null
+tags[2]: a,b
+"""
+
+
+def _unchanged_outside(original: str, patched: str, old: str, new: str) -> None:
+    """Assert ``patched`` is ``original`` with exactly one ``old`` block swapped for ``new``."""
+    start = original.index(old)
+    assert patched == original[:start] + new + original[start + len(old) :]
+
+
+class TestPatchToon:
+    """Test patch_toon rewrites only the changed field."""
+
+    def test_modify_scalar(self):
+        """Test changing a scalar keeps comments and blank lines byte-identical."""
+        patched = patch_toon(SOURCE, "/server/port", 8080)
+
+        _unchanged_outside(SOURCE, patched, "port: 80\n", "port: 8080\n")
+        assert decode(patched)["server"]["port"] == 8080
+
+    def test_replace_tabular_array(self):
+        """Test a whole tabular array is re-encoded in place."""
+        patched = patch_toon(SOURCE, "/users", [{"id": 3, "name": "Cy"}])
+
+        _unchanged_outside(
+            SOURCE, patched, "users[2]{id,name}:\n  1,Ada\n  2,Bob", "users[1]{id,name}:\n  3,Cy"
+        )
+
+    def test_tabular_cell_rewrites_its_array(self):
+        """Test a cell edit re-encodes the enclosing tabular field only."""
+        patched = patch_toon(SOURCE, "/users/1/name", "Bo")
+
+        _unchanged_outside(SOURCE, patched, "  2,Bob", "  2,Bo")
+
+    def test_insert_key_into_nested_object(self):
+        """Test a new key goes after the object's last field at its indentation."""
+        patched = patch_toon(SOURCE, "/server/tls", {"cert": "x.pem"})
+
+        _unchanged_outside(SOURCE, patched, "port: 80\n", "port: 80\n  tls:\n    cert: x.pem\n")
+        assert decode(patched)["server"]["tls"] == {"cert": "x.pem"}
+
+    def test_insert_key_at_root(self):
+        """Test a new root key is appended after the last root field."""
+        patched = patch_toon(SOURCE, "/debug", False)
+
+        assert patched == SOURCE.replace("tags[2]: a,b\n", "tags[2]: a,b\ndebug: false\n")
+
+    def test_list_item_fields(self):
+        """Test edits inside a list item object keep the item's indentation."""
+        replaced = patch_toon(SOURCE, "/items/0/k", {"deep": [1, 2]})
+        inserted = patch_toon(SOURCE, "/items/0/n", 5)
+
+        _unchanged_outside(SOURCE, replaced, "- k: v\n", "- k:\n      deep[2]: 1,2\n")
+        _unchanged_outside(SOURCE, inserted, "z: true\n", "z: true\n    n: 5\n")
+        assert decode(inserted)["items"][0] == {"k": "v", "z": True, "n": 5}
+
+    def test_append_to_inline_array(self):
+        """Test "-" appends to an array by re-encoding its field."""
+        patched = patch_toon(SOURCE, "/tags/-", "c")
+
+        _unchanged_outside(SOURCE, patched, "tags[2]: a,b", "tags[3]: a,b,c")
+
+    def test_root_pointer_replaces_document(self):
+        """Test the empty pointer re-encodes the whole document."""
+        assert patch_toon(SOURCE, "", {"x": 1}) == "x: 1"
+
+    def test_indent_unit_of_document_is_kept(self):
+        """Test nested output uses the document's indent size."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        source = "a:\n    b: 1\n"
+        patched = patch_toon(source, "/a/c", {"d": 2}, ToonDecodeOptions(indent_size=None))
+
+        assert patched == "a:\n    b: 1\n    c:\n        d: 2\n"
+
+    def test_missing_parent_raises(self):
+        """Test setting below a missing value is a LookupError."""
+        with pytest.raises(LookupError, match="/nope"):
+            patch_toon(SOURCE, "/nope/x", 1)
+        with pytest.raises(LookupError, match="out of range"):
+            patch_toon(SOURCE, "/tags/5", "x")
+
+
+class TestToonDocument:
+    """Test the ToonDocument wrapper."""
+
+    def test_exposes_value_and_spans(self):
+        """Test the decoded value and spans are available."""
+        doc = ToonDocument(SOURCE)
+
+        assert doc.value == decode(SOURCE)
+        assert doc.spans["/server/port"].start_line == 3
+
+    def test_splice_does_not_modify_document(self):
+        """Test splice returns new text and leaves the document as it was."""
+        doc = ToonDocument(SOURCE)
+        doc.splice("/server/port", 1)
+
+        assert doc.text == SOURCE
+        assert doc.value["server"]["port"] == 80
@@ -464,3 +464,74 @@ class TestEdgeCases:
         result = folder.unfold_key("a.b", True)
 
         assert result == {"a": {"b": True}}
+
+
+class TestCollapseAndExpandRoundtrip:
+    """Test folded output decodes back with expand_paths="safe"."""
+
+    def test_roundtrip_chain_endings(self):
+        """Test chains ending in primitives, objects, lists and empty objects."""
+        from toonverter.core.spec import ToonDecodeOptions, ToonEncodeOptions
+        from toonverter.decoders import decode
+        from toonverter.encoders import encode
+
+        cases = [
+            ({"a": {"b": {"c": 1}}}, "a.b.c: 1"),
+            ({"a": {"b": {"c": 1, "d": 2}}}, "a.b:\n  c: 1\n  d: 2"),
+            ({"a": {"b": [1, 2]}}, "a.b[2]: 1,2"),
+            ({"a": {"b": [{"x": 1}, {"x": 2}]}}, "a.b[2]{x}:\n  1\n  2"),
+            ({"a": {"b": {}}}, "a.b:"),
+            ({"z": [{"p": {"q": 1}}]}, "z[1]:\n  - p.q: 1"),
+        ]
+        for data, expected in cases:
+            toon_str = encode(data, ToonEncodeOptions(key_folding="safe"))
+            assert toon_str == expected
+            assert decode(toon_str, ToonDecodeOptions(expand_paths="safe")) == data
+        # Without expand_paths a dotted key stays literal
+        assert decode("a.b.c: 1") == {"a.b.c": 1}
+
+    def test_literal_dotted_keys_are_quoted(self):
+        """Test keys containing dots stay literal through the round-trip."""
+        from toonverter.core.spec import ToonDecodeOptions, ToonEncodeOptions
+        from toonverter.decoders import decode
+        from toonverter.encoders import encode
+
+        data = {"x": {"y": 1}, "x.y": 2, "v1.2": {"n": {"m": 3}}}
+        toon_str = encode(data, ToonEncodeOptions(key_folding="safe"))
+
+        assert '"x.y": 2' in toon_str
+        assert '"v1.2":' in toon_str
+        assert decode(toon_str, ToonDecodeOptions(expand_paths="safe")) == data
+
+    def test_unsafe_segments_are_not_folded(self):
+        """Test segments that need quoting break the chain."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.encoders import encode
+
+        toon_str = encode({"a": {"x y": {"c": 1}}}, ToonEncodeOptions(key_folding="safe"))
+        assert toon_str == 'a:\n  "x y":\n    c: 1'
+
+    def test_expansion_merges_and_detects_conflicts(self):
+        """Test expanded paths merge, and overwrites fail only in strict mode."""
+        import pytest
+
+        from toonverter.core.exceptions import ValidationError
+        from toonverter.core.spec import ToonDecodeOptions
+        from toonverter.decoders import decode
+
+        options = ToonDecodeOptions(expand_paths="safe")
+        assert decode("a.b: 1\na.c: 2", options) == {"a": {"b": 1, "c": 2}}
+        assert decode('"a.b": 1', options) == {"a.b": 1}
+
+        with pytest.raises(ValidationError, match="Path expansion conflict"):
+            decode("a: 1\na.b: 2", options)
+        lenient = ToonDecodeOptions(expand_paths="safe", strict=False)
+        assert decode("a: 1\na.b: 2", lenient) == {"a": {"b": 2}}
+
+    def test_facade_options(self):
+        """Test the user-facing option names on encode and decode."""
+        import toonverter as toon
+
+        toon_str = toon.encode({"a": {"b": {"c": 1}}}, collapse_single_key_chains=True)
+        assert toon_str == "a.b.c: 1"
+        assert toon.decode(toon_str, expand_paths="safe") == {"a": {"b": {"c": 1}}}
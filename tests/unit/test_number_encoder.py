@@ -101,6 +101,109 @@ class TestNumberEncoderEncoding:
         assert self.encoder.encode(1.23456789) == "1.23456789"
 
 
+class TestNumberEncoderFloatPrecision:
+    """Test rounding floats to significant digits."""
+
+    def test_precision_cleans_binary_artifacts(self):
+        """Test 0.1 + 0.2 with precision 2 encodes as 0.3."""
+        assert NumberEncoder(float_precision=2).encode(0.1 + 0.2) == "0.3"
+
+    def test_precision_counts_significant_digits(self):
+        """Test precision applies to significant digits, not decimal places."""
+        encoder = NumberEncoder(float_precision=3)
+        assert encoder.encode(123.456) == "123"
+        assert encoder.encode(0.00123456) == "0.00123"
+        assert encoder.encode(-2.71828) == "-2.72"
+
+    def test_precision_large_values_stay_canonical(self):
+        """Test rounding large floats never produces exponent notation."""
+        assert NumberEncoder(float_precision=2).encode(123456.7) == "120000"
+
+    def test_precision_leaves_integers_untouched(self):
+        """Test ints are not rounded."""
+        assert NumberEncoder(float_precision=1).encode(123456) == "123456"
+
+    def test_precision_rounds_to_whole_number(self):
+        """Test a float rounded up to a whole number drops the decimal point."""
+        assert NumberEncoder(float_precision=2).encode(9.99) == "10"
+
+
+class TestNumberEncoderNegativeZero:
+    """Test the preserve_negative_zero option."""
+
+    def test_negative_zero_keeps_sign(self):
+        """Test -0.0 encodes with its sign and a fraction."""
+        assert NumberEncoder(preserve_negative_zero=True).encode(-0.0) == "-0.0"
+
+    def test_other_zeros_unaffected(self):
+        """Test 0.0 and integer zero still encode as 0."""
+        encoder = NumberEncoder(preserve_negative_zero=True)
+        assert encoder.encode(0.0) == "0"
+        assert encoder.encode(0) == "0"
+
+    def test_round_trip_through_decoder(self):
+        """Test the sign survives encode and decode in fields and arrays."""
+        import math
+
+        import toonverter as toon
+
+        toon_str = toon.encode({"z": -0.0, "p": 0.0, "v": [1, -0.0]}, preserve_negative_zero=True)
+        result = toon.decode(toon_str)
+
+        assert toon_str == "z: -0.0\np: 0\nv[2]: 1,-0.0"
+        assert math.copysign(1.0, result["z"]) == -1.0
+        assert math.copysign(1.0, result["v"][1]) == -1.0
+        assert result["p"] == 0
+
+    def test_default_drops_sign(self):
+        """Test the default output is unchanged."""
+        import toonverter as toon
+
+        assert toon.encode({"z": -0.0}) == "z: 0"
+
+
+class TestNumberEncoderIntAsFloat:
+    """Test the int_as_float option."""
+
+    def test_integers_get_a_fraction(self):
+        """Test integers, whole floats and zero are written with .0."""
+        encoder = NumberEncoder(int_as_float=True)
+        assert encoder.encode(1) == "1.0"
+        assert encoder.encode(2.0) == "2.0"
+        assert encoder.encode(0) == "0.0"
+        assert encoder.encode(-7) == "-7.0"
+
+    def test_fractions_and_nonfinite_unchanged(self):
+        """Test floats with a fraction and NaN keep their usual form."""
+        encoder = NumberEncoder(int_as_float=True)
+        assert encoder.encode(1.5) == "1.5"
+        assert encoder.encode(float("inf")) == "null"
+
+    def test_large_integer_keeps_its_digits(self):
+        """Test big integers are not rounded through float."""
+        assert NumberEncoder(int_as_float=True).encode(10**20 + 1) == "100000000000000000001.0"
+
+    def test_mixed_column_decodes_as_floats(self):
+        """Test [1, 2.0] round-trips to [1.0, 2.0] and booleans stay booleans."""
+        import toonverter as toon
+
+        toon_str = toon.encode({"v": [1, 2.0], "ok": True}, int_as_float=True)
+        result = toon.decode(toon_str)
+
+        assert toon_str == "v[2]: 1.0,2.0\nok: true"
+        assert result == {"v": [1.0, 2.0], "ok": True}
+        assert all(isinstance(item, float) for item in result["v"])
+
+    def test_tabular_column_is_uniform(self):
+        """Test every cell of a numeric tabular column decodes as a float."""
+        import toonverter as toon
+
+        rows = [{"id": "a", "score": 3}, {"id": "b", "score": 4.5}]
+        result = toon.decode(toon.encode(rows, int_as_float=True))
+
+        assert [type(row["score"]) for row in result] == [float, float]
+
+
 class TestNumberEncoderDecoding:
     """Test number decoding functionality."""
 
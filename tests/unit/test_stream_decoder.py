@@ -43,6 +43,28 @@ class TestToonStreamDecoder:
         assert items[0] == {"name": "Alice", "age": 30}
         assert items[1] == {"name": "Bob", "age": 25}
 
+    def test_stream_encoder_block_list_objects(self, stream_decoder: StreamDecoder) -> None:
+        """Test streaming objects written with their first field on the dash line."""
+        toon = "[2]:\n  - name: Alice\n    tags[2]: a,b\n  - name: Bob\n    age: 25\n"
+
+        items = list(stream_decoder.decode_stream(stream_from_string(toon)))
+        assert items == [{"name": "Alice", "tags": ["a", "b"]}, {"name": "Bob", "age": 25}]
+
+    def test_stream_multi_level_dedent(self, stream_decoder: StreamDecoder) -> None:
+        """Test an item ending three levels deep is followed by the next item."""
+        toon = (
+            "[2]:\n"
+            "  - inner[1]:\n"
+            "      - [1]:\n"
+            "        - deep: 1\n"
+            "    sib:\n"
+            "      k: 1\n"
+            "  - a: 2\n"
+        )
+
+        items = list(stream_decoder.decode_stream(stream_from_string(toon)))
+        assert items == [{"inner": [[{"deep": 1}]], "sib": {"k": 1}}, {"a": 2}]
+
     def test_stream_inline_array(self, stream_decoder: StreamDecoder) -> None:
         """Test streaming an inline array (fallback logic)."""
         toon = "[3]: 1, 2, 3"
@@ -116,3 +138,42 @@ class TestToonStreamDecoder:
         # Fallback yields the single parsed object
         assert len(items) == 1
         assert items[0] == {"name": "Alice", "age": 30}
+
+    def test_as_pairs_collects_duplicate_keys(self) -> None:
+        """Test items honour as_pairs and duplicate_keys="collect" like decode."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(as_pairs=True, duplicate_keys="collect")
+        toon = "[2]:\n  - include: a\n    include: b\n    include: c\n  - 1\n"
+        items = list(StreamDecoder(options).decode_stream(stream_from_string(toon)))
+
+        assert items == [[("include", "a"), ("include", "b"), ("include", "c")], 1]
+
+    def test_as_pairs_root_object_fallback(self) -> None:
+        """Test the non-streaming root object is returned as pairs too."""
+        from toonverter.core.spec import ToonDecodeOptions
+
+        options = ToonDecodeOptions(as_pairs=True, duplicate_keys="collect")
+        toon = "include: a\ninclude: b\nn:\n  x: 1"
+        items = list(StreamDecoder(options).decode_stream(stream_from_string(toon)))
+
+        assert items == [[("include", "a"), ("include", "b"), ("n", [("x", 1)])]]
+
+    def test_source_errors_propagate(self, stream_decoder: StreamDecoder) -> None:
+        """Test errors from the caller's iterator are not relabelled as decoding errors."""
+
+        def failing_source() -> Iterator[str]:
+            yield "[2]:\n"
+            yield "  - 1\n"
+            raise KeyError("source failed")
+
+        with pytest.raises(KeyError, match="source failed"):
+            list(stream_decoder.decode_stream(failing_source()))
+
+    def test_lexer_and_parser_errors_wrapped(self, stream_decoder: StreamDecoder) -> None:
+        """Test malformed lines and items still raise DecodingError."""
+        from toonverter.core.exceptions import DecodingError
+
+        for toon in ('[1]:\n  - "open\n', "[1]:\n  - a: [x\n", "a: {b\n"):
+            with pytest.raises(DecodingError):
+                list(stream_decoder.decode_stream(stream_from_string(toon)))
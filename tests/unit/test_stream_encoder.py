@@ -183,3 +183,79 @@ class TestToonStreamEncoder:
         stream_gen = stream_encoder.iterencode(stream_list)
         result = "".join(stream_gen)
         assert "[0]:" in result
+
+
+class TestIterencodeRows:
+    """Test tabular encoding of rows pulled from an iterable."""
+
+    def test_generator_matches_standard_encoder(self, standard_encoder: ToonEncoder) -> None:
+        """Test a generator of rows encodes like the equivalent list."""
+        rows = [{"id": i, "name": f"User {i}", "active": i % 2 == 0} for i in range(5)]
+
+        result = "".join(ToonStreamEncoder().iterencode_rows((r for r in rows), 5, key="users"))
+
+        assert result == standard_encoder.encode({"users": rows})
+
+    def test_root_array(self, standard_encoder: ToonEncoder) -> None:
+        """Test rows without a key encode as a root tabular array."""
+        rows = [{"a": 1, "b": "x,y"}, {"a": 2, "b": None}]
+
+        result = "".join(ToonStreamEncoder().iterencode_rows(iter(rows), 2))
+
+        assert result == standard_encoder.encode(rows)
+
+    def test_rows_are_pulled_lazily(self) -> None:
+        """Test only the rows needed for the chunks consumed are produced."""
+        pulled = []
+
+        def source():
+            for i in range(1_000_000):
+                pulled.append(i)
+                yield {"n": i}
+
+        chunks = ToonStreamEncoder().iterencode_rows(source(), 1_000_000)
+        assert next(chunks) == "[1000000]{n}:"
+        assert next(chunks) == "\n  0"
+        assert next(chunks) == "\n  1"
+        assert len(pulled) == 2
+
+    def test_explicit_fields_and_delimiter(self) -> None:
+        """Test explicit field order and a pipe delimiter."""
+        from toonverter.core.spec import Delimiter, ToonEncodeOptions
+
+        encoder = ToonStreamEncoder(ToonEncodeOptions(delimiter=Delimiter.PIPE))
+        rows = ({"a": i, "b": f"x|{i}", "c": "unused"} for i in range(2))
+
+        result = "".join(encoder.iterencode_rows(rows, 2, fields=["b", "a"], key="t"))
+
+        assert result == 't[2|]{b|a}:\n  "x|0"|0\n  "x|1"|1'
+
+    def test_empty_iterable(self) -> None:
+        """Test an empty iterable encodes as an empty array."""
+        assert "".join(ToonStreamEncoder().iterencode_rows(iter([]), 0, key="t")) == "t[0]:"
+
+    def test_length_mismatch(self) -> None:
+        """Test a row count different from the declared length raises."""
+        from toonverter.core.exceptions import EncodingError
+
+        with pytest.raises(EncodingError, match="Declared 3 rows but the iterable yielded 2"):
+            "".join(ToonStreamEncoder().iterencode_rows(iter([{"a": 1}, {"a": 2}]), 3))
+
+    def test_missing_field(self) -> None:
+        """Test a row without one of the fields raises."""
+        from toonverter.core.exceptions import EncodingError
+
+        with pytest.raises(EncodingError, match="Row 2 has no field 'b'"):
+            "".join(ToonStreamEncoder().iterencode_rows(iter([{"a": 1, "b": 2}, {"a": 3}]), 2))
+
+    def test_column_encoding_matches_standard_encoder(self) -> None:
+        """Test delta and rle columns stream like the standard encoder writes them."""
+        from toonverter.core.spec import ToonEncodeOptions
+
+        options = ToonEncodeOptions(column_encoding={"ts": "delta", "status": "rle"})
+        rows = [{"status": "ok" if i < 3 else None, "ts": 10 * i} for i in range(5)]
+
+        result = "".join(ToonStreamEncoder(options).iterencode_rows(iter(rows), 5, key="t"))
+
+        assert result == ToonEncoder(options).encode({"t": rows})
+        assert result.startswith("t[5]{status:*rle,ts:+delta}:\n  ok,0\n  ,10\n")
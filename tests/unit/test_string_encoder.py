@@ -168,6 +168,18 @@ class TestStringEncoderDecoding:
         with pytest.raises(ValueError, match="Unterminated escape sequence"):
             self.encoder.decode('"test\\"')
 
+    def test_decode_hex_escape(self):
+        """Test \\xNN escapes decode to the character with that code point."""
+        assert self.encoder.decode('"\\x41"') == "A"
+        assert self.encoder.decode('"caf\\xE9 \\x7e"') == "café ~"
+
+    def test_decode_invalid_hex_escape_raises_error(self):
+        """Test \\x needs exactly two hex digits."""
+        with pytest.raises(ValueError, match="needs two hex digits"):
+            self.encoder.decode('"\\x4g"')
+        with pytest.raises(ValueError, match="needs two hex digits"):
+            self.encoder.decode('"\\x4"')
+
     def test_decode_single_quote_not_quoted(self):
         """Test single quote character is not treated as quoted."""
         # String with only opening quote is not quoted
@@ -229,3 +241,53 @@ class TestStringEncoderRoundtrip:
         encoded = self.encoder.encode(s)
         decoded = self.encoder.decode(encoded)
         assert decoded == s
+
+
+class TestReservedLiterals:
+    """Test custom reserved literals."""
+
+    def test_custom_literals_are_quoted(self):
+        """Test strings equal to a custom literal are quoted, ignoring case."""
+        encoder = StringEncoder(Delimiter.COMMA, ["yes", "no"])
+
+        assert encoder.encode("yes") == '"yes"'
+        assert encoder.encode("NO") == '"NO"'
+        assert encoder.encode("yesterday") == "yesterday"
+        assert encoder.encode("true") == '"true"'
+
+    def test_keys_stay_bare(self):
+        """Test keys equal to a custom literal are not quoted."""
+        assert StringEncoder(Delimiter.COMMA, ["yes"]).encode_key("yes") == "yes"
+
+    def test_literal_keys_quoted_only_in_headers(self):
+        """Test reserved-word and numeric keys are quoted as tabular field names only."""
+        encoder = StringEncoder(Delimiter.PIPE)
+
+        assert [encoder.encode_key(k) for k in ("null", "-1", "a,b")] == ["null", "-1", '"a,b"']
+        assert [encoder.encode_key(k, header=True) for k in ("null", "-1", "a,b")] == [
+            '"null"',
+            '"-1"',
+            "a,b",
+        ]
+
+    def test_encoder_option(self):
+        """Test reserved_literals reaches values in every encoder path."""
+        from toonverter.core.spec import ToonEncodeOptions
+        from toonverter.encoders import ToonEncoder, ToonStreamEncoder
+
+        options = ToonEncodeOptions(reserved_literals=("yes", "no"))
+        data = {"answer": "yes", "tags": ["no", "maybe"], "rows": [{"ok": "yes"}]}
+
+        expected = 'answer: "yes"\ntags[2]: "no",maybe\nrows[1]{ok}:\n  "yes"'
+        assert ToonEncoder(options).encode(data) == expected
+        assert 'answer: "yes"' in "".join(ToonStreamEncoder(options).iterencode(data))
+        assert ToonEncoder().encode({"answer": "yes"}) == "answer: yes"
+
+    def test_facade_round_trip(self):
+        """Test the facade option and that quoted literals decode as strings."""
+        import toonverter as toon
+
+        text = toon.encode({"a": "yes", "b": True}, reserved_literals=["yes"])
+
+        assert text == 'a: "yes"\nb: true'
+        assert toon.decode(text) == {"a": "yes", "b": True}
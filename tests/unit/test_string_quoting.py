@@ -32,6 +32,12 @@ class TestQuotingRequired:
         assert self.encoder.encode("test ").startswith('"')
         assert self.encoder.encode("test\t").startswith('"')
 
+    def test_comment_marker_quoted(self):
+        """Strings that could be read as a comment must be quoted."""
+        assert self.encoder.encode("#tag") == '"#tag"'
+        assert self.encoder.encode("val #note") == '"val #note"'
+        assert self.encoder.encode("val#note") == "val#note"
+
     def test_reserved_words_quoted(self):
         """Reserved words must be quoted."""
         assert self.encoder.encode("true") == '"true"'
@@ -128,3 +134,38 @@ class TestEscapeSequences:
         """Carriage returns must be escaped."""
         result = self.encoder.encode("text\rmore")
         assert "\\r" in result
+
+
+class TestSurroundingWhitespace:
+    """Test leading/trailing spaces survive encode and decode."""
+
+    def test_padded_value_roundtrip(self):
+        """Padded values are quoted and keep their spaces."""
+        from toonverter import decode, encode
+
+        data = {"a": "  padded  ", "rows": [{"x": " l", "y": "t "}], "items": [" i "]}
+        toon_str = encode(data)
+
+        assert 'a: "  padded  "' in toon_str
+        assert decode(toon_str) == data
+
+    def test_padded_key_roundtrip(self):
+        """Padded keys are quoted in fields, array headers and nested objects."""
+        from toonverter import decode, encode
+
+        data = {" k ": "v", "a b": [1, 2], " x": {"y ": 1}, "t ": [{" c ": 1}]}
+        toon_str = encode(data)
+
+        assert '" k ": v' in toon_str
+        assert '"a b"[2]: 1,2' in toon_str
+        assert decode(toon_str) == data
+
+    def test_padded_key_stream_encoder(self):
+        """The streaming encoder quotes padded keys too."""
+        from toonverter import decode
+        from toonverter.encoders.stream_encoder import ToonStreamEncoder
+
+        data = {" k ": "v", "n": {" m": [1]}}
+        toon_str = "".join(ToonStreamEncoder().iterencode(data))
+
+        assert decode(toon_str) == data
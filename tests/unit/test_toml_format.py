@@ -238,3 +238,72 @@ class TestTOMLLegacy:
         ):
             assert self.adapter.validate("valid") is True
             mock_toml.loads.assert_called_once()
+
+
+class TestTomlNullPolicy:
+    """Test the toml_null handling of None values."""
+
+    DATA = {"name": "svc", "owner": None, "tags": ["a", None], "db": {"host": None, "port": 5432}}
+
+    def test_omit(self):
+        """Test omit drops keys and array items holding None."""
+        from toonverter.formats.toml_format import apply_toml_null_policy
+
+        result = apply_toml_null_policy(self.DATA, "omit")
+        assert result == {"name": "svc", "tags": ["a"], "db": {"port": 5432}}
+
+    def test_empty_string(self):
+        """Test empty_string replaces None with an empty string."""
+        from toonverter.formats.toml_format import apply_toml_null_policy
+
+        result = apply_toml_null_policy(self.DATA, "empty_string")
+        assert result["owner"] == ""
+        assert result["tags"] == ["a", ""]
+        assert result["db"] == {"host": "", "port": 5432}
+
+    def test_error_reports_path(self):
+        """Test error names the pointer of the first None value."""
+        from toonverter.core.exceptions import EncodingError
+        from toonverter.formats.toml_format import apply_toml_null_policy
+
+        with pytest.raises(EncodingError, match="TOML has no null value at /owner"):
+            apply_toml_null_policy(self.DATA, "error")
+
+    def test_adapter_applies_policy(self):
+        """Test the adapter filters None before writing."""
+        from unittest.mock import MagicMock, patch
+
+        from toonverter.core.types import EncodeOptions
+
+        mock_writer = MagicMock()
+        mock_writer.dumps.return_value = "ok"
+        with (
+            patch("toonverter.formats.toml_format.TOML_WRITE_AVAILABLE", True),
+            patch("toonverter.formats.toml_format.tomli_w", mock_writer, create=True),
+        ):
+            TOMLFormat().encode(self.DATA, EncodeOptions(toml_null="omit"))
+        mock_writer.dumps.assert_called_once_with(
+            {"name": "svc", "tags": ["a"], "db": {"port": 5432}}
+        )
+
+    def test_adapter_rejects_null_by_default(self):
+        """Test None values are rejected without an explicit policy."""
+        from toonverter.core.exceptions import EncodingError
+
+        with pytest.raises(EncodingError, match="TOML has no null value at /db/host"):
+            TOMLFormat().encode({"db": {"host": None}})
+
+    def test_toon_to_toml_arrays_of_tables(self):
+        """Test nested tabular arrays become TOML arrays of tables."""
+        pytest.importorskip("tomli_w")
+        import tomllib
+
+        import toonverter as toon
+
+        text = "servers[2]{name,port,alias}:\n  a,80,null\n  b,443,web"
+        result = toon.toon_to_toml(text, toml_null="omit")
+
+        assert "[[servers]]" in result
+        assert tomllib.loads(result) == {
+            "servers": [{"name": "a", "port": 80}, {"name": "b", "port": 443, "alias": "web"}]
+        }
@@ -2,7 +2,7 @@ from collections.abc import Iterator
 
 from toonverter.core.spec import ToonDecodeOptions
 from toonverter.core.types import DecodeOptions
-from toonverter.formats.toon_format import ToonFormatAdapter, _convert_decode_options
+from toonverter.formats.toon_format import ToonFormatAdapter, convert_decode_options
 
 
 class TestToonFormatAdapter:
@@ -24,16 +24,16 @@ class TestToonFormatAdapter:
         assert adapter.validate(invalid_toon) is False
 
     def test_convert_decode_options_none(self):
-        assert _convert_decode_options(None) is None
+        assert convert_decode_options(None) is None
 
     def test_convert_decode_options_toon_options(self):
         options = ToonDecodeOptions(strict=True)
-        converted = _convert_decode_options(options)
+        converted = convert_decode_options(options)
         assert converted is options
 
     def test_convert_decode_options_base_options(self):
         options = DecodeOptions(strict=True, type_inference=False)
-        converted = _convert_decode_options(options)
+        converted = convert_decode_options(options)
         assert isinstance(converted, ToonDecodeOptions)
         assert converted.strict is True
         assert converted.type_inference is False
@@ -1,11 +1,13 @@
 """Comprehensive tests for utility modules."""
 
+import os
 from pathlib import Path
 
 import pytest
 
 from toonverter.core.exceptions import FileOperationError, ValidationError
-from toonverter.utils.io import read_file, write_file
+from toonverter.utils.io import read_file, read_stream, write_file, write_stream
+from toonverter.utils.pointer import format_pointer, parse_pointer, project, resolve_pointer
 from toonverter.utils.validation import (
     validate_data_not_empty,
     validate_file_exists,
@@ -65,6 +67,39 @@ class TestFileIO:
         result = read_file(str(file_path))
         assert result == ""
 
+    def test_read_file_drops_utf8_bom(self, tmp_path):
+        """Test a leading UTF-8 byte order mark is not part of the content."""
+        file_path = tmp_path / "bom.txt"
+        file_path.write_text('{"a": 1}', encoding="utf-8-sig")
+
+        assert read_file(str(file_path)) == '{"a": 1}'
+
+    def test_read_file_utf16_rejected(self, tmp_path):
+        """Test UTF-16 files get a targeted message."""
+        file_path = tmp_path / "wide.txt"
+        file_path.write_text("a: 1", encoding="utf-16")
+
+        with pytest.raises(FileOperationError, match="file appears to be UTF-16"):
+            read_file(str(file_path))
+
+    @pytest.mark.parametrize(("existing", "expected"), [("a\n", "a\nb"), ("a", "a\nb"), ("", "b")])
+    def test_write_file_append(self, tmp_path, existing, expected):
+        """Test appended content starts on its own line without doubling newlines."""
+        file_path = tmp_path / "log.txt"
+        file_path.write_text(existing)
+
+        write_file(str(file_path), "b", append=True)
+
+        assert file_path.read_text() == expected
+
+    def test_write_file_append_creates_missing_file(self, tmp_path):
+        """Test append on a missing file writes it normally."""
+        file_path = tmp_path / "sub" / "new.txt"
+
+        write_file(str(file_path), "b", append=True)
+
+        assert file_path.read_text() == "b"
+
     def test_write_file_multiline(self, tmp_path):
         """Test writing multiline content."""
         file_path = tmp_path / "multiline.txt"
@@ -75,6 +110,118 @@ class TestFileIO:
 
         assert result == content
 
+    def test_write_file_max_bytes_allows_exact_size(self, tmp_path):
+        """Test content exactly at the cap is written."""
+        file_path = tmp_path / "exact.txt"
+
+        write_file(str(file_path), "abcd", max_bytes=4)
+
+        assert file_path.read_text() == "abcd"
+
+    def test_write_file_max_bytes_exceeded_leaves_no_file(self, tmp_path):
+        """Test exceeding the cap raises and leaves nothing behind."""
+        file_path = tmp_path / "capped.txt"
+
+        with pytest.raises(FileOperationError, match="exceeding limit of 4"):
+            write_file(str(file_path), "abcde", max_bytes=4)
+
+        assert list(tmp_path.iterdir()) == []
+
+    def test_write_file_max_bytes_counts_encoded_bytes(self, tmp_path):
+        """Test the cap applies to UTF-8 bytes, not characters."""
+        with pytest.raises(FileOperationError, match="6 bytes"):
+            write_file(str(tmp_path / "utf8.txt"), "äöü", max_bytes=5)
+
+    def test_write_file_exceeded_keeps_existing_content(self, tmp_path):
+        """Test a rejected write does not truncate an existing file."""
+        file_path = tmp_path / "existing.txt"
+        file_path.write_text("old")
+
+        with pytest.raises(FileOperationError):
+            write_file(str(file_path), "new content", max_bytes=3)
+
+        assert file_path.read_text() == "old"
+        assert [p.name for p in tmp_path.iterdir()] == ["existing.txt"]
+
+    def test_write_file_new_file_mode_follows_umask(self, tmp_path):
+        """Test a new file gets 0o666 minus the current umask, as open() would give it."""
+        file_path = tmp_path / "new.txt"
+
+        previous = os.umask(0o027)
+        try:
+            write_file(str(file_path), "x")
+        finally:
+            os.umask(previous)
+
+        assert file_path.stat().st_mode & 0o777 == 0o640
+
+    def test_write_file_keeps_existing_mode(self, tmp_path):
+        """Test replacing a file keeps its permission bits."""
+        file_path = tmp_path / "existing.txt"
+        file_path.write_text("old")
+        file_path.chmod(0o600)
+
+        write_file(str(file_path), "new")
+
+        assert file_path.stat().st_mode & 0o777 == 0o600
+
+    def test_write_file_follows_symlink(self, tmp_path):
+        """Test writing through a symlink updates the real file and keeps the link."""
+        target = tmp_path / "real.toon"
+        target.write_text("old")
+        link = tmp_path / "link.toon"
+        link.symlink_to(target)
+
+        write_file(str(link), "new")
+
+        assert link.is_symlink()
+        assert target.read_text() == "new"
+        assert sorted(p.name for p in tmp_path.iterdir()) == ["link.toon", "real.toon"]
+
+
+class TestStreamIO:
+    """Test reading and writing file-like objects."""
+
+    def test_read_stream_splits_multibyte_characters(self):
+        """Test binary readers decode UTF-8 split across reads."""
+        import io
+
+        assert read_stream(io.BytesIO("äöü".encode()), chunk_size=1) == "äöü"
+
+    def test_read_stream_invalid_utf8(self):
+        """Test invalid UTF-8 raises FileOperationError."""
+        import io
+
+        with pytest.raises(FileOperationError, match="Failed to read stream"):
+            read_stream(io.BytesIO(b"\xff"))
+
+    def test_read_stream_byte_order_marks(self):
+        """Test a UTF-8 BOM is dropped and a UTF-16 BOM is rejected."""
+        import io
+
+        assert read_stream(io.BytesIO(b"\xef\xbb\xbfa: 1"), chunk_size=1) == "a: 1"
+        with pytest.raises(FileOperationError, match="stream appears to be UTF-16"):
+            read_stream(io.BytesIO("a: 1".encode("utf-16")))
+
+    def test_read_stream_utf16_bom_split_across_reads(self):
+        """Test a UTF-16 BOM is still rejected when it arrives one byte at a time."""
+        import io
+
+        with pytest.raises(FileOperationError, match="stream appears to be UTF-16"):
+            read_stream(io.BytesIO("a: 1".encode("utf-16")), chunk_size=1)
+        assert read_stream(io.BytesIO(b"a"), chunk_size=1) == "a"
+
+    def test_write_stream_text_and_binary(self):
+        """Test text writers get str and binary writers get UTF-8 bytes."""
+        import io
+
+        text, binary = io.StringIO(), io.BytesIO()
+        write_stream(text, "ä")
+        write_stream(binary, "ä")
+
+        assert text.getvalue() == "ä"
+        assert binary.getvalue() == "ä".encode()
+
 
 class TestFileValidation:
     """Test file validation utilities."""
@@ -155,3 +302,81 @@ class TestDataValidation:
         """Test empty dict raises error."""
         with pytest.raises(ValidationError, match="cannot be empty"):
             validate_data_not_empty({})
+
+
+class TestPointer:
+    """Test JSON Pointer helpers."""
+
+    def test_format_pointer(self):
+        """Test formatting keys and indices as a pointer."""
+        assert format_pointer(["rows", 17, "score"]) == "/rows/17/score"
+        assert format_pointer([]) == ""
+
+    def test_format_pointer_escapes(self):
+        """Test '~' and '/' are escaped per RFC 6901."""
+        assert format_pointer(["a/b", "c~d"]) == "/a~1b/c~0d"
+
+    def test_parse_pointer_roundtrip(self):
+        """Test parsing reverses formatting."""
+        assert parse_pointer(format_pointer(["a/b", "c~d", 3])) == ["a/b", "c~d", "3"]
+        assert parse_pointer("") == []
+
+    def test_parse_pointer_invalid(self):
+        """Test pointers must start with '/'."""
+        with pytest.raises(ValueError, match="must be empty or start with"):
+            parse_pointer("rows/1")
+
+
+class TestProjection:
+    """Test resolving pointers and projecting documents."""
+
+    DOC = {
+        "user": {"name": "Ada", "email": "a@x", "tags": ["x", "y"]},
+        "rows": [{"id": 1, "v": 10}, {"id": 2, "v": 20}, {"id": 3, "v": 30}],
+        "n": 1,
+    }
+
+    def test_resolve_pointer(self):
+        """Test resolving dict keys and list indices."""
+        assert resolve_pointer(self.DOC, "/rows/1/v") == 20
+        assert resolve_pointer(self.DOC, "") is self.DOC
+
+    def test_resolve_pointer_missing(self):
+        """Test missing keys, out-of-range and leading-zero indices fail."""
+        for pointer in ["/nope", "/rows/9", "/rows/01", "/n/x"]:
+            with pytest.raises(LookupError, match="No value at"):
+                resolve_pointer(self.DOC, pointer)
+
+    def test_project_nested_paths(self):
+        """Test nested projections keep structure and key order."""
+        result = project(self.DOC, ["/rows/2/id", "/user/name", "/rows/0/v"])
+        assert result == {"user": {"name": "Ada"}, "rows": [{"v": 10}, {"id": 3}]}
+        assert list(result) == ["user", "rows"]
+
+    def test_project_ancestor_wins(self):
+        """Test selecting a value and one of its children keeps the whole value."""
+        assert project(self.DOC, ["/user/tags/0", "/user"]) == {"user": self.DOC["user"]}
+        assert project(self.DOC, ["/user", "/user/tags/0"]) == {"user": self.DOC["user"]}
+
+    def test_project_missing_skip(self):
+        """Test missing paths are skipped by default."""
+        assert project(self.DOC, ["/n", "/user/age", "/rows/7"]) == {"n": 1}
+        assert project(self.DOC, ["/nope"]) == {}
+
+    def test_project_missing_error(self):
+        """Test missing='error' raises for the first missing path."""
+        with pytest.raises(LookupError, match="/user/age"):
+            project(self.DOC, ["/n", "/user/age"], missing="error")
+
+    def test_project_invalid_missing_mode(self):
+        """Test unknown missing modes are rejected."""
+        with pytest.raises(ValueError, match="missing must be"):
+            project(self.DOC, ["/n"], missing="ignore")
+
+    def test_project_toon_facade(self):
+        """Test project_toon decodes, projects and optionally re-encodes."""
+        import toonverter as toon
+
+        toon_str = "rows[3]{id,v}:\n  1,10\n  2,20\n  3,30\nn: 1"
+        assert toon.project_toon(toon_str, ["/rows/1"]) == {"rows": [{"id": 2, "v": 20}]}
+        assert toon.project_toon(toon_str, ["/rows/1"], as_toon=True) == "rows[1]{id,v}:\n  2,20"
\ No newline at end of file
@@ -0,0 +1,461 @@
+"""Tests for the path-aware pre-encoding normalizer."""
+
+import enum
+import math
+
+import pytest
+
+import toonverter as toon
+from toonverter.core.exceptions import ValidationError
+from toonverter.core.spec import ToonEncodeOptions
+from toonverter.encoders import ToonEncoder
+from toonverter.encoders.value_normalizer import ValueNormalizer
+
+
+def _report_with_nan() -> dict:
+    rows = [{"id": i, "score": float(i)} for i in range(20)]
+    rows[17]["score"] = math.nan
+    return {"report": {"summary": {"rows": rows}}}
+
+
+class TestNonfinite:
+    """Test the nonfinite encoder option."""
+
+    def test_default_maps_nan_to_null(self):
+        """Test NaN still encodes as null by default."""
+        result = ToonEncoder().encode({"x": math.nan})
+        assert result == "x: null"
+
+    def test_error_mode_reports_path_in_tabular_row(self):
+        """Test a NaN nested inside a tabular row names its pointer path."""
+        encoder = ToonEncoder(ToonEncodeOptions(nonfinite="error"))
+        with pytest.raises(ValidationError, match="/report/summary/rows/17/score"):
+            encoder.encode(_report_with_nan())
+
+    def test_error_mode_rejects_infinity_in_list(self):
+        """Test infinities inside plain lists are rejected."""
+        encoder = ToonEncoder(ToonEncodeOptions(nonfinite="error"))
+        with pytest.raises(ValidationError, match="/values/2"):
+            encoder.encode({"values": [1, 2, -math.inf]})
+
+    def test_error_mode_root_value(self):
+        """Test a non-finite root primitive reports the root path."""
+        encoder = ToonEncoder(ToonEncodeOptions(nonfinite="error"))
+        with pytest.raises(ValidationError, match="at /"):
+            encoder.encode(math.inf)
+
+    def test_error_mode_finite_data_unchanged(self):
+        """Test finite data encodes identically with the option enabled."""
+        data = {"rows": [{"a": 1.5, "b": 2}, {"a": 3.0, "b": 4}]}
+        strict = ToonEncoder(ToonEncodeOptions(nonfinite="error")).encode(data)
+        assert strict == ToonEncoder().encode(data)
+
+    def test_facade_option(self):
+        """Test nonfinite is accepted by the facade encode function."""
+        with pytest.raises(ValidationError, match="/x"):
+            toon.encode({"x": math.nan}, nonfinite="error")
+
+    def test_invalid_mode_rejected(self):
+        """Test unknown modes are rejected at option construction."""
+        with pytest.raises(ValueError, match="nonfinite"):
+            ToonEncodeOptions(nonfinite="drop")  # type: ignore[arg-type]
+
+
+class TestIntegerKeyedDictAsList:
+    """Test the integer_keyed_dict_as_list encoder option."""
+
+    def test_index_keyed_dict_encodes_as_array(self):
+        """Test {"0": "a", "1": "b"} encodes as an inline array."""
+        encoder = ToonEncoder(ToonEncodeOptions(integer_keyed_dict_as_list=True))
+        assert encoder.encode({"0": "a", "1": "b"}) == "[2]: a,b"
+
+    def test_default_keeps_dict(self):
+        """Test the dict is encoded as a normal object without the option."""
+        assert ToonEncoder().encode({"0": "a", "1": "b"}) == "0: a\n1: b"
+
+    def test_nested_and_unordered_keys(self):
+        """Test nested index-keyed dicts convert and keys are ordered by index."""
+        encoder = ToonEncoder(ToonEncodeOptions(integer_keyed_dict_as_list=True))
+        result = encoder.encode({"items": {"1": "b", "0": "a", "2": "c"}})
+        assert result == "items[3]: a,b,c"
+
+    def test_non_contiguous_keys_stay_dict(self):
+        """Test gaps, offsets, and mixed keys keep the dict form."""
+        normalizer = ValueNormalizer(ToonEncodeOptions(integer_keyed_dict_as_list=True))
+        for data in ({"0": 1, "2": 2}, {"1": 1, "2": 2}, {"0": 1, "a": 2}, {"00": 1}, {}):
+            assert normalizer.normalize(data) == data
+
+    def test_facade_option(self):
+        """Test integer_keyed_dict_as_list is accepted by the facade."""
+        assert toon.encode({"0": 1, "1": 2}, integer_keyed_dict_as_list=True) == "[2]: 1,2"
+
+
+def _customers() -> dict:
+    return {
+        "account": {"owner": {"name": "Ada", "ssn": "123-45-6789"}},
+        "rows": [
+            {"id": 1, "email": "a@example.com", "plan": "pro"},
+            {"id": 2, "email": "b@example.com", "plan": "free"},
+        ],
+    }
+
+
+class TestRedaction:
+    """Test the redact_paths and transform encoder options."""
+
+    def test_redacts_nested_field(self):
+        """Test a nested field is masked in place."""
+        result = ToonEncoder(ToonEncodeOptions(redact_paths=["/account/owner/ssn"])).encode(
+            _customers()
+        )
+        assert "ssn: ***" in result
+        assert "123-45-6789" not in result
+        assert "name: Ada" in result
+
+    def test_redacts_tabular_column(self):
+        """Test a wildcard masks one column of a tabular array."""
+        options = ToonEncodeOptions(redact_paths=["/rows/*/email"], redact_with="[hidden]")
+        result = ToonEncoder(options).encode(_customers())
+
+        assert "rows[2]{id,email,plan}:" in result
+        assert '1,"[hidden]",pro' in result
+        assert "example.com" not in result
+
+    def test_unmatched_paths_leave_output_identical(self):
+        """Test output is byte-identical when no path matches."""
+        options = ToonEncodeOptions(redact_paths=["/rows/*/missing", "/nope"])
+        assert ToonEncoder(options).encode(_customers()) == ToonEncoder().encode(_customers())
+
+    def test_transform_sees_pointer_paths(self):
+        """Test transform receives each value's pointer and can replace it."""
+        seen = []
+
+        def mask_names(pointer, value):
+            seen.append(pointer)
+            return "A." if pointer.endswith("/name") else value
+
+        result = ToonEncoder(ToonEncodeOptions(transform=mask_names)).encode(_customers())
+
+        assert "name: A." in result
+        assert seen[:3] == ["", "/account", "/account/owner"]
+        assert "/rows/1/email" in seen
+
+    def test_facade_option(self):
+        """Test redact_paths is accepted by the facade encode function."""
+        result = toon.encode({"a": {"secret": 1}}, redact_paths=["/a/secret"])
+        assert result == "a:\n  secret: ***"
+
+    def test_invalid_path_rejected(self):
+        """Test malformed pointers are rejected when the encoder is built."""
+        with pytest.raises(ValueError, match="JSON Pointer"):
+            ToonEncoder(ToonEncodeOptions(redact_paths=["rows/email"]))
+
+
+class TestValueNormalizer:
+    """Test the normalizer directly."""
+
+    def test_inactive_by_default(self):
+        """Test the pre-pass is skipped when no option needs it."""
+        assert not ValueNormalizer(ToonEncodeOptions()).active
+
+    def test_escapes_keys_in_path(self):
+        """Test keys containing '/' are escaped in the reported path."""
+        normalizer = ValueNormalizer(ToonEncodeOptions(nonfinite="error"))
+        with pytest.raises(ValidationError, match="/a~1b/0"):
+            normalizer.normalize({"a/b": [math.nan]})
+
+
+class TestEmptyStrings:
+    """Test empty string round-trips and the empty_strings encoder option."""
+
+    def test_empty_string_round_trips(self):
+        """Test {"k": ""} survives encode and decode unchanged."""
+        data = {"k": ""}
+        result = toon.encode(data)
+        assert result == 'k: ""'
+        assert toon.decode(result) == data
+
+    def test_empty_strings_in_arrays_round_trip(self):
+        """Test empty strings survive in inline, tabular, and list arrays."""
+        data = {
+            "tags": ["", "a", ""],
+            "rows": [{"a": "", "b": 1}, {"a": "x", "b": 2}],
+            "mixed": [{"x": ""}, ""],
+        }
+        assert toon.decode(toon.encode(data)) == data
+
+    def test_empty_string_distinct_from_null(self):
+        """Test the default keeps empty strings and null apart."""
+        result = toon.encode({"a": "", "b": None})
+        assert result == 'a: ""\nb: null'
+        assert toon.decode(result) == {"a": "", "b": None}
+
+    def test_null_mode_writes_null(self):
+        """Test empty_strings="null" writes empty strings as null everywhere."""
+        options = ToonEncodeOptions(empty_strings="null")
+        result = ToonEncoder(options).encode({"k": "", "tags": ["", "a"], "keep": " "})
+        assert result == 'k: null\ntags[2]: null,a\nkeep: " "'
+
+    def test_null_mode_keeps_empty_keys(self):
+        """Test only values are affected, not empty keys."""
+        result = toon.encode({"": "x"}, empty_strings="null")
+        assert result == '"": x'
+
+    def test_invalid_mode_rejected(self):
+        """Test unknown modes are rejected at option construction."""
+        with pytest.raises(ValueError, match="empty_strings"):
+            ToonEncodeOptions(empty_strings="omit")  # type: ignore[arg-type]
+
+
+def _with_set() -> dict:
+    return {"items": [1, 2, {"id": 3, "tags": {"a"}}]}
+
+
+class TestOnUnsupported:
+    """Test the on_unsupported encoder option."""
+
+    def test_error_reports_path(self):
+        """Test the default error names the pointer path of the value."""
+        with pytest.raises(ValidationError, match="set at /items/2/tags"):
+            ToonEncoder().encode(_with_set())
+
+    def test_skip_drops_entry_and_collects_warning(self):
+        """Test skip omits the entry and records where it was found."""
+        encoder = ToonEncoder(ToonEncodeOptions(on_unsupported="skip"))
+        result = encoder.encode(_with_set())
+
+        assert toon.decode(result) == {"items": [1, 2, {"id": 3}]}
+        assert encoder.warnings == ["Skipped unsupported set at /items/2/tags"]
+
+    def test_skip_drops_list_element(self):
+        """Test a skipped list element shortens the array."""
+        result = toon.encode({"v": [1, {2}, 3]}, on_unsupported="skip")
+        assert result == "v[2]: 1,3"
+
+    def test_null_substitutes_null(self):
+        """Test null writes null in place of the value."""
+        result = toon.encode(_with_set(), on_unsupported="null")
+        assert toon.decode(result) == {"items": [1, 2, {"id": 3, "tags": None}]}
+
+    def test_repr_writes_string(self):
+        """Test repr stores repr(value) as a string."""
+        result = toon.encode(_with_set(), on_unsupported="repr")
+        assert toon.decode(result) == {"items": [1, 2, {"id": 3, "tags": "{'a'}"}]}
+
+    def test_transform_runs_first(self):
+        """Test a transform can convert values before the policy applies."""
+        options = ToonEncodeOptions(
+            transform=lambda _, v: sorted(v) if isinstance(v, set) else v
+        )
+        assert ToonEncoder(options).encode({"s": {2, 1}}) == "s[2]: 1,2"
+
+    def test_invalid_mode_rejected(self):
+        """Test unknown modes are rejected at option construction."""
+        with pytest.raises(ValueError, match="on_unsupported"):
+            ToonEncodeOptions(on_unsupported="drop")  # type: ignore[arg-type]
+
+
+class Point:
+    """Custom class with no TOON form of its own."""
+
+    def __init__(self, x: int, y: int) -> None:
+        self.x = x
+        self.y = y
+
+
+class TestFallbackEncoder:
+    """Test the process-wide fallback for unsupported values."""
+
+    @pytest.fixture(autouse=True)
+    def _reset(self):
+        yield
+        toon.set_fallback_encoder(None)
+
+    def test_custom_class_converted(self):
+        """Test the fallback result is encoded in place of the object."""
+        toon.set_fallback_encoder(lambda v: {"x": v.x, "y": v.y})
+        result = toon.encode({"points": [Point(1, 2), Point(3, 4)]})
+
+        assert result == "points[2]{x,y}:\n  1,2\n  3,4"
+
+    def test_result_is_normalized(self):
+        """Test value options apply to the fallback result."""
+        toon.set_fallback_encoder(lambda v: [v.x, float("nan")])
+        with pytest.raises(ValidationError, match="Non-finite number nan at /p/1"):
+            toon.encode({"p": Point(1, 2)}, nonfinite="error")
+
+    def test_declined_sentinel(self):
+        """Test returning FALLBACK_DECLINED leaves the value to on_unsupported."""
+
+        def fallback(value):
+            if isinstance(value, Point):
+                return [value.x, value.y]
+            return toon.FALLBACK_DECLINED
+
+        toon.set_fallback_encoder(fallback)
+        result = toon.encode({"p": Point(1, 2), "s": {1}}, on_unsupported="null")
+
+        assert toon.decode(result) == {"p": [1, 2], "s": None}
+
+    def test_type_error_propagates(self):
+        """Test a TypeError raised by the fallback fails the encode instead of declining."""
+        from toonverter.core.exceptions import EncodingError
+
+        def fallback(value):
+            return value.x + "!"
+
+        toon.set_fallback_encoder(fallback)
+        with pytest.raises(EncodingError, match="unsupported operand"):
+            toon.encode({"p": Point(1, 2)}, on_unsupported="null")
+
+    def test_stream_encoder_does_not_consult_fallback(self):
+        """Test ToonStreamEncoder rejects unsupported values even with a fallback."""
+        from toonverter.core.exceptions import EncodingError
+        from toonverter.encoders import ToonStreamEncoder
+
+        toon.set_fallback_encoder(lambda v: [v.x, v.y])
+        with pytest.raises(EncodingError, match="Unsupported type"):
+            "".join(ToonStreamEncoder().iterencode({"p": Point(1, 2)}))
+
+    def test_unsupported_result_rejected(self):
+        """Test a fallback returning another unsupported value is not re-converted."""
+        calls = []
+
+        def fallback(value):
+            calls.append(value)
+            return {"inner": Point(0, 0)}
+
+        toon.set_fallback_encoder(fallback)
+        with pytest.raises(ValidationError, match="returned unsupported type Point at /p/inner"):
+            toon.encode({"p": Point(1, 2)})
+        assert len(calls) == 1
+
+    def test_clear_with_none(self):
+        """Test None removes the registered fallback."""
+        toon.set_fallback_encoder(lambda v: str(v))
+        toon.set_fallback_encoder(None)
+
+        assert not ValueNormalizer(ToonEncodeOptions()).active
+        with pytest.raises(ValidationError, match="set at /s"):
+            toon.encode({"s": {1}})
+
+
+class TestNormalizeNumbers:
+    """Test canonical output for Decimal, Fraction and other numeric types."""
+
+    def test_decimals_written_canonically(self):
+        """Test trailing zeros and exponents are dropped from Decimals."""
+        from decimal import Decimal
+
+        data = {"a": Decimal("100.00"), "b": Decimal("1E+3"), "c": Decimal("2.50")}
+        result = toon.encode(data, normalize_numbers=True)
+        assert result == "a: 100\nb: 1000\nc: 2.5"
+
+    def test_matches_builtin_numbers(self):
+        """Test each source type renders exactly like the equivalent int or float."""
+        from decimal import Decimal
+        from fractions import Fraction
+
+        values = [Decimal("-0.00"), Decimal("1E-7"), Fraction(1, 4), Fraction(6, 3)]
+        expected = [0, 1e-7, 0.25, 2]
+        assert toon.encode(values, normalize_numbers=True) == toon.encode(expected)
+
+    def test_large_integral_decimal_keeps_digits(self):
+        """Test integral values stay exact instead of going through a float."""
+        from decimal import Decimal
+
+        result = toon.encode({"n": Decimal("12345678901234567890.000")}, normalize_numbers=True)
+        assert result == "n: 12345678901234567890"
+
+    def test_int_as_float_marks_integral_values(self):
+        """Test integral values are written as floats only with int_as_float."""
+        from decimal import Decimal
+
+        result = toon.encode({"n": Decimal("100.00")}, normalize_numbers=True, int_as_float=True)
+        assert result == "n: 100.0"
+
+    def test_nonfinite_follows_nonfinite_option(self):
+        """Test Decimal NaN and infinity are handled like the floats."""
+        from decimal import Decimal
+
+        data = {"a": Decimal("NaN"), "b": Decimal("-Infinity")}
+        assert toon.encode(data, normalize_numbers=True) == "a: null\nb: null"
+        with pytest.raises(ValidationError, match="Non-finite number nan at /a"):
+            toon.encode(data, normalize_numbers=True, nonfinite="error")
+
+    def test_tabular_cells(self):
+        """Test numbers in tabular rows are normalized too."""
+        from decimal import Decimal
+
+        rows = [{"id": 1, "price": Decimal("9.90")}, {"id": 2, "price": Decimal("10.00")}]
+        result = toon.encode(rows, normalize_numbers=True)
+        assert result == "[2]{id,price}:\n  1,9.9\n  2,10"
+
+    def test_default_leaves_decimal_unsupported(self):
+        """Test other numeric types still follow on_unsupported without the option."""
+        from decimal import Decimal
+
+        with pytest.raises(ValidationError, match="Unsupported type for TOON encoding: Decimal"):
+            toon.encode({"a": Decimal("1")})
+
+
+class Priority(enum.IntEnum):
+    """Int-valued enum."""
+
+    LOW = 1
+    HIGH = 2
+
+
+class Color(str, enum.Enum):
+    """String-valued enum."""
+
+    RED = "red"
+    GREEN = "green"
+
+
+class Shape(enum.Enum):
+    """Plain enum with a value that has no TOON form."""
+
+    POINT = (0, 0)
+
+
+class TestEnumMode:
+    """Test the enum_mode encoder option."""
+
+    def test_value_mode(self):
+        """Test members are written as their values."""
+        data = {"p": Priority.HIGH, "tags": [Color.RED, Color.GREEN]}
+        assert toon.encode(data, enum_mode="value") == "p: 2\ntags[2]: red,green"
+
+    def test_name_mode(self):
+        """Test members are written as their names."""
+        data = {"p": Priority.HIGH, "tags": [Color.RED, Color.GREEN]}
+        assert toon.encode(data, enum_mode="name") == "p: HIGH\ntags[2]: RED,GREEN"
+
+    @pytest.mark.parametrize(
+        ("mode", "expected"), [("value", "red: 1\n2: 2"), ("name", "RED: 1\nHIGH: 2")]
+    )
+    def test_dict_keys(self, mode, expected):
+        """Test members used as dict keys are converted like values."""
+        assert toon.encode({Color.RED: 1, Priority.HIGH: 2}, enum_mode=mode) == expected
+
+    def test_tabular_cells(self):
+        """Test members in tabular rows are converted too."""
+        rows = [{"id": 1, "p": Priority.LOW}, {"id": 2, "p": Priority.HIGH}]
+        assert toon.encode(rows, enum_mode="name") == "[2]{id,p}:\n  1,LOW\n  2,HIGH"
+
+    def test_unsupported_value_follows_policy(self):
+        """Test an enum value with no TOON form is handled by on_unsupported."""
+        with pytest.raises(ValidationError, match="tuple at /s"):
+            toon.encode({"s": Shape.POINT}, enum_mode="value")
+        assert toon.encode({"s": Shape.POINT}, enum_mode="name") == "s: POINT"
+
+    def test_default_leaves_plain_enum_unsupported(self):
+        """Test plain Enum members still follow on_unsupported without the option."""
+        with pytest.raises(ValidationError, match="Unsupported type for TOON encoding: Shape"):
+            toon.encode({"s": Shape.POINT})
+
+    def test_invalid_mode_rejected(self):
+        """Test unknown modes are rejected at option construction."""
+        with pytest.raises(ValueError, match="enum_mode"):
+            ToonEncodeOptions(enum_mode="label")  # type: ignore[arg-type]
@@ -223,3 +223,33 @@ class TestYAMLErrors:
             with pytest.raises(ImportError) as exc:
                 YAMLFormat()
             assert "PyYAML is required" in str(exc.value)
+
+
+@pytest.mark.skipif(not YAML_AVAILABLE, reason="PyYAML not installed")
+class TestToonToYaml:
+    """Test converting TOON documents to YAML."""
+
+    def test_round_trip(self):
+        """Test YAML output decodes to the same data as the TOON input."""
+        import toonverter as toon
+
+        data = {
+            "service": "api",
+            "replicas": 3,
+            "ratio": 0.75,
+            "debug": False,
+            "owner": None,
+            "tags": ["a", "b c"],
+            "users": [{"id": 1, "name": "Ada"}, {"id": 2, "name": "Bob"}],
+            "nested": {"deep": {"list": [[1, 2], []]}},
+        }
+        result = toon.toon_to_yaml(toon.encode(data))
+
+        assert yaml.safe_load(result) == data
+        assert toon.decode(toon.encode(yaml.safe_load(result))) == data
+
+    def test_options_are_forwarded(self):
+        """Test encoding overrides reach the YAML adapter."""
+        import toonverter as toon
+
+        assert toon.toon_to_yaml("b: 1\na: 2", sort_keys=True) == "a: 2\nb: 1\n"